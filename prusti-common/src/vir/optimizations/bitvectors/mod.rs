@@ -1,4 +1,5 @@
-//! If the program uses bit operations, change all integers to bitvectors.
+//! If the program uses bit operations or the bitvector integer encoding is
+//! selected, change all integers to bitvectors.
 
 use vir::polymorphic::{self as vir_poly, ExprFolder, ExprWalker, StmtFolder, StmtWalker};
 
@@ -0,0 +1,105 @@
+// © 2023, ETH Zurich
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Optimization that removes labels that are not referenced by any `old[label](..)`
+//! expression.
+
+use crate::vir::polymorphic_vir::{ast, cfg};
+use rustc_hash::FxHashSet;
+
+/// Remove the `label` statements whose label is not used in the method.
+pub fn remove_unused_labels(mut method: cfg::CfgMethod) -> cfg::CfgMethod {
+    let mut collector = UsedLabelCollector {
+        used_labels: FxHashSet::default(),
+    };
+    method.walk_statements(|stmt| {
+        ast::StmtWalker::walk(&mut collector, stmt);
+    });
+    method.walk_successors(|successor| match successor {
+        cfg::Successor::Undefined | cfg::Successor::Return | cfg::Successor::Goto(_) => {}
+        cfg::Successor::GotoSwitch(conditional_targets, _) => {
+            for (expr, _) in conditional_targets {
+                ast::ExprWalker::walk(&mut collector, expr);
+            }
+        }
+    });
+    let used_labels = collector.used_labels;
+    method.retain_stmts(|stmt| match stmt {
+        ast::Stmt::Label(ast::Label { label }) => used_labels.contains(label),
+        _ => true,
+    });
+    method
+}
+
+/// Collects all labels that are used in `old[label](..)` expressions or by package
+/// statements.
+struct UsedLabelCollector {
+    used_labels: FxHashSet<String>,
+}
+
+impl ast::ExprWalker for UsedLabelCollector {
+    fn walk_labelled_old(&mut self, ast::LabelledOld { label, base, .. }: &ast::LabelledOld) {
+        self.used_labels.insert(label.clone());
+        ast::ExprWalker::walk(self, base);
+    }
+}
+
+impl ast::StmtWalker for UsedLabelCollector {
+    fn walk_expr(&mut self, expr: &ast::Expr) {
+        ast::ExprWalker::walk(self, expr);
+    }
+    fn walk_package_magic_wand(
+        &mut self,
+        ast::PackageMagicWand {
+            magic_wand,
+            package_stmts,
+            label,
+            ..
+        }: &ast::PackageMagicWand,
+    ) {
+        self.used_labels.insert(label.clone());
+        self.walk_expr(magic_wand);
+        for statement in package_stmts {
+            self.walk(statement);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vir::polymorphic_vir::{ast::*, cfg::CfgMethod};
+
+    fn labels(method: &CfgMethod) -> Vec<String> {
+        let mut labels = Vec::new();
+        method.walk_statements(|stmt| {
+            if let Stmt::Label(Label { label }) = stmt {
+                labels.push(label.clone());
+            }
+        });
+        labels
+    }
+
+    #[test]
+    fn unused_label_is_removed() {
+        let x = Expr::local(LocalVar::new("x", Type::Int));
+        let mut method = CfgMethod::new("m".to_string(), vec![], vec![], vec![]);
+        let entry = method.add_block("start", vec![]);
+        method.add_stmt(entry, Stmt::label("l_used"));
+        method.add_stmt(entry, Stmt::label("l_unused"));
+        method.add_stmt(
+            entry,
+            Stmt::Assert(Assert {
+                expr: Expr::eq_cmp(x.clone(), Expr::labelled_old("l_used", x)),
+                position: Position::default(),
+            }),
+        );
+        method.set_successor(entry, cfg::Successor::Return);
+
+        let method = remove_unused_labels(method);
+        assert_eq!(labels(&method), vec!["l_used".to_string()]);
+    }
+}
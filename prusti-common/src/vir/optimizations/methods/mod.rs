@@ -9,6 +9,8 @@
 mod assert_remover;
 mod cfg_cleaner;
 mod empty_if_remover;
+mod label_remover;
+mod pure_call_sharer;
 mod purifier;
 mod quantifier_fixer;
 mod unfolding_fixer;
@@ -19,7 +21,8 @@ use crate::{config::Optimizations, vir::polymorphic_vir::cfg::CfgMethod};
 
 use self::{
     assert_remover::remove_trivial_assertions, cfg_cleaner::clean_cfg,
-    empty_if_remover::remove_empty_if, purifier::purify_vars, quantifier_fixer::fix_quantifiers,
+    empty_if_remover::remove_empty_if, label_remover::remove_unused_labels,
+    pure_call_sharer::share_pure_calls, purifier::purify_vars, quantifier_fixer::fix_quantifiers,
     unfolding_fixer::fix_unfoldings, var_remover::remove_unused_vars,
 };
 
@@ -61,6 +64,8 @@ pub fn optimize_method_encoding(
     let cfg = apply!(remove_empty_if, cfg);
     let cfg = apply!(remove_unused_vars, cfg);
     let cfg = apply!(remove_trivial_assertions, cfg);
+    let cfg = apply!(remove_unused_labels, cfg);
+    let cfg = apply!(share_pure_calls, cfg);
     let cfg = apply!(clean_cfg, cfg);
 
     cfg
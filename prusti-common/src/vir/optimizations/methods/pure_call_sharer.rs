@@ -0,0 +1,278 @@
+// © 2023, ETH Zurich
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Optimization that binds repeated pure function calls to `let` variables,
+//! i.e. common subexpression elimination of pure calls within one assertion.
+//! Calls are not moved across statements, and in particular not out of loops.
+//!
+//! Loop invariants and the assertions generated around loops often call the
+//! same pure function with the same arguments many times, which makes the
+//! verifier evaluate each of the calls separately. Within a single pure
+//! assertion all calls are evaluated in the same state, so a call that is
+//! evaluated unconditionally can be evaluated once and reused:
+//!
+//! `assert f(x) > 0 && (c ==> f(x) < 10)` becomes
+//! `assert (let __shared_0 == (f(x)) in __shared_0 > 0 && (c ==> __shared_0 < 10))`.
+//!
+//! Only calls that are evaluated regardless of the values of the
+//! subexpressions are shared: binding a call from the right-hand side of
+//! `&&`, `||`, `==>` or out of a conditional could evaluate it in a context in
+//! which its precondition does not hold. Calls under `old`, `unfolding`,
+//! quantifiers, and `let` expressions are left untouched.
+
+use crate::vir::polymorphic_vir::{ast::*, cfg::CfgMethod};
+use std::mem;
+
+pub fn share_pure_calls(mut method: CfgMethod) -> CfgMethod {
+    let mut counter = 0;
+    for block in &mut method.basic_blocks {
+        for stmt in &mut block.stmts {
+            if let Stmt::Assert(Assert { expr, .. })
+            | Stmt::Inhale(Inhale { expr })
+            | Stmt::Exhale(Exhale { expr, .. }) = stmt
+            {
+                let original = mem::replace(expr, true.into());
+                *expr = share_in_expr(original, &mut counter);
+            }
+        }
+    }
+    method
+}
+
+/// Returns the expression in which every unconditionally evaluated pure
+/// function call that occurs more than once is evaluated only once.
+pub fn share_in_expr(expr: Expr, counter: &mut u32) -> Expr {
+    if !expr.is_pure() {
+        return expr;
+    }
+    let position = expr.pos();
+    let mut body = expr;
+    let mut bindings = vec![];
+    while let Some(call) = find_repeated_call(&body) {
+        let variable = LocalVar::new(format!("__shared_{counter}"), call.return_type.clone());
+        *counter += 1;
+        body = CallReplacer {
+            call: &call,
+            replacement: Expr::local(variable.clone()),
+        }
+        .fold(body);
+        bindings.push((variable, call));
+    }
+    bindings
+        .into_iter()
+        .rev()
+        .fold(body, |body, (variable, call)| {
+            Expr::LetExpr(LetExpr {
+                variable,
+                def: box Expr::FuncApp(call),
+                body: box body,
+                position,
+            })
+        })
+}
+
+/// Finds the first unconditionally evaluated call that occurs at least twice.
+fn find_repeated_call(expr: &Expr) -> Option<FuncApp> {
+    let mut collector = UnconditionalCallCollector { calls: vec![] };
+    collector.walk(expr);
+    collector.calls.into_iter().find(|call| {
+        let mut counter = CallCounter { call, count: 0 };
+        counter.walk(expr);
+        counter.count > 1
+    })
+}
+
+/// Collects the calls that are evaluated whenever the expression is evaluated.
+struct UnconditionalCallCollector {
+    calls: Vec<FuncApp>,
+}
+
+impl ExprWalker for UnconditionalCallCollector {
+    fn walk_bin_op(&mut self, expr: &BinOp) {
+        match expr.op_kind {
+            BinaryOpKind::And | BinaryOpKind::Or | BinaryOpKind::Implies => self.walk(&expr.left),
+            _ => {
+                self.walk(&expr.left);
+                self.walk(&expr.right);
+            }
+        }
+    }
+    fn walk_cond(&mut self, expr: &Cond) {
+        self.walk(&expr.guard);
+    }
+    fn walk_func_app(&mut self, expr: &FuncApp) {
+        for arg in &expr.arguments {
+            self.walk(arg);
+        }
+        if !self.calls.contains(expr) {
+            self.calls.push(expr.clone());
+        }
+    }
+    fn walk_labelled_old(&mut self, _expr: &LabelledOld) {}
+    fn walk_unfolding(&mut self, _expr: &Unfolding) {}
+    fn walk_forall(&mut self, _expr: &ForAll) {}
+    fn walk_exists(&mut self, _expr: &Exists) {}
+    fn walk_let_expr(&mut self, _expr: &LetExpr) {}
+}
+
+/// Counts the occurrences of `call` that can be replaced.
+struct CallCounter<'a> {
+    call: &'a FuncApp,
+    count: usize,
+}
+
+impl<'a> ExprWalker for CallCounter<'a> {
+    fn walk_func_app(&mut self, expr: &FuncApp) {
+        if expr == self.call {
+            self.count += 1;
+        } else {
+            for arg in &expr.arguments {
+                self.walk(arg);
+            }
+        }
+    }
+    fn walk_labelled_old(&mut self, _expr: &LabelledOld) {}
+    fn walk_unfolding(&mut self, _expr: &Unfolding) {}
+    fn walk_forall(&mut self, _expr: &ForAll) {}
+    fn walk_exists(&mut self, _expr: &Exists) {}
+    fn walk_let_expr(&mut self, _expr: &LetExpr) {}
+}
+
+/// Replaces the occurrences counted by `CallCounter`.
+struct CallReplacer<'a> {
+    call: &'a FuncApp,
+    replacement: Expr,
+}
+
+impl<'a> ExprFolder for CallReplacer<'a> {
+    fn fold_func_app(&mut self, expr: FuncApp) -> Expr {
+        if &expr == self.call {
+            self.replacement.clone()
+        } else {
+            Expr::FuncApp(FuncApp {
+                arguments: expr.arguments.into_iter().map(|e| self.fold(e)).collect(),
+                ..expr
+            })
+        }
+    }
+    fn fold_labelled_old(&mut self, expr: LabelledOld) -> Expr {
+        Expr::LabelledOld(expr)
+    }
+    fn fold_unfolding(&mut self, expr: Unfolding) -> Expr {
+        Expr::Unfolding(expr)
+    }
+    fn fold_forall(&mut self, expr: ForAll) -> Expr {
+        Expr::ForAll(expr)
+    }
+    fn fold_exists(&mut self, expr: Exists) -> Expr {
+        Expr::Exists(expr)
+    }
+    fn fold_let_expr(&mut self, expr: LetExpr) -> Expr {
+        Expr::LetExpr(expr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn call(name: &str, argument: Expr) -> Expr {
+        Expr::FuncApp(FuncApp {
+            function_name: name.to_string(),
+            type_arguments: vec![],
+            arguments: vec![argument],
+            formal_arguments: vec![LocalVar::new("arg", Type::Int)],
+            return_type: Type::Int,
+            position: Position::default(),
+        })
+    }
+
+    fn local(name: &str, typ: Type) -> Expr {
+        Expr::local(LocalVar::new(name, typ))
+    }
+
+    fn count_calls(expr: &Expr) -> usize {
+        struct Counter(usize);
+        impl ExprWalker for Counter {
+            fn walk_func_app(&mut self, expr: &FuncApp) {
+                self.0 += 1;
+                for arg in &expr.arguments {
+                    self.walk(arg);
+                }
+            }
+        }
+        let mut counter = Counter(0);
+        counter.walk(expr);
+        counter.0
+    }
+
+    #[test]
+    fn repeated_call_is_evaluated_once() {
+        let x = local("x", Type::Int);
+        let c = local("c", Type::Bool);
+        let f_x = call("f", x);
+        // f(x) > 0 && (c ==> f(x) < 10) && f(x) != 5
+        let expr = Expr::bin_op(
+            BinaryOpKind::And,
+            Expr::bin_op(
+                BinaryOpKind::And,
+                Expr::bin_op(BinaryOpKind::GtCmp, f_x.clone(), 0.into()),
+                Expr::bin_op(
+                    BinaryOpKind::Implies,
+                    c,
+                    Expr::bin_op(BinaryOpKind::LtCmp, f_x.clone(), 10.into()),
+                ),
+            ),
+            Expr::ne_cmp(f_x, 5.into()),
+        );
+        assert_eq!(count_calls(&expr), 3);
+        let shared = share_in_expr(expr, &mut 0);
+        assert_eq!(count_calls(&shared), 1);
+        assert!(matches!(shared, Expr::LetExpr(_)));
+    }
+
+    #[test]
+    fn nested_repeated_calls() {
+        let x = local("x", Type::Int);
+        let g_f_x = call("g", call("f", x));
+        // g(f(x)) == g(f(x)) + 0
+        let expr = Expr::bin_op(
+            BinaryOpKind::EqCmp,
+            g_f_x.clone(),
+            Expr::bin_op(BinaryOpKind::Add, g_f_x, 0.into()),
+        );
+        assert_eq!(count_calls(&expr), 4);
+        let shared = share_in_expr(expr, &mut 0);
+        assert_eq!(count_calls(&shared), 2);
+    }
+
+    #[test]
+    fn conditional_call_is_not_shared() {
+        let x = local("x", Type::Int);
+        let c = local("c", Type::Bool);
+        let f_x = call("f", x);
+        // c ==> (f(x) > 0 && f(x) < 10)
+        let expr = Expr::bin_op(
+            BinaryOpKind::Implies,
+            c,
+            Expr::bin_op(
+                BinaryOpKind::And,
+                Expr::bin_op(BinaryOpKind::GtCmp, f_x.clone(), 0.into()),
+                Expr::bin_op(BinaryOpKind::LtCmp, f_x, 10.into()),
+            ),
+        );
+        let shared = share_in_expr(expr.clone(), &mut 0);
+        assert_eq!(shared, expr);
+    }
+
+    #[test]
+    fn single_call_is_not_shared() {
+        let x = local("x", Type::Int);
+        let expr = Expr::bin_op(BinaryOpKind::GtCmp, call("f", x), 0.into());
+        let shared = share_in_expr(expr.clone(), &mut 0);
+        assert_eq!(shared, expr);
+    }
+}
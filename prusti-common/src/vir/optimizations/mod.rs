@@ -70,7 +70,9 @@ pub fn optimize_program(p: Program, source_file_name: &str) -> Program {
             "encode_bitvectors",
             false,
         );
-        if bitvectors::uses_bit_operations(&program) {
+        if config::integer_encoding() == config::IntegerEncoding::Bitvector
+            || bitvectors::uses_bit_operations(&program)
+        {
             bitvectors::replace_all_ints(&mut program);
         }
         log_methods(
@@ -1,6 +1,11 @@
 use super::low_to_viper::{Context, ToViper};
 use viper::{self, AstFactory};
 
+/// The name of the program that contains the exported Viper prelude of a
+/// crate. Since `$` cannot appear in Rust identifiers, it does not clash with
+/// the names of the programs of encoded items.
+pub const VIPER_PRELUDE_NAME: &str = "$prelude";
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Hash, Eq, PartialEq)]
 pub enum Program {
     Legacy(vir::legacy::Program),
@@ -29,6 +34,18 @@ impl Program {
     pub fn get_name_with_check_mode(&self) -> String {
         format!("{}-{}", self.get_name(), self.get_check_mode())
     }
+    pub fn is_viper_prelude(&self) -> bool {
+        self.get_name().ends_with(VIPER_PRELUDE_NAME)
+    }
+}
+
+impl std::fmt::Display for Program {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Program::Legacy(program) => write!(f, "{program}"),
+            Program::Low(program) => write!(f, "{program}"),
+        }
+    }
 }
 
 impl<'v> ToViper<'v, viper::Program<'v>> for Program {
@@ -902,19 +902,16 @@ impl<'a, 'v> ToViper<'v, viper::DomainFunc<'v>> for &'a DomainFunc {
 impl<'a, 'v> ToViper<'v, viper::NamedDomainAxiom<'v>> for &'a DomainAxiom {
     #[tracing::instrument(name = "DomainAxiom::to_viper", level = "trace", skip(context, ast))]
     fn to_viper(&self, context: Context, ast: &AstFactory<'v>) -> viper::NamedDomainAxiom<'v> {
+        let expr = self.expr_with_triggers();
         if let Some(comment) = &self.comment {
             ast.named_domain_axiom_with_comment(
                 &self.name,
-                self.expr.to_viper(context, ast),
+                expr.to_viper(context, ast),
                 &self.domain_name,
                 comment,
             )
         } else {
-            ast.named_domain_axiom(
-                &self.name,
-                self.expr.to_viper(context, ast),
-                &self.domain_name,
-            )
+            ast.named_domain_axiom(&self.name, expr.to_viper(context, ast), &self.domain_name)
         }
     }
 }
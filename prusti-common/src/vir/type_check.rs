@@ -0,0 +1,300 @@
+// © 2023, ETH Zurich
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! A lightweight type checker that validates the sorts of the operands of
+//! each operator, so that ill-typed expressions produced by encoder bugs are
+//! reported before the program is handed over to Viper.
+
+use crate::vir::polymorphic_vir::{ast::*, cfg, Program};
+use std::fmt;
+
+/// An operator whose operands have the wrong sorts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeError {
+    /// Where the expression occurs, e.g. "postcondition of function `f`".
+    pub path: String,
+    /// The offending sub-expression.
+    pub expr: String,
+    pub message: String,
+}
+
+impl fmt::Display for TypeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} in `{}` ({})", self.message, self.expr, self.path)
+    }
+}
+
+/// Type check all expressions of `program`.
+pub fn type_check_program(program: &Program) -> Vec<TypeError> {
+    let mut errors = Vec::new();
+    for domain in &program.domains {
+        for axiom in &domain.axioms {
+            let path = format!("axiom `{}` of domain `{}`", axiom.name, domain.name);
+            errors.extend(type_check_expr(&axiom.expr, &path));
+        }
+    }
+    for function in &program.functions {
+        for pre in &function.pres {
+            let path = format!("precondition of function `{}`", function.name);
+            errors.extend(type_check_expr(pre, &path));
+        }
+        for post in &function.posts {
+            let path = format!("postcondition of function `{}`", function.name);
+            errors.extend(type_check_expr(post, &path));
+        }
+        if let Some(body) = &function.body {
+            let path = format!("body of function `{}`", function.name);
+            errors.extend(type_check_expr(body, &path));
+        }
+    }
+    for predicate in &program.viper_predicates {
+        if let Some(body) = predicate.body() {
+            let path = format!("body of predicate `{}`", predicate.name());
+            errors.extend(type_check_expr(&body, &path));
+        }
+    }
+    for method in &program.methods {
+        let mut checker = TypeChecker {
+            path: format!("method `{}`", method.name()),
+            errors: Vec::new(),
+        };
+        method.walk_statements(|stmt| {
+            StmtWalker::walk(&mut checker, stmt);
+        });
+        method.walk_successors(|successor| {
+            if let cfg::Successor::GotoSwitch(conditional_targets, _) = successor {
+                for (guard, _) in conditional_targets {
+                    checker.check_bool(guard, "branch condition");
+                    ExprWalker::walk(&mut checker, guard);
+                }
+            }
+        });
+        errors.extend(checker.errors);
+    }
+    errors
+}
+
+/// Type check `expr`, which occurs at the given `path` of the program.
+pub fn type_check_expr(expr: &Expr, path: &str) -> Vec<TypeError> {
+    let mut checker = TypeChecker {
+        path: path.to_string(),
+        errors: Vec::new(),
+    };
+    ExprWalker::walk(&mut checker, expr);
+    checker.errors
+}
+
+/// The sort of `expr`, or `None` if it cannot be determined cheaply and
+/// without panicking. Unknown sorts are never reported as errors.
+fn sort(expr: &Expr) -> Option<&Type> {
+    match expr {
+        Expr::Local(..)
+        | Expr::Variant(..)
+        | Expr::Field(..)
+        | Expr::AddrOf(..)
+        | Expr::FuncApp(..)
+        | Expr::DomainFuncApp(..)
+        | Expr::Const(..)
+        | Expr::Map(..)
+        | Expr::Seq(..)
+        | Expr::Cast(..) => Some(expr.get_type()),
+        Expr::ForAll(..) | Expr::Exists(..) => Some(&Type::Bool),
+        Expr::UnaryOp(UnaryOp {
+            op_kind: UnaryOpKind::Not,
+            ..
+        }) => Some(&Type::Bool),
+        Expr::BinOp(BinOp {
+            op_kind:
+                BinaryOpKind::EqCmp
+                | BinaryOpKind::NeCmp
+                | BinaryOpKind::GtCmp
+                | BinaryOpKind::GeCmp
+                | BinaryOpKind::LtCmp
+                | BinaryOpKind::LeCmp
+                | BinaryOpKind::And
+                | BinaryOpKind::Or
+                | BinaryOpKind::Implies,
+            ..
+        }) => Some(&Type::Bool),
+        Expr::UnaryOp(UnaryOp { argument: base, .. })
+        | Expr::BinOp(BinOp { left: base, .. })
+        | Expr::Cond(Cond {
+            then_expr: base, ..
+        })
+        | Expr::LetExpr(LetExpr { body: base, .. })
+        | Expr::LabelledOld(LabelledOld { base, .. })
+        | Expr::Unfolding(Unfolding { base, .. })
+        | Expr::Downcast(DowncastExpr { base, .. }) => sort(base),
+        _ => None,
+    }
+}
+
+fn is_primitive(typ: &Type) -> bool {
+    matches!(typ, Type::Int | Type::Bool | Type::Float(_) | Type::BitVector(_))
+}
+
+struct TypeChecker {
+    path: String,
+    errors: Vec<TypeError>,
+}
+
+impl TypeChecker {
+    fn report(&mut self, expr: &dyn fmt::Display, message: String) {
+        self.errors.push(TypeError {
+            path: self.path.clone(),
+            expr: expr.to_string(),
+            message,
+        });
+    }
+
+    fn check_bool(&mut self, expr: &Expr, what: &str) {
+        if let Some(typ) = sort(expr) {
+            if typ != &Type::Bool {
+                self.report(expr, format!("{what} must be of sort Bool, found {typ}"));
+            }
+        }
+    }
+}
+
+impl ExprWalker for TypeChecker {
+    fn walk_unary_op(&mut self, expr: &UnaryOp) {
+        let UnaryOp {
+            op_kind, argument, ..
+        } = expr;
+        match op_kind {
+            UnaryOpKind::Not => self.check_bool(argument, "operand of `!`"),
+            UnaryOpKind::Minus => {
+                if sort(argument) == Some(&Type::Bool) {
+                    self.report(expr, "operand of `-` must not be of sort Bool".to_string());
+                }
+            }
+        }
+        ExprWalker::walk(self, argument);
+    }
+
+    fn walk_bin_op(&mut self, expr: &BinOp) {
+        let BinOp {
+            op_kind,
+            left,
+            right,
+            ..
+        } = expr;
+        if let (Some(left_sort), Some(right_sort)) = (sort(left), sort(right)) {
+            let mismatch = (is_primitive(left_sort) || is_primitive(right_sort))
+                && left_sort != right_sort;
+            let message = match op_kind {
+                BinaryOpKind::And | BinaryOpKind::Or | BinaryOpKind::Implies
+                    if left_sort != &Type::Bool || right_sort != &Type::Bool =>
+                {
+                    Some(format!("operands of `{op_kind}` must be of sort Bool"))
+                }
+                BinaryOpKind::GtCmp
+                | BinaryOpKind::GeCmp
+                | BinaryOpKind::LtCmp
+                | BinaryOpKind::LeCmp
+                | BinaryOpKind::Add
+                | BinaryOpKind::Sub
+                | BinaryOpKind::Mul
+                | BinaryOpKind::Div
+                | BinaryOpKind::Mod
+                | BinaryOpKind::Min
+                | BinaryOpKind::Max
+                    if left_sort == &Type::Bool || right_sort == &Type::Bool =>
+                {
+                    Some(format!("operands of `{op_kind}` must not be of sort Bool"))
+                }
+                BinaryOpKind::And | BinaryOpKind::Or | BinaryOpKind::Implies => None,
+                _ if mismatch => Some(format!("operands of `{op_kind}` have different sorts")),
+                _ => None,
+            };
+            if let Some(message) = message {
+                self.report(expr, format!("{message}, found {left_sort} and {right_sort}"));
+            }
+        }
+        ExprWalker::walk(self, left);
+        ExprWalker::walk(self, right);
+    }
+
+    fn walk_cond(&mut self, expr: &Cond) {
+        let Cond {
+            guard,
+            then_expr,
+            else_expr,
+            ..
+        } = expr;
+        self.check_bool(guard, "condition of a conditional expression");
+        if let (Some(then_sort), Some(else_sort)) = (sort(then_expr), sort(else_expr)) {
+            if (is_primitive(then_sort) || is_primitive(else_sort)) && then_sort != else_sort {
+                self.report(
+                    expr,
+                    format!("branches have different sorts, found {then_sort} and {else_sort}"),
+                );
+            }
+        }
+        ExprWalker::walk(self, guard);
+        ExprWalker::walk(self, then_expr);
+        ExprWalker::walk(self, else_expr);
+    }
+}
+
+impl StmtWalker for TypeChecker {
+    fn walk_expr(&mut self, expr: &Expr) {
+        ExprWalker::walk(self, expr);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn well_typed_expression_is_accepted() {
+        let x = Expr::local(LocalVar::new("x", Type::Int));
+        let b = Expr::local(LocalVar::new("b", Type::Bool));
+        let expr = Expr::implies(b, Expr::eq_cmp(x.clone(), Expr::ite(true.into(), x, 0.into())));
+        assert_eq!(type_check_expr(&expr, "test"), vec![]);
+    }
+
+    #[test]
+    fn comparison_of_int_and_bool_is_rejected() {
+        let x = Expr::local(LocalVar::new("x", Type::Int));
+        let b = Expr::local(LocalVar::new("b", Type::Bool));
+        let ill_typed = Expr::eq_cmp(x, b);
+        let expr = Expr::and(true.into(), ill_typed.clone());
+        let errors = type_check_expr(&expr, "postcondition of function `f`");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].expr, ill_typed.to_string());
+        assert_eq!(errors[0].path, "postcondition of function `f`");
+        assert_eq!(
+            errors[0].message,
+            "operands of `==` have different sorts, found Int and Bool"
+        );
+    }
+
+    #[test]
+    fn arithmetic_on_bool_is_rejected() {
+        let b = Expr::local(LocalVar::new("b", Type::Bool));
+        let expr = Expr::bin_op(BinaryOpKind::Add, b.clone(), b);
+        let errors = type_check_expr(&expr, "test");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].message,
+            "operands of `+` must not be of sort Bool, found Bool and Bool"
+        );
+    }
+
+    #[test]
+    fn non_bool_guard_is_rejected() {
+        let x = Expr::local(LocalVar::new("x", Type::Int));
+        let expr = Expr::ite(x.clone(), x.clone(), x);
+        let errors = type_check_expr(&expr, "test");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].message,
+            "condition of a conditional expression must be of sort Bool, found Int"
+        );
+    }
+}
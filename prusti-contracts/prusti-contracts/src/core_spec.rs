@@ -11,8 +11,31 @@ impl<T, E> ::core::result::Result<T, E> {
     fn is_err(&self) -> bool;
 }
 
+#[extern_spec]
+impl<T: Copy, E: Copy> ::core::result::Result<T, E> {
+    #[pure]
+    #[ensures(match self {
+        Ok(value) => result === Some(value),
+        Err(_) => result === None,
+    })]
+    fn ok(self) -> Option<T>;
+
+    #[pure]
+    #[ensures(match self {
+        Ok(_) => result === None,
+        Err(error) => result === Some(error),
+    })]
+    fn err(self) -> Option<E>;
+}
+
 #[extern_spec]
 impl<T, E: ::core::fmt::Debug> ::core::result::Result<T, E> {
     #[requires(matches!(self, Ok(_)))]
     fn unwrap(self) -> T;
 }
+
+#[extern_spec]
+impl<T: ::core::fmt::Debug, E> ::core::result::Result<T, E> {
+    #[requires(matches!(self, Err(_)))]
+    fn unwrap_err(self) -> E;
+}
@@ -21,9 +21,30 @@ pub use prusti_contracts_proc_macros::trusted;
 /// A macro for marking a function as opted into verification.
 pub use prusti_contracts_proc_macros::verified;
 
+/// A macro for marking a method as preserving the length of `self`.
+pub use prusti_contracts_proc_macros::preserves_len;
+
+/// A macro for marking a function as never panicking.
+pub use prusti_contracts_proc_macros::no_panic;
+
+/// A macro for using calls of a pure function as the triggers of the
+/// quantifiers that mention it.
+pub use prusti_contracts_proc_macros::trigger_on_call;
+
+/// A macro for annotating a function with several preconditions and
+/// postconditions at once, e.g. `#[contract(requires = x > 0, ensures = result > x)]`.
+pub use prusti_contracts_proc_macros::contract;
+
+/// A macro for declaring the fields of `self` that a method may modify, e.g.
+/// `#[modifies(self.a, self.b)]`. All other fields are unchanged.
+pub use prusti_contracts_proc_macros::modifies;
+
 /// A macro for type invariants.
 pub use prusti_contracts_proc_macros::invariant;
 
+/// A macro for invariants of a single enum variant.
+pub use prusti_contracts_proc_macros::variant_invariant;
+
 /// A macro for writing a loop body invariant.
 pub use prusti_contracts_proc_macros::body_invariant;
 
@@ -33,9 +54,16 @@ pub use prusti_contracts_proc_macros::prusti_assert;
 /// A macro for writing assumptions using prusti syntax
 pub use prusti_contracts_proc_macros::prusti_assume;
 
+/// A macro for assuming the type invariant of a value
+pub use prusti_contracts_proc_macros::prusti_assume_invariant;
+
 /// A macro for writing refutations using prusti syntax
 pub use prusti_contracts_proc_macros::prusti_refute;
 
+/// A macro for naming the current state, so that later specifications can
+/// refer to it with `old[label](..)`
+pub use prusti_contracts_proc_macros::snapshot;
+
 /// A macro for impl blocks that refine trait specifications.
 pub use prusti_contracts_proc_macros::refine_trait_spec;
 
@@ -63,6 +91,9 @@ pub use prusti_contracts_proc_macros::print_counterexample;
 /// A macro to annotate termination of a function
 pub use prusti_contracts_proc_macros::terminates;
 
+/// A macro for newtypes that specifications can treat like their only field.
+pub use prusti_contracts_proc_macros::transparent;
+
 /// A macro to annotate body variant of a loop to prove termination
 pub use prusti_contracts_proc_macros::body_variant;
 
@@ -95,6 +126,20 @@ mod private {
         ($left:expr, $right:expr $(,)?) => {};
     }
 
+    #[macro_export]
+    macro_rules! closures_agree {
+        ($f:expr, $g:expr, $($arg:ident : $ty:ty),+ $(,)?) => {
+            true
+        };
+    }
+
+    #[macro_export]
+    macro_rules! prusti_disjoint {
+        ($a:expr, $b:expr $(,)?) => {
+            true
+        };
+    }
+
     /// A sequence type
     #[non_exhaustive]
     #[derive(PartialEq, Eq, Copy, Clone)]
@@ -182,6 +227,30 @@ mod private {
         };
     }
 
+    /// States that two closures return equal results for all arguments of the
+    /// given names and types, for example `closures_agree!(f, g, x: i32)`.
+    #[macro_export]
+    macro_rules! closures_agree {
+        ($f:expr, $g:expr, $($arg:ident : $ty:ty),+ $(,)?) => {
+            $crate::forall(
+                (),
+                #[prusti::spec_only]
+                |$($arg: $ty),+| -> bool {
+                    $crate::snapshot_equality(($f)($($arg),+), ($g)($($arg),+))
+                },
+            )
+        };
+    }
+
+    /// States that the references `a` and `b` point to different memory
+    /// locations, for example `prusti_disjoint!(a, b)`.
+    #[macro_export]
+    macro_rules! prusti_disjoint {
+        ($a:expr, $b:expr $(,)?) => {
+            $crate::disjoint($a, $b)
+        };
+    }
+
     impl Neg for Int {
         type Output = Self;
         fn neg(self) -> Self {
@@ -223,6 +292,9 @@ mod private {
         pub fn len(self) -> Int {
             panic!()
         }
+        pub fn tail(self) -> Self {
+            panic!()
+        }
     }
 
     #[macro_export]
@@ -279,6 +351,14 @@ mod private {
         pub fn contains(self, _key: K) -> bool {
             panic!()
         }
+        /// Same as `contains`, named after `HashMap::contains_key`.
+        pub fn contains_key(self, _key: K) -> bool {
+            panic!()
+        }
+        /// Same as `lookup`, named after `HashMap::get`.
+        pub fn get(self, _key: K) -> V {
+            panic!()
+        }
     }
 
     #[macro_export]
@@ -339,6 +419,31 @@ pub fn old<T>(arg: T) -> T {
     arg
 }
 
+/// This function is used to evaluate an expression in the state of the
+/// previous iteration of a loop, that is at the body invariant in the
+/// previous iteration. It is written `old[prev](..)` in body invariants. When
+/// the invariant is checked on entry to the loop, it is the current state.
+pub fn old_prev_iteration<T>(arg: T) -> T {
+    arg
+}
+
+/// This function marks the point where `snapshot!(label)` names the state,
+/// with the label given as the unit struct `L`.
+pub fn snapshot<L>() {}
+
+/// This function is used to evaluate an expression in the state named by
+/// `snapshot!(label)`, with the label given as the unit struct `L`. It is
+/// written `old[label](..)` in specifications.
+pub fn old_at<L, T>(arg: T) -> T {
+    arg
+}
+
+/// The number of completed iterations of the loop whose body invariant uses
+/// this function.
+pub fn iterations() -> usize {
+    0
+}
+
 /// Universal quantifier.
 ///
 /// This is a Prusti-internal representation of the `forall` syntax.
@@ -368,4 +473,43 @@ pub fn snapshot_equality<T>(_l: T, _r: T) -> bool {
     true
 }
 
+/// Non-aliasing of two references. Holds if the two references point to
+/// different memory locations, which is guaranteed by Rust for two mutable
+/// references but not for shared references or references obtained from raw
+/// pointers. Intended to be used in preconditions.
+///
+/// This is a Prusti-internal representation of the `prusti_disjoint!` syntax.
+pub fn disjoint<T: ?Sized>(_a: &T, _b: &T) -> bool {
+    true
+}
+
+/// States that the reference returned by a function borrows from the given
+/// argument, i.e. that the result's lifetime is one of the lifetimes of the
+/// argument. Can only be used in postconditions as
+/// `result_borrows_from(result, arg)`, where `arg` is an argument of the
+/// function. The claim is checked against the lifetimes in the signature of
+/// the function, which must not be shared with the other arguments.
+pub fn result_borrows_from<T: ?Sized, U: ?Sized>(_result: &T, _arg: &U) -> bool {
+    true
+}
+
+/// States that the iterator returned by a function is lazy with respect to
+/// the given argument: the result borrows from `source` (as with
+/// `result_borrows_from`), and the value of `source` is the same as at the
+/// beginning of the function, i.e. nothing was consumed from it yet. Can only
+/// be used in postconditions as `lazy_iterator(result, source)`; unlike in
+/// `result_borrows_from`, the result is taken by value, since iterators are
+/// usually not references themselves.
+pub fn lazy_iterator<T, U: ?Sized>(_result: T, _source: &U) -> bool {
+    true
+}
+
+/// The accessor of a newtype marked with `#[transparent]`, which gives
+/// specifications access to the only field of the type. The implementation is
+/// only generated when verifying, so it can only be used in specifications.
+pub trait Transparent {
+    type Field;
+    fn field(&self) -> &Self::Field;
+}
+
 pub use private::*;
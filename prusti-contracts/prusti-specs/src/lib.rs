@@ -16,6 +16,7 @@ mod type_cond_specs;
 mod parse_closure_macro;
 mod parse_quote_spanned;
 mod predicate;
+mod preserves_len;
 mod rewriter;
 mod span_overrider;
 mod spec_attribute_kind;
@@ -24,16 +25,18 @@ mod type_model;
 mod user_provided_type_params;
 mod print_counterexample;
 
-use proc_macro2::{Span, TokenStream, TokenTree};
+use proc_macro2::{Spacing, Span, TokenStream, TokenTree};
 use quote::{quote, quote_spanned, ToTokens};
 use rewriter::AstRewriter;
 use std::convert::TryInto;
-use syn::{spanned::Spanned, visit::Visit};
+use syn::{parse::Parser, spanned::Spanned, visit::Visit};
 
 use crate::{
-    common::{merge_generics, RewritableReceiver, SelfTypeRewriter},
+    common::{merge_generics, HasSignature, RewritableReceiver, SelfTypeRewriter},
     predicate::{is_predicate_macro, ParsedPredicate},
-    specifications::preparser::{parse_prusti, parse_type_cond_spec, NestedSpec},
+    specifications::preparser::{
+        parse_prusti, parse_type_cond_spec, snapshot_label_type, NestedSpec,
+    },
 };
 pub use extern_spec_rewriter::ExternSpecKind;
 use parse_closure_macro::ClosureWithSpec;
@@ -74,7 +77,9 @@ fn extract_prusti_attributes(
                     | SpecAttributeKind::Ensures
                     | SpecAttributeKind::AfterExpiry
                     | SpecAttributeKind::AssertOnExpiry
-                    | SpecAttributeKind::RefineSpec => {
+                    | SpecAttributeKind::RefineSpec
+                    | SpecAttributeKind::Contract
+                    | SpecAttributeKind::Modifies => {
                         // We need to drop the surrounding parenthesis to make the
                         // tokens identical to the ones passed by the native procedural
                         // macro call.
@@ -83,12 +88,16 @@ fn extract_prusti_attributes(
                         assert!(iter.next().is_none(), "Unexpected shape of an attribute.");
                         group.stream()
                     }
+                    // The parameter of `#[trusted]` is optional.
+                    SpecAttributeKind::Trusted => trusted_attribute_tokens(attr.tokens),
                     // Nothing to do for attributes without arguments.
                     SpecAttributeKind::Pure
                     | SpecAttributeKind::Terminates
-                    | SpecAttributeKind::Trusted
                     | SpecAttributeKind::Predicate
-                    | SpecAttributeKind::Verified => {
+                    | SpecAttributeKind::Verified
+                    | SpecAttributeKind::PreservesLen
+                    | SpecAttributeKind::NoPanic
+                    | SpecAttributeKind::TriggerOnCall => {
                         assert!(attr.tokens.is_empty(), "Unexpected shape of an attribute.");
                         attr.tokens
                     }
@@ -110,6 +119,18 @@ fn extract_prusti_attributes(
     prusti_attributes
 }
 
+/// Returns the tokens of the optional parameter of a non-outer `#[trusted]`
+/// attribute, without the surrounding parenthesis. Tokens of any other shape
+/// are returned unchanged and rejected when generating the spec.
+fn trusted_attribute_tokens(tokens: TokenStream) -> TokenStream {
+    let mut iter = tokens.clone().into_iter();
+    if let (Some(TokenTree::Group(group)), None) = (iter.next(), iter.next()) {
+        group.stream()
+    } else {
+        tokens
+    }
+}
+
 /// Rewrite an item as required by *all* its specification attributes.
 ///
 /// The first attribute (the outer one) needs to be passed via `attr_kind` and `attr` because
@@ -139,6 +160,28 @@ pub fn rewrite_prusti_attributes(
         .to_compile_error();
     }
 
+    // A contract of an `async fn` would have to be checked against the output of the returned
+    // future, which cannot be encoded yet, so such specifications are rejected.
+    if let Some(asyncness) = item.sig().asyncness {
+        if prusti_attributes.iter().any(|(ak, _)| {
+            !matches!(ak, SpecAttributeKind::Trusted | SpecAttributeKind::Verified)
+        }) {
+            return syn::Error::new(
+                asyncness.span(),
+                "specifications of an `async fn` are not supported; \
+                only `#[trusted]` and `#[verified]` can be used",
+            )
+            .to_compile_error();
+        }
+    }
+
+    if prusti_attributes
+        .iter()
+        .any(|(ak, _)| ak == &SpecAttributeKind::PreservesLen)
+    {
+        preserves_len::insert_loop_invariants(&mut item);
+    }
+
     let (generated_spec_items, generated_attributes) =
         handle_result!(generate_spec_and_assertions(prusti_attributes, &item));
 
@@ -170,6 +213,11 @@ fn generate_spec_and_assertions(
             SpecAttributeKind::Verified => generate_for_verified(attr_tokens, item),
             SpecAttributeKind::Terminates => generate_for_terminates(attr_tokens, item),
             SpecAttributeKind::Trusted => generate_for_trusted(attr_tokens, item),
+            SpecAttributeKind::PreservesLen => generate_for_preserves_len(attr_tokens, item),
+            SpecAttributeKind::NoPanic => generate_for_no_panic(attr_tokens, item),
+            SpecAttributeKind::TriggerOnCall => generate_for_trigger_on_call(attr_tokens, item),
+            SpecAttributeKind::Contract => generate_for_contract(attr_tokens, item),
+            SpecAttributeKind::Modifies => generate_for_modifies(attr_tokens, item),
             // Predicates are handled separately below; the entry in the SpecAttributeKind enum
             // only exists so we successfully parse it and emit an error in
             // `check_incompatible_attrs`; so we'll never reach here.
@@ -184,9 +232,48 @@ fn generate_spec_and_assertions(
         generated_attributes.extend(new_attributes);
     }
 
+    propagate_cfg_attributes(item, &mut generated_items);
+
     Ok((generated_items, generated_attributes))
 }
 
+/// Copy the `#[cfg(...)]` attributes of `item` to the spec items generated for it. The spec
+/// items are emitted next to `item` rather than inside it, so without this they would still be
+/// compiled (and collected) when the configuration predicate disables `item`. This happens when
+/// the attributes of `item` are processed eagerly, e.g. within `#[refine_trait_spec]` or
+/// `#[extern_spec]` blocks.
+fn propagate_cfg_attributes(item: &untyped::AnyFnItem, generated_items: &mut [syn::Item]) {
+    let cfg_attributes: Vec<_> = item
+        .attrs()
+        .iter()
+        .filter(|attr| attr.path.is_ident("cfg"))
+        .cloned()
+        .collect();
+    if cfg_attributes.is_empty() {
+        return;
+    }
+    for generated_item in generated_items {
+        match generated_item {
+            syn::Item::Fn(item_fn) => item_fn.attrs.extend(cfg_attributes.iter().cloned()),
+            syn::Item::Impl(item_impl) => item_impl.attrs.extend(cfg_attributes.iter().cloned()),
+            _ => {}
+        }
+    }
+}
+
+/// Generate spec items and attributes to typecheck and later retrieve "preserves_len" annotations.
+/// The loop invariants are inserted separately, because they modify the item itself.
+fn generate_for_preserves_len(attr: TokenStream, item: &untyped::AnyFnItem) -> GeneratedResult {
+    if !attr.is_empty() {
+        return Err(syn::Error::new(
+            attr.span(),
+            "the `#[preserves_len]` attribute does not take parameters",
+        ));
+    }
+    preserves_len::check_receiver(item)?;
+    generate_for_ensures(preserves_len::len_preserved(), item)
+}
+
 /// Generate spec items and attributes to typecheck the and later retrieve "requires" annotations.
 fn generate_for_requires(attr: TokenStream, item: &untyped::AnyFnItem) -> GeneratedResult {
     let mut rewriter = rewriter::AstRewriter::new();
@@ -217,6 +304,76 @@ fn generate_for_ensures(attr: TokenStream, item: &untyped::AnyFnItem) -> Generat
     ))
 }
 
+/// Generate spec items and attributes for a "contract" annotation, which groups several
+/// preconditions and postconditions, by handling each of them like a separate annotation.
+fn generate_for_contract(attr: TokenStream, item: &untyped::AnyFnItem) -> GeneratedResult {
+    let mut generated_items = vec![];
+    let mut generated_attributes = vec![];
+    for (attr_kind, attr_tokens) in parse_contract_clauses(attr)? {
+        let (new_items, new_attributes) = match attr_kind {
+            SpecAttributeKind::Requires => generate_for_requires(attr_tokens, item)?,
+            SpecAttributeKind::Ensures => generate_for_ensures(attr_tokens, item)?,
+            _ => unreachable!(),
+        };
+        generated_items.extend(new_items);
+        generated_attributes.extend(new_attributes);
+    }
+    Ok((generated_items, generated_attributes))
+}
+
+/// Split the arguments of a "contract" annotation into its `requires = ...` and `ensures = ...`
+/// clauses. A clause extends up to the next comma that starts another clause, so that the
+/// specifications themselves may contain commas.
+fn parse_contract_clauses(attr: TokenStream) -> syn::Result<Vec<(SpecAttributeKind, TokenStream)>> {
+    let tokens: Vec<TokenTree> = attr.into_iter().collect();
+    let clause_kind = |index: usize| match (tokens.get(index), tokens.get(index + 1)) {
+        (Some(TokenTree::Ident(ident)), Some(TokenTree::Punct(punct)))
+            if punct.as_char() == '=' && punct.spacing() == Spacing::Alone =>
+        {
+            match ident.to_string().as_str() {
+                "requires" => Some(SpecAttributeKind::Requires),
+                "ensures" => Some(SpecAttributeKind::Ensures),
+                _ => None,
+            }
+        }
+        _ => None,
+    };
+    let is_clause_end = |index: usize| {
+        matches!(&tokens[index], TokenTree::Punct(punct) if punct.as_char() == ',')
+            && (index + 1 == tokens.len() || clause_kind(index + 1).is_some())
+    };
+    let mut clauses = vec![];
+    let mut index = 0;
+    while index < tokens.len() {
+        let Some(attr_kind) = clause_kind(index) else {
+            return Err(syn::Error::new(
+                tokens[index].span(),
+                "expected `requires = ...` or `ensures = ...`",
+            ));
+        };
+        let start = index + 2;
+        let mut end = start;
+        while end < tokens.len() && !is_clause_end(end) {
+            end += 1;
+        }
+        if start == end {
+            return Err(syn::Error::new(
+                tokens[index + 1].span(),
+                "expected a specification after `=`",
+            ));
+        }
+        clauses.push((attr_kind, tokens[start..end].iter().cloned().collect()));
+        index = end + 1;
+    }
+    if clauses.is_empty() {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            "the `#[contract]` attribute needs at least one `requires` or `ensures` clause",
+        ));
+    }
+    Ok(clauses)
+}
+
 /// Generate spec items and attributes to typecheck and later retrieve "after_expiry" annotations.
 fn generate_for_after_expiry(attr: TokenStream, item: &untyped::AnyFnItem) -> GeneratedResult {
     let mut rewriter = rewriter::AstRewriter::new();
@@ -330,29 +487,107 @@ fn generate_for_pure_refinements(item: &untyped::AnyFnItem) -> GeneratedResult {
     ))
 }
 
-/// Generate spec items and attributes to typecheck and later retrieve "trusted" annotations.
-fn generate_for_trusted(attr: TokenStream, item: &untyped::AnyFnItem) -> GeneratedResult {
+/// Generate spec items and attributes to typecheck and later retrieve "no_panic" annotations.
+fn generate_for_no_panic(attr: TokenStream, item: &untyped::AnyFnItem) -> GeneratedResult {
     if !attr.is_empty() {
         return Err(syn::Error::new(
             attr.span(),
-            "the `#[trusted]` attribute does not take parameters",
+            "the `#[no_panic]` attribute does not take parameters",
+        ));
+    }
+
+    Ok((
+        vec![],
+        vec![parse_quote_spanned! {item.span()=>
+            #[prusti::no_panic]
+        }],
+    ))
+}
+
+/// Generate spec items and attributes to typecheck and later retrieve "trigger_on_call" annotations.
+fn generate_for_trigger_on_call(attr: TokenStream, item: &untyped::AnyFnItem) -> GeneratedResult {
+    if !attr.is_empty() {
+        return Err(syn::Error::new(
+            attr.span(),
+            "the `#[trigger_on_call]` attribute does not take parameters",
+        ));
+    }
+
+    Ok((
+        vec![],
+        vec![parse_quote_spanned! {item.span()=>
+            #[prusti::trigger_on_call]
+        }],
+    ))
+}
+
+/// Generate spec items and attributes to typecheck and later retrieve "modifies" annotations.
+/// The parameters of the attribute are the fields of `self` that the method may modify, e.g.
+/// `#[modifies(self.a, self.b)]`; they are passed to Prusti as a comma-separated list.
+fn generate_for_modifies(attr: TokenStream, item: &untyped::AnyFnItem) -> GeneratedResult {
+    if item.sig().receiver().is_none() {
+        return Err(syn::Error::new(
+            item.sig().span(),
+            "the `#[modifies]` attribute can only be used on methods with a `self` receiver",
         ));
     }
+    let fields =
+        syn::punctuated::Punctuated::<syn::Expr, syn::Token![,]>::parse_terminated.parse2(attr)?;
+    let mut field_names = vec![];
+    for field in &fields {
+        match field {
+            syn::Expr::Field(syn::ExprField {
+                base: box syn::Expr::Path(base),
+                member: syn::Member::Named(name),
+                ..
+            }) if base.path.is_ident("self") => field_names.push(name.to_string()),
+            _ => {
+                return Err(syn::Error::new(
+                    field.span(),
+                    "expected a field of `self`, e.g. `self.field`",
+                ))
+            }
+        }
+    }
+    let field_names = field_names.join(",");
 
     Ok((
         vec![],
         vec![parse_quote_spanned! {item.span()=>
-            #[prusti::trusted]
+            #[prusti::modifies = #field_names]
         }],
     ))
 }
 
+/// Generate spec items and attributes to typecheck and later retrieve "trusted" annotations.
+/// The optional parameter of the attribute is a string literal stating why the
+/// function is trusted, e.g. `#[trusted("FFI boundary")]`.
+fn generate_for_trusted(attr: TokenStream, item: &untyped::AnyFnItem) -> GeneratedResult {
+    let mut attrs = vec![parse_quote_spanned! {item.span()=>
+        #[prusti::trusted]
+    }];
+    if !attr.is_empty() {
+        let reason: syn::LitStr = syn::parse2(attr).map_err(|err| {
+            syn::Error::new(
+                err.span(),
+                "the parameter of the `#[trusted]` attribute must be a string literal \
+                stating the reason for trusting the function",
+            )
+        })?;
+        attrs.push(parse_quote_spanned! {item.span()=>
+            #[prusti::trusted_reason = #reason]
+        });
+    }
+
+    Ok((vec![], attrs))
+}
+
 /// Generate spec items and attributes to typecheck and later retrieve "trusted" annotations.
 fn generate_for_trusted_for_types(attr: TokenStream, item: &syn::DeriveInput) -> GeneratedResult {
     if !attr.is_empty() {
         return Err(syn::Error::new(
             attr.span(),
-            "the `#[trusted]` attribute does not take parameters",
+            "the `#[trusted]` attribute of a type does not take parameters",
         ));
     }
     // TODO: reduce duplication with `invariant`
@@ -431,10 +666,26 @@ pub fn prusti_assume(tokens: TokenStream) -> TokenStream {
     generate_expression_closure(&AstRewriter::process_prusti_assumption, tokens)
 }
 
+pub fn prusti_assume_invariant(tokens: TokenStream) -> TokenStream {
+    generate_expression_closure(&AstRewriter::process_prusti_invariant_assumption, tokens)
+}
+
 pub fn prusti_refutation(tokens: TokenStream) -> TokenStream {
     generate_expression_closure(&AstRewriter::process_prusti_refutation, tokens)
 }
 
+/// Generates the marker of `snapshot!(label)`. The label is declared as a unit
+/// struct, which `old[label](..)` passes to `prusti_contracts::old_at`.
+pub fn snapshot(tokens: TokenStream) -> TokenStream {
+    let label: syn::Ident = handle_result!(syn::parse2(tokens));
+    let label_type = snapshot_label_type(&label);
+    quote_spanned! {label.span()=>
+        #[allow(non_camel_case_types, dead_code)]
+        struct #label_type;
+        ::prusti_contracts::snapshot::<#label_type>();
+    }
+}
+
 /// Generates the TokenStream encoding an expression using prusti syntax
 /// Used for body invariants, assertions, and assumptions
 fn generate_expression_closure(
@@ -562,6 +813,12 @@ pub fn refine_trait_spec(_attr: TokenStream, tokens: TokenStream) -> TokenStream
             syn::ImplItem::Method(method) => {
                 let mut method_item = untyped::AnyFnItem::ImplMethod(method);
                 let prusti_attributes: Vec<_> = extract_prusti_attributes(&mut method_item);
+                if prusti_attributes
+                    .iter()
+                    .any(|(kind, _)| kind == &SpecAttributeKind::PreservesLen)
+                {
+                    preserves_len::insert_loop_invariants(&mut method_item);
+                }
 
                 let illegal_attribute_span = prusti_attributes
                     .iter()
@@ -600,9 +857,11 @@ pub fn refine_trait_spec(_attr: TokenStream, tokens: TokenStream) -> TokenStream
 
                 let ParsedPredicate::Impl(predicate) = parsed_predicate else { unreachable!() };
 
-                // Patch spec function: Rewrite self with _self: <SpecStruct>
-                let syn::Item::Fn(spec_function) = predicate.spec_function else { unreachable!() };
-                generated_spec_items.push(spec_function);
+                // Patch spec functions: Rewrite self with _self: <SpecStruct>
+                for spec_function in predicate.spec_functions {
+                    let syn::Item::Fn(spec_function) = spec_function else { unreachable!() };
+                    generated_spec_items.push(spec_function);
+                }
 
                 // Add patched predicate function to new items
                 new_items.push(syn::ImplItem::Method(predicate.patched_function));
@@ -627,17 +886,17 @@ pub fn refine_trait_spec(_attr: TokenStream, tokens: TokenStream) -> TokenStream
 }
 
 pub fn trusted(attr: TokenStream, tokens: TokenStream) -> TokenStream {
-    if !attr.is_empty() {
-        return syn::Error::new(
-            attr.span(),
-            "the `#[trusted]` attribute does not take parameters",
-        )
-        .to_compile_error();
-    }
-
     // `#[trusted]` can be applied to both types and to methods, figure out
     // which one by trying to parse a `DeriveInput`.
     if syn::parse2::<syn::DeriveInput>(tokens.clone()).is_ok() {
+        if !attr.is_empty() {
+            return syn::Error::new(
+                attr.span(),
+                "the `#[trusted]` attribute of a type does not take parameters",
+            )
+            .to_compile_error();
+        }
+
         // TODO: reduce duplication with `invariant`
         let mut rewriter = rewriter::AstRewriter::new();
         let spec_id = rewriter.generate_spec_id();
@@ -708,6 +967,10 @@ pub fn trusted(attr: TokenStream, tokens: TokenStream) -> TokenStream {
 }
 
 pub fn invariant(attr: TokenStream, tokens: TokenStream) -> TokenStream {
+    if let Ok(item_trait) = syn::parse2::<syn::ItemTrait>(tokens.clone()) {
+        return trait_invariant(attr, item_trait);
+    }
+
     let mut rewriter = rewriter::AstRewriter::new();
     let spec_id = rewriter.generate_spec_id();
     let spec_id_str = spec_id.to_string();
@@ -734,6 +997,132 @@ pub fn invariant(attr: TokenStream, tokens: TokenStream) -> TokenStream {
         }
     };
 
+    generate_type_spec_impl(&item, spec_item)
+}
+
+/// An invariant on a trait is a contract that all values of the types
+/// implementing the trait have to satisfy. It is added to the trait as a
+/// provided method, which the encoder instantiates with each implementing type
+/// as `Self`.
+fn trait_invariant(attr: TokenStream, mut item_trait: syn::ItemTrait) -> TokenStream {
+    let item_span = item_trait.span();
+    if !item_trait.generics.params.is_empty() {
+        return syn::Error::new(
+            item_trait.generics.span(),
+            "the `#[invariant]` attribute is only supported on traits without generic parameters",
+        )
+        .to_compile_error();
+    }
+
+    let mut rewriter = rewriter::AstRewriter::new();
+    let spec_id = rewriter.generate_spec_id();
+    let spec_id_str = spec_id.to_string();
+    let item_ident = item_trait.ident.clone();
+    let item_name = syn::Ident::new(
+        &format!("prusti_trait_invariant_item_{item_ident}_{spec_id}"),
+        item_span,
+    );
+
+    let attr = handle_result!(parse_prusti(attr));
+
+    let spec_item: syn::TraitItemMethod = parse_quote_spanned! {item_span=>
+        #[allow(unused_must_use, unused_parens, unused_variables, dead_code, non_snake_case)]
+        #[prusti::spec_only]
+        #[prusti::trait_invariant_spec]
+        #[prusti::spec_id = #spec_id_str]
+        fn #item_name(self) -> bool where Self: Sized {
+            !!((#attr) : bool)
+        }
+    };
+    item_trait.items.push(syn::TraitItem::Method(spec_item));
+
+    quote_spanned! { item_span =>
+        #[prusti::specs_version = #SPECS_VERSION]
+        #item_trait
+    }
+}
+
+pub fn variant_invariant(attr: TokenStream, tokens: TokenStream) -> TokenStream {
+    let mut rewriter = rewriter::AstRewriter::new();
+    let spec_id = rewriter.generate_spec_id();
+    let spec_id_str = spec_id.to_string();
+
+    let item: syn::DeriveInput = handle_result!(syn::parse2(tokens));
+    let item_span = item.span();
+    let item_ident = item.ident.clone();
+    if !matches!(item.data, syn::Data::Enum(_)) {
+        return syn::Error::new(
+            item_span,
+            "the `#[variant_invariant]` attribute can only be used on enums",
+        )
+        .to_compile_error();
+    }
+    let item_name = syn::Ident::new(
+        &format!("prusti_variant_invariant_item_{item_ident}_{spec_id}"),
+        item_span,
+    );
+
+    let (pattern, attr) = handle_result!(split_variant_invariant(attr));
+    let variant_ident = handle_result!(pattern
+        .clone()
+        .into_iter()
+        .find_map(|token| match token {
+            TokenTree::Ident(ident) => Some(ident),
+            _ => None,
+        })
+        .ok_or_else(|| syn::Error::new(item_span, "expected the pattern of a variant")));
+    let variant_name = variant_ident.to_string();
+    let attr = handle_result!(parse_prusti(attr));
+
+    // The invariant only constrains values of the given variant; the encoder
+    // guards it with the discriminant of the variant.
+    let spec_item: syn::ItemFn = parse_quote_spanned! {item_span=>
+        #[allow(unused_must_use, unused_parens, unused_variables, dead_code, non_snake_case)]
+        #[allow(unreachable_patterns)]
+        #[prusti::spec_only]
+        #[prusti::variant_invariant_spec = #variant_name]
+        #[prusti::spec_id = #spec_id_str]
+        fn #item_name(self) -> bool {
+            match self {
+                Self::#pattern => !!((#attr) : bool),
+                _ => true,
+            }
+        }
+    };
+
+    generate_type_spec_impl(&item, spec_item)
+}
+
+/// Splits the arguments of `#[variant_invariant(Variant(x) => x > 0)]` into
+/// the pattern of the variant and the invariant.
+fn split_variant_invariant(attr: TokenStream) -> syn::Result<(TokenStream, TokenStream)> {
+    let tokens: Vec<TokenTree> = attr.into_iter().collect();
+    let arrow = tokens.windows(2).position(|pair| {
+        matches!(
+            pair,
+            [TokenTree::Punct(first), TokenTree::Punct(second)]
+                if first.as_char() == '='
+                    && first.spacing() == Spacing::Joint
+                    && second.as_char() == '>'
+        )
+    });
+    match arrow {
+        Some(index) if index > 0 && index + 2 < tokens.len() => Ok((
+            tokens[..index].iter().cloned().collect(),
+            tokens[index + 2..].iter().cloned().collect(),
+        )),
+        _ => Err(syn::Error::new(
+            Span::call_site(),
+            "expected `Variant(..) => invariant`",
+        )),
+    }
+}
+
+/// Generates an inherent impl block of the type `item` containing the
+/// specification function `spec_item` of a type-level specification.
+fn generate_type_spec_impl(item: &syn::DeriveInput, spec_item: syn::ItemFn) -> TokenStream {
+    let item_span = item.span();
+    let item_ident = item.ident.clone();
     let generics = item.generics.clone();
     let generics_idents = generics
         .params
@@ -864,7 +1253,13 @@ fn extract_prusti_attributes_for_types(
                     SpecAttributeKind::Invariant => unreachable!("invariant on type"),
                     SpecAttributeKind::Predicate => unreachable!("predicate on type"),
                     SpecAttributeKind::Terminates => unreachable!("terminates on type"),
-                    SpecAttributeKind::Trusted | SpecAttributeKind::Model => {
+                    SpecAttributeKind::PreservesLen => unreachable!("preserves_len on type"),
+                    SpecAttributeKind::NoPanic => unreachable!("no_panic on type"),
+                    SpecAttributeKind::TriggerOnCall => unreachable!("trigger_on_call on type"),
+                    SpecAttributeKind::Contract => unreachable!("contract on type"),
+                    SpecAttributeKind::Modifies => unreachable!("modifies on type"),
+                    SpecAttributeKind::Trusted => trusted_attribute_tokens(attr.tokens),
+                    SpecAttributeKind::Model => {
                         assert!(attr.tokens.is_empty(), "Unexpected shape of an attribute.");
                         attr.tokens
                     }
@@ -909,6 +1304,11 @@ fn generate_spec_and_assertions_for_types(
             SpecAttributeKind::Invariant => unreachable!(),
             SpecAttributeKind::RefineSpec => unreachable!(),
             SpecAttributeKind::Terminates => unreachable!(),
+            SpecAttributeKind::PreservesLen => unreachable!(),
+            SpecAttributeKind::NoPanic => unreachable!(),
+            SpecAttributeKind::TriggerOnCall => unreachable!(),
+            SpecAttributeKind::Contract => unreachable!(),
+            SpecAttributeKind::Modifies => unreachable!(),
             SpecAttributeKind::Trusted => generate_for_trusted_for_types(attr_tokens, item),
             SpecAttributeKind::Model => generate_for_model(attr_tokens, item),
             SpecAttributeKind::PrintCounterexample => {
@@ -1003,6 +1403,43 @@ pub fn print_counterexample(attr: TokenStream, tokens: TokenStream) -> TokenStre
         .to_compile_error()
     }
 }
+/// Marks a newtype with `#[prusti::transparent]` and generates its accessor,
+/// an implementation of `prusti_contracts::Transparent` that returns a
+/// reference to the only field of the type.
+pub fn transparent(tokens: TokenStream) -> TokenStream {
+    let item: syn::DeriveInput = handle_result!(syn::parse2(tokens));
+    let item_span = item.span();
+    let field = match &item.data {
+        syn::Data::Struct(data) if data.fields.len() == 1 => data.fields.iter().next().unwrap(),
+        _ => {
+            return syn::Error::new(
+                item_span,
+                "only structs with a single field can be marked as `#[transparent]`",
+            )
+            .to_compile_error()
+        }
+    };
+    let field_ty = &field.ty;
+    let member = match &field.ident {
+        Some(ident) => syn::Member::Named(ident.clone()),
+        None => syn::Member::Unnamed(syn::Index::from(0)),
+    };
+    let item_ident = &item.ident;
+    let (impl_generics, ty_generics, where_clause) = item.generics.split_for_impl();
+    quote_spanned! {item_span=>
+        #[prusti::transparent]
+        #item
+        impl #impl_generics ::prusti_contracts::Transparent
+            for #item_ident #ty_generics #where_clause
+        {
+            type Field = #field_ty;
+            fn field(&self) -> &#field_ty {
+                &self.#member
+            }
+        }
+    }
+}
+
 pub fn ghost(tokens: TokenStream) -> TokenStream {
     let mut rewriter = rewriter::AstRewriter::new();
     let callsite_span = Span::call_site();
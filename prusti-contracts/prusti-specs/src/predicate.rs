@@ -13,12 +13,15 @@ pub struct PredicateWithBody<T: ToTokens> {
     /// The function which was inside the macro to be used at the definition-site of the macro
     /// The body of the function is replaced (`unimplemented!()`)
     pub patched_function: T,
-    pub spec_function: syn::Item,
+    /// One spec function per body fragment; the predicate is their conjunction
+    pub spec_functions: Vec<syn::Item>,
 }
 
 impl<T: ToTokens> ToTokens for PredicateWithBody<T> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        self.spec_function.to_tokens(tokens);
+        for spec_function in &self.spec_functions {
+            spec_function.to_tokens(tokens);
+        }
         self.patched_function.to_tokens(tokens);
     }
 }
@@ -97,35 +100,38 @@ fn parse_predicate_internal(
         syn::ReturnType::Type(_, box typ) => typ.to_token_stream(),
     };
 
-    if input.body.is_some() {
+    if !input.bodies.is_empty() {
+        if input.bodies.len() > 1 && return_type.to_string() != "bool" {
+            return Err(syn::Error::new(
+                input.fn_sig.output.span(),
+                "`predicate!` with multiple bodies must return `bool`",
+            ));
+        }
         let mut rewriter = rewriter::AstRewriter::new();
-        let spec_id = rewriter.generate_spec_id();
+        let spec_ids: Vec<_> = input
+            .bodies
+            .iter()
+            .map(|_| rewriter.generate_spec_id())
+            .collect();
 
         if in_spec_refinement {
             let patched_function: syn::ImplItemMethod =
-                patch_predicate_macro_body(&input, span, spec_id);
-            let spec_function = generate_spec_function(
-                input.body.unwrap(),
-                return_type,
-                spec_id,
-                &patched_function,
-            )?;
+                patch_predicate_macro_body(&input, span, &spec_ids);
+            let spec_functions =
+                generate_spec_functions(input.bodies, return_type, spec_ids, &patched_function)?;
 
             Ok(ParsedPredicate::Impl(PredicateWithBody {
-                spec_function,
+                spec_functions,
                 patched_function,
             }))
         } else {
-            let patched_function: syn::ItemFn = patch_predicate_macro_body(&input, span, spec_id);
-            let spec_function = generate_spec_function(
-                input.body.unwrap(),
-                return_type,
-                spec_id,
-                &patched_function,
-            )?;
+            let patched_function: syn::ItemFn =
+                patch_predicate_macro_body(&input, span, &spec_ids);
+            let spec_functions =
+                generate_spec_functions(input.bodies, return_type, spec_ids, &patched_function)?;
 
             Ok(ParsedPredicate::FreeStanding(PredicateWithBody {
-                spec_function,
+                spec_functions,
                 patched_function,
             }))
         }
@@ -146,15 +152,15 @@ fn parse_predicate_internal(
 fn patch_predicate_macro_body<R: Parse>(
     predicate: &PredicateFnInput,
     input_span: Span,
-    spec_id: SpecificationId,
+    spec_ids: &[SpecificationId],
 ) -> R {
     let visibility = &predicate.visibility;
     let signature = &predicate.fn_sig;
-    let spec_id_str = spec_id.to_string();
+    let spec_id_strs = spec_ids.iter().map(|spec_id| spec_id.to_string());
 
     parse_quote_spanned!(input_span=>
         #[allow(unused_must_use, unused_variables, dead_code)]
-        #[prusti::pred_spec_id_ref = #spec_id_str]
+        #(#[prusti::pred_spec_id_ref = #spec_id_strs])*
         #[prusti::specs_version = #SPECS_VERSION]
         #visibility #signature {
             unimplemented!("predicate")
@@ -162,26 +168,33 @@ fn patch_predicate_macro_body<R: Parse>(
     )
 }
 
-fn generate_spec_function<T: HasSignature + Spanned>(
-    body: TokenStream,
+fn generate_spec_functions<T: HasSignature + Spanned>(
+    bodies: Vec<TokenStream>,
     return_type: TokenStream,
-    spec_id: SpecificationId,
+    spec_ids: Vec<SpecificationId>,
     patched_function: &T,
-) -> syn::Result<syn::Item> {
+) -> syn::Result<Vec<syn::Item>> {
     let mut rewriter = rewriter::AstRewriter::new();
-    rewriter.process_assertion(
-        rewriter::SpecItemType::Predicate(return_type),
-        spec_id,
-        body,
-        patched_function,
-    )
+    bodies
+        .into_iter()
+        .zip(spec_ids)
+        .map(|(body, spec_id)| {
+            rewriter.process_assertion(
+                rewriter::SpecItemType::Predicate(return_type.clone()),
+                spec_id,
+                body,
+                patched_function,
+            )
+        })
+        .collect()
 }
 
 #[derive(Debug)]
 struct PredicateFnInput {
     visibility: Option<syn::Visibility>,
     fn_sig: syn::Signature,
-    body: Option<TokenStream>,
+    /// The body fragments of the predicate, empty for abstract predicates
+    bodies: Vec<TokenStream>,
 }
 
 impl syn::parse::Parse for PredicateFnInput {
@@ -189,21 +202,24 @@ impl syn::parse::Parse for PredicateFnInput {
         let visibility = input.parse().ok();
         let fn_sig = input.parse()?;
 
-        let body = if input.peek(syn::Token![;]) {
+        let mut bodies = vec![];
+        if input.peek(syn::Token![;]) {
             let _semi: syn::Token![;] = input.parse()?;
-            None
         } else {
-            let brace_content;
-            let _brace_token = syn::braced!(brace_content in input);
-            let parsed_body: TokenStream = brace_content.parse()?;
-            // add the braces back to allow function-like syntax
-            Some(quote_spanned!(parsed_body.span()=> { #parsed_body }))
-        };
+            // Multiple bodies are conjoined
+            while !input.is_empty() {
+                let brace_content;
+                let _brace_token = syn::braced!(brace_content in input);
+                let parsed_body: TokenStream = brace_content.parse()?;
+                // add the braces back to allow function-like syntax
+                bodies.push(quote_spanned!(parsed_body.span()=> { #parsed_body }));
+            }
+        }
 
         Ok(PredicateFnInput {
             visibility,
             fn_sig,
-            body,
+            bodies,
         })
     }
 }
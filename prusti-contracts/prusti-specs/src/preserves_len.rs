@@ -0,0 +1,63 @@
+use crate::{common::HasSignature, specifications::untyped};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, spanned::Spanned, visit_mut::VisitMut};
+
+/// The property that is preserved by a `#[preserves_len]` method.
+pub fn len_preserved() -> TokenStream {
+    quote! { self.len() == old(self.len()) }
+}
+
+/// Checks that `#[preserves_len]` is attached to a method with a `self` receiver.
+pub fn check_receiver(item: &untyped::AnyFnItem) -> syn::Result<()> {
+    if item.sig().receiver().is_none() {
+        return Err(syn::Error::new(
+            item.sig().span(),
+            "the `#[preserves_len]` attribute can only be used on methods with a `self` receiver",
+        ));
+    }
+    Ok(())
+}
+
+/// Inserts the length invariant at the beginning of every loop in the body of `item`.
+pub fn insert_loop_invariants(item: &mut untyped::AnyFnItem) {
+    if let Some(block) = item.block_mut() {
+        LoopInvariantInserter.visit_block_mut(block);
+    }
+}
+
+struct LoopInvariantInserter;
+
+impl LoopInvariantInserter {
+    fn insert_invariant(body: &mut syn::Block) {
+        let property = len_preserved();
+        body.stmts.insert(
+            0,
+            parse_quote! {
+                body_invariant!(#property);
+            },
+        );
+    }
+}
+
+impl VisitMut for LoopInvariantInserter {
+    fn visit_expr_while_mut(&mut self, expr: &mut syn::ExprWhile) {
+        syn::visit_mut::visit_expr_while_mut(self, expr);
+        Self::insert_invariant(&mut expr.body);
+    }
+
+    fn visit_expr_loop_mut(&mut self, expr: &mut syn::ExprLoop) {
+        syn::visit_mut::visit_expr_loop_mut(self, expr);
+        Self::insert_invariant(&mut expr.body);
+    }
+
+    fn visit_expr_for_loop_mut(&mut self, expr: &mut syn::ExprForLoop) {
+        syn::visit_mut::visit_expr_for_loop_mut(self, expr);
+        Self::insert_invariant(&mut expr.body);
+    }
+
+    // Loops in closures and nested items are not loops of the method itself.
+    fn visit_expr_closure_mut(&mut self, _expr: &mut syn::ExprClosure) {}
+
+    fn visit_item_mut(&mut self, _item: &mut syn::Item) {}
+}
@@ -2,7 +2,9 @@ use crate::{
     common::HasSignature,
     specifications::{
         common::{SpecificationId, SpecificationIdGenerator},
-        preparser::{parse_prusti, parse_prusti_assert_pledge, parse_prusti_pledge},
+        preparser::{
+            parse_prusti, parse_prusti_assert_pledge, parse_prusti_pledge, reject_snapshot_labels,
+        },
         untyped,
     },
 };
@@ -152,6 +154,7 @@ impl AstRewriter {
         tokens: TokenStream,
         item: &T,
     ) -> syn::Result<syn::Item> {
+        reject_snapshot_labels(&tokens)?;
         self.generate_spec_item_fn(spec_type, spec_id, parse_prusti(tokens)?, item)
     }
 
@@ -162,12 +165,50 @@ impl AstRewriter {
         tokens: TokenStream,
         item: &untyped::AnyFnItem,
     ) -> syn::Result<syn::Item> {
-        self.generate_spec_item_fn(
-            SpecItemType::Pledge,
-            spec_id,
-            parse_prusti_pledge(tokens)?,
-            item,
-        )
+        reject_snapshot_labels(&tokens)?;
+        let (reference, rhs) = parse_prusti_pledge(tokens)?;
+        let mut spec_item = self.generate_spec_item_fn(SpecItemType::Pledge, spec_id, rhs, item)?;
+        self.attach_pledge_reference("after_expiry", reference, item, &mut spec_item)?;
+        Ok(spec_item)
+    }
+
+    /// Resolve the reference of a pledge, i.e. the `a` in `a => b`. If the reference is missing
+    /// or is `result`, the pledge is attached to the borrow of the result. Otherwise, the
+    /// reference must be an argument of `item`, whose index is recorded in a
+    /// `#[prusti::pledge_reference]` attribute on `spec_item`.
+    fn attach_pledge_reference(
+        &self,
+        attr_name: &str,
+        reference: Option<TokenStream>,
+        item: &untyped::AnyFnItem,
+        spec_item: &mut syn::Item,
+    ) -> syn::Result<()> {
+        let Some(reference) = reference else {
+            return Ok(());
+        };
+        let name = reference.to_string();
+        if name == "result" {
+            return Ok(());
+        }
+        let index = item.sig().inputs.iter().position(|input| match input {
+            syn::FnArg::Receiver(_) => name == "self",
+            syn::FnArg::Typed(syn::PatType { pat, .. }) => {
+                matches!(&**pat, syn::Pat::Ident(syn::PatIdent { ident, .. }) if *ident == name)
+            }
+        });
+        let Some(index) = index else {
+            return Err(syn::Error::new(
+                reference.span(),
+                format!("reference of {attr_name} must be \"result\" or an argument of the function"),
+            ));
+        };
+        let index_str = index.to_string();
+        if let syn::Item::Fn(spec_item) = spec_item {
+            spec_item.attrs.push(parse_quote_spanned! {reference.span()=>
+                #[prusti::pledge_reference = #index_str]
+            });
+        }
+        Ok(())
     }
 
     pub fn process_pure_refinement(
@@ -202,9 +243,12 @@ impl AstRewriter {
         tokens: TokenStream,
         item: &untyped::AnyFnItem,
     ) -> syn::Result<(syn::Item, syn::Item)> {
-        let (lhs, rhs) = parse_prusti_assert_pledge(tokens)?;
+        reject_snapshot_labels(&tokens)?;
+        let (reference, lhs, rhs) = parse_prusti_assert_pledge(tokens)?;
         let lhs_item = self.generate_spec_item_fn(SpecItemType::Pledge, spec_id_lhs, lhs, item)?;
-        let rhs_item = self.generate_spec_item_fn(SpecItemType::Pledge, spec_id_rhs, rhs, item)?;
+        let mut rhs_item =
+            self.generate_spec_item_fn(SpecItemType::Pledge, spec_id_rhs, rhs, item)?;
+        self.attach_pledge_reference("assert_on_expiry", reference, item, &mut rhs_item)?;
         Ok((lhs_item, rhs_item))
     }
 
@@ -255,6 +299,29 @@ impl AstRewriter {
         self.process_prusti_expression(quote! {prusti_assumption}, spec_id, tokens)
     }
 
+    /// Parse the value whose type invariant is assumed into a Rust expression
+    pub fn process_prusti_invariant_assumption(
+        &mut self,
+        spec_id: SpecificationId,
+        tokens: TokenStream,
+    ) -> syn::Result<TokenStream> {
+        let expr: syn::Expr = syn::parse2(tokens)?;
+        let spec_id_str = spec_id.to_string();
+        // The closure only borrows the value, so that the encoder can find the
+        // borrowed place among the captures of the closure.
+        Ok(quote_spanned! {expr.span()=>
+            {
+                #[prusti::spec_only]
+                #[prusti::prusti_invariant_assumption]
+                #[prusti::spec_id = #spec_id_str]
+                || -> bool {
+                    let _prusti_value = &#expr;
+                    true
+                };
+            }
+        })
+    }
+
     /// Parse a prusti refute into a Rust expression
     pub fn process_prusti_refutation(
         &mut self,
@@ -18,6 +18,11 @@ pub enum SpecAttributeKind {
     Terminates = 10,
     PrintCounterexample = 11,
     Verified = 12,
+    PreservesLen = 13,
+    NoPanic = 14,
+    TriggerOnCall = 15,
+    Contract = 16,
+    Modifies = 17,
 }
 
 impl TryFrom<String> for SpecAttributeKind {
@@ -37,6 +42,11 @@ impl TryFrom<String> for SpecAttributeKind {
             "model" => Ok(SpecAttributeKind::Model),
             "print_counterexample" => Ok(SpecAttributeKind::PrintCounterexample),
             "verified" => Ok(SpecAttributeKind::Verified),
+            "preserves_len" => Ok(SpecAttributeKind::PreservesLen),
+            "no_panic" => Ok(SpecAttributeKind::NoPanic),
+            "trigger_on_call" => Ok(SpecAttributeKind::TriggerOnCall),
+            "contract" => Ok(SpecAttributeKind::Contract),
+            "modifies" => Ok(SpecAttributeKind::Modifies),
             _ => Err(name),
         }
     }
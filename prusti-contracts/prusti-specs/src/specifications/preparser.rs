@@ -1,7 +1,7 @@
 /// The preparser processes Prusti syntax into Rust syntax.
-use proc_macro2::{Delimiter, Span, TokenStream, TokenTree};
+use proc_macro2::{Delimiter, Ident, Span, TokenStream, TokenTree};
 use proc_macro2::{Punct, Spacing::*};
-use quote::{quote, quote_spanned, ToTokens};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
 use std::collections::VecDeque;
 use syn::{
     parse::{Parse, ParseStream},
@@ -22,39 +22,57 @@ pub fn parse_prusti(tokens: TokenStream) -> syn::Result<TokenStream> {
     syn::parse2::<syn::Expr>(parsed.clone())?;
     Ok(parsed)
 }
-pub fn parse_prusti_pledge(tokens: TokenStream) -> syn::Result<TokenStream> {
-    // TODO: pledges with reference that is not "result" are not supported;
-    // for this reason we assert here that the reference (if there is any) is "result"
-    // then return the RHS only
+/// Parses a pledge, returning its reference (if there is any) and its body. The reference
+/// is resolved against the signature of the annotated function by the rewriter.
+pub fn parse_prusti_pledge(tokens: TokenStream) -> syn::Result<(Option<TokenStream>, TokenStream)> {
     let (reference, rhs) = PrustiTokenStream::new(tokens).parse_pledge()?;
-    if let Some(reference) = reference {
-        if reference.to_string() != "result" {
-            return err(
-                reference.span(),
-                "reference of after_expiry must be \"result\"",
-            );
-        }
-    }
     syn::parse2::<syn::Expr>(rhs.clone())?;
-    Ok(rhs)
+    Ok((reference, rhs))
 }
 
-pub fn parse_prusti_assert_pledge(tokens: TokenStream) -> syn::Result<(TokenStream, TokenStream)> {
-    // TODO: pledges with reference that is not "result" are not supported;
-    // for this reason we assert here that the reference (if there is any) is "result"
-    // then return the RHS only
+/// Parses an assert pledge, returning its reference (if there is any) and its two
+/// assertions.
+pub fn parse_prusti_assert_pledge(
+    tokens: TokenStream,
+) -> syn::Result<(Option<TokenStream>, TokenStream, TokenStream)> {
     let (reference, lhs, rhs) = PrustiTokenStream::new(tokens).parse_assert_pledge()?;
-    if let Some(reference) = reference {
-        if reference.to_string() != "result" {
-            return err(
-                reference.span(),
-                "reference of assert_on_expiry must be \"result\"",
-            );
-        }
-    }
     syn::parse2::<syn::Expr>(lhs.clone())?;
     syn::parse2::<syn::Expr>(rhs.clone())?;
-    Ok((lhs, rhs))
+    Ok((reference, lhs, rhs))
+}
+
+/// The unit struct that `snapshot!(label)` declares to name the state, and that
+/// `old[label](..)` refers to.
+pub fn snapshot_label_type(label: &Ident) -> Ident {
+    format_ident!("prusti_snapshot_{}", label)
+}
+
+/// Rejects `old[label](..)` in the contract of a function: the labels of
+/// `snapshot!(label)` name states in the body of the function, to which the
+/// contract cannot refer.
+pub fn reject_snapshot_labels(tokens: &TokenStream) -> syn::Result<()> {
+    let mut tokens = tokens.clone().into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Ident(ident) if ident == "old" => {
+                if let Some(TokenTree::Group(group)) = tokens.peek() {
+                    let is_snapshot_label = group.delimiter() == Delimiter::Bracket
+                        && syn::parse2::<Ident>(group.stream())
+                            .map_or(false, |label| label != "prev");
+                    if is_snapshot_label {
+                        return err(
+                            group.span(),
+                            "`old[label](..)` can only be used in assertions in the body of \
+                            the function that takes the snapshot",
+                        );
+                    }
+                }
+            }
+            TokenTree::Group(group) => reject_snapshot_labels(&group.stream())?,
+            _ => (),
+        }
+    }
+    Ok(())
 }
 
 pub fn parse_type_cond_spec(tokens: TokenStream) -> syn::Result<TypeCondSpecRefinement> {
@@ -149,6 +167,37 @@ impl PrustiTokenStream {
                     PrustiToken::Quantifier(ident.span(), Quantifier::Forall),
                 (TokenTree::Ident(ident), _, _, _) if ident == "exists" =>
                     PrustiToken::Quantifier(ident.span(), Quantifier::Exists),
+                // `old[prev](..)` refers to the previous iteration of a loop
+                (TokenTree::Ident(ident), Some(TokenTree::Group(label)), _, _)
+                    if ident == "old"
+                        && label.delimiter() == Delimiter::Bracket
+                        && label.stream().to_string() == "prev" =>
+                {
+                    pos += 1;
+                    tokens.extend(
+                        quote_spanned! { ident.span() => ::prusti_contracts::old_prev_iteration }
+                            .into_iter()
+                            .map(PrustiToken::Token),
+                    );
+                    continue;
+                }
+                // `old[label](..)` refers to the state named by `snapshot!(label)`
+                (TokenTree::Ident(ident), Some(TokenTree::Group(group)), _, _)
+                    if let Some(label) = (ident == "old" && group.delimiter() == Delimiter::Bracket)
+                        .then(|| syn::parse2::<Ident>(group.stream()).ok())
+                        .flatten() =>
+                {
+                    let label_type = snapshot_label_type(&label);
+                    pos += 1;
+                    tokens.extend(
+                        quote_spanned! { ident.span() =>
+                            ::prusti_contracts::old_at::<#label_type, _>
+                        }
+                        .into_iter()
+                        .map(PrustiToken::Token),
+                    );
+                    continue;
+                }
                 (TokenTree::Punct(punct), _, _, _)
                     if punct.as_char() == ',' && punct.spacing() == Alone =>
                     PrustiToken::BinOp(punct.span(), PrustiBinaryOp::Rust(RustOp::Comma)),
@@ -314,6 +363,12 @@ impl PrustiTokenStream {
                 kind.translate(span, triggers, args, body)
             }
 
+            // `if` and `if let` expressions are atoms, so that operators in
+            // their branches do not bind to the surrounding expression
+            Some(PrustiToken::Token(TokenTree::Ident(ident))) if ident == "if" => {
+                self.pop_if_expr(ident)?
+            }
+
             Some(PrustiToken::SpecEnt(span, _)) | Some(PrustiToken::CallDesc(span, _)) => {
                 return err(span, "unexpected operator")
             }
@@ -396,6 +451,86 @@ impl PrustiTokenStream {
         Ok(lhs)
     }
 
+    /// Pops the remainder of an `if` or `if let` expression, whose `if`
+    /// keyword was already consumed. The condition extends up to the first
+    /// braced group, since struct literals are not allowed there.
+    fn pop_if_expr(&mut self, if_token: Ident) -> syn::Result<TokenStream> {
+        let span = if_token.span();
+        let mut condition = VecDeque::new();
+        let body = loop {
+            match self.tokens.pop_front() {
+                Some(PrustiToken::Group(group_span, Delimiter::Brace, box stream)) => {
+                    let mut group = proc_macro2::Group::new(Delimiter::Brace, stream.parse()?);
+                    group.set_span(group_span);
+                    break group;
+                }
+                Some(token) => condition.push_back(token),
+                None => return err(span, "expected a block after the `if` condition"),
+            }
+        };
+        if condition.is_empty() {
+            return err(span, "expected a condition after `if`");
+        }
+        let condition = Self {
+            tokens: condition,
+            source_span: span,
+        }
+        .parse_if_condition()?;
+        let mut result = quote! { #if_token #condition #body };
+        if matches!(
+            self.tokens.front(),
+            Some(PrustiToken::Token(TokenTree::Ident(ident))) if ident == "else"
+        ) {
+            let Some(PrustiToken::Token(else_token)) = self.tokens.pop_front() else {
+                unreachable!()
+            };
+            let else_branch = match self.tokens.pop_front() {
+                Some(PrustiToken::Token(TokenTree::Ident(ident))) if ident == "if" => {
+                    self.pop_if_expr(ident)?
+                }
+                Some(PrustiToken::Group(group_span, Delimiter::Brace, box stream)) => {
+                    let mut group = proc_macro2::Group::new(Delimiter::Brace, stream.parse()?);
+                    group.set_span(group_span);
+                    TokenTree::Group(group).to_token_stream()
+                }
+                _ => return err(else_token.span(), "expected a block or `if` after `else`"),
+            };
+            result.extend(quote! { #else_token #else_branch });
+        }
+        Ok(result)
+    }
+
+    /// Parses the condition of an `if` expression. If the condition is a
+    /// `let`-chain (`let A = x && let B = y && c`), its operands are parsed
+    /// separately and conjoined without parentheses, since a parenthesized
+    /// `let` is not a valid Rust expression.
+    fn parse_if_condition(self) -> syn::Result<TokenStream> {
+        let operands = self.clone().split(PrustiBinaryOp::And, false);
+        if !operands.iter().any(|operand| operand.starts_with_let()) {
+            return self.parse();
+        }
+        let mut result = TokenStream::new();
+        for (i, operand) in operands.into_iter().enumerate() {
+            if i > 0 {
+                result.extend(quote! { && });
+            }
+            if operand.starts_with_let() {
+                result.extend(operand.parse()?);
+            } else {
+                let operand = operand.parse()?;
+                result.extend(quote! { (#operand) });
+            }
+        }
+        Ok(result)
+    }
+
+    fn starts_with_let(&self) -> bool {
+        matches!(
+            self.tokens.front(),
+            Some(PrustiToken::Token(TokenTree::Ident(ident))) if ident == "let"
+        )
+    }
+
     fn pop_group(&mut self, delimiter: Delimiter) -> Option<Self> {
         match self.tokens.pop_front() {
             Some(PrustiToken::Group(_, del, box stream)) if del == delimiter => Some(stream),
@@ -1105,6 +1240,58 @@ mod tests {
                 .to_string(),
             "assert ! (! (snapshot_equality (& (a) , & (b))) || (b))",
         );
+        assert_eq!(
+            parse_prusti("if let Some(x) = opt { x > 0 } else { true }".parse().unwrap())
+                .unwrap()
+                .to_string(),
+            "if let Some (x) = opt { x > 0 } else { true }",
+        );
+        assert_eq!(
+            parse_prusti("a ==> if let Some(x) = opt { x ==> b } else { c } && d".parse().unwrap())
+                .unwrap()
+                .to_string(),
+            "! (a) || ((if let Some (x) = opt { ! (x) || (b) } else { c }) && (d))",
+        );
+        assert_eq!(
+            parse_prusti("if a { b } else if let Ok(x) = c { x } else { d }".parse().unwrap())
+                .unwrap()
+                .to_string(),
+            "if a { b } else if let Ok (x) = c { x } else { d }",
+        );
+        assert_eq!(
+            parse_prusti("if let Some(x) = a && x ==> b && let Ok(y) = c { y } else { d }".parse().unwrap())
+                .unwrap()
+                .to_string(),
+            "if let Some (x) = a && (! (x) || (b)) && let Ok (y) = c { y } else { d }",
+        );
+        assert_eq!(
+            parse_prusti("old[prev](x) ==> b".parse().unwrap())
+                .unwrap()
+                .to_string(),
+            "! (:: prusti_contracts :: old_prev_iteration (x)) || (b)",
+        );
+        assert_eq!(
+            parse_prusti("old[mid](x) ==> b".parse().unwrap())
+                .unwrap()
+                .to_string(),
+            "! (:: prusti_contracts :: old_at :: < prusti_snapshot_mid , _ > (x)) || (b)",
+        );
+        assert_error!(
+            parse_prusti("if let Some(x) = opt".parse().unwrap()),
+            "expected a block after the `if` condition"
+        );
+    }
+
+    #[test]
+    fn test_reject_snapshot_labels() {
+        assert!(reject_snapshot_labels(&"old(x) == y".parse().unwrap()).is_ok());
+        assert!(reject_snapshot_labels(&"old[prev](x) == y".parse().unwrap()).is_ok());
+        assert!(reject_snapshot_labels(&"arr[i] == y".parse().unwrap()).is_ok());
+        assert_error!(
+            reject_snapshot_labels(&"a ==> (old[mid](x) == y)".parse().unwrap()),
+            "`old[label](..)` can only be used in assertions in the body of the function \
+            that takes the snapshot"
+        );
     }
 
     mod type_cond_specs {
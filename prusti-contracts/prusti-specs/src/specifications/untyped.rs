@@ -38,6 +38,15 @@ impl syn::parse::Parse for AnyFnItem {
 }
 
 impl AnyFnItem {
+    pub fn attrs(&self) -> &Vec<syn::Attribute> {
+        match self {
+            AnyFnItem::Fn(item) => &item.attrs,
+            AnyFnItem::TraitMethod(item) => &item.attrs,
+            AnyFnItem::ImplMethod(item) => &item.attrs,
+            AnyFnItem::ForeignFn(item) => &item.attrs,
+        }
+    }
+
     pub fn attrs_mut(&mut self) -> &mut Vec<syn::Attribute> {
         match self {
             AnyFnItem::Fn(item) => &mut item.attrs,
@@ -56,6 +65,15 @@ impl AnyFnItem {
         }
     }
 
+    pub fn block_mut(&mut self) -> Option<&mut syn::Block> {
+        match self {
+            AnyFnItem::Fn(item) => Some(&mut item.block),
+            AnyFnItem::ImplMethod(item) => Some(&mut item.block),
+            AnyFnItem::TraitMethod(item) => item.default.as_mut(),
+            AnyFnItem::ForeignFn(_) => None,
+        }
+    }
+
     pub fn vis(&self) -> Option<&syn::Visibility> {
         match self {
             AnyFnItem::Fn(item) => Some(&item.vis),
@@ -1,3 +1,5 @@
+#![cfg_attr(feature = "prusti", feature(allocator_api))]
+
 use prusti_contracts::*;
 
 #[extern_spec]
@@ -12,3 +14,138 @@ where
         K: ::core::borrow::Borrow<Q>,
         Q: ::core::hash::Hash + Eq;
 }
+
+#[extern_spec]
+impl<K, V, S> ::std::collections::hash_map::HashMap<K, V, S> {
+    #[pure]
+    pub fn len(&self) -> usize;
+}
+
+#[extern_spec]
+impl<T> ::std::vec::Vec<T> {
+    #[ensures(result.len() == 0)]
+    pub fn new() -> ::std::vec::Vec<T>;
+
+    #[ensures(result.len() == 0)]
+    #[ensures(result.capacity() >= capacity)]
+    pub fn with_capacity(capacity: usize) -> ::std::vec::Vec<T>;
+}
+
+#[extern_spec]
+impl<T, A: ::std::alloc::Allocator> ::std::vec::Vec<T, A> {
+    #[pure]
+    pub fn len(&self) -> usize;
+
+    #[pure]
+    #[ensures(result == (self.len() == 0))]
+    pub fn is_empty(&self) -> bool;
+
+    // The capacity is not related to the length except where stated
+    // explicitly: operations that change the length leave the capacity
+    // unspecified.
+    #[pure]
+    pub fn capacity(&self) -> usize;
+
+    #[ensures(self.len() == old(self.len()))]
+    #[ensures(self.capacity() >= self.len())]
+    #[ensures(self.capacity() - self.len() >= additional)]
+    pub fn reserve(&mut self, additional: usize);
+
+    // The standard implementation shrinks the buffer to exactly the length.
+    #[ensures(self.len() == old(self.len()))]
+    #[ensures(self.capacity() == self.len())]
+    pub fn shrink_to_fit(&mut self);
+
+    #[ensures(self.len() == old(self.len()) + 1)]
+    #[ensures(vec_get(self, old(self.len())) === value)]
+    #[ensures(forall(|index: usize| index < old(self.len()) ==>
+        vec_get(self, index) === old(vec_get(self, index))))]
+    pub fn push(&mut self, value: T);
+
+    #[ensures(old(self.len()) == 0 ==> matches!(result, None))]
+    #[ensures(old(self.len()) == 0 ==> self.len() == 0)]
+    #[ensures(old(self.len()) > 0 ==> result === Some(old(vec_get(self, self.len() - 1))))]
+    #[ensures(old(self.len()) > 0 ==> self.len() == old(self.len()) - 1)]
+    #[ensures(forall(|index: usize| index < self.len() ==>
+        vec_get(self, index) === old(vec_get(self, index))))]
+    pub fn pop(&mut self) -> Option<T>;
+}
+
+// Vectors are modelled as the sequence of their elements: `len` is the length
+// of this sequence and `vec_get` its elements.
+
+/// The element at position `index` of `vec`, which is meaningless unless
+/// `index < vec.len()`. Taking any `Index<usize>` type, rather than naming
+/// `Vec<T, A>`, accepts vectors with any allocator without requiring the
+/// allocator API outside of Prusti.
+#[pure]
+#[trusted]
+pub fn vec_get<V: ::core::ops::Index<usize>>(_vec: &V, _index: usize) -> V::Output
+where
+    V::Output: Sized,
+{
+    unimplemented!()
+}
+
+#[extern_spec]
+trait Clone {
+    // Clones of `Copy` types are expected to be the same as copies, which is
+    // what a derived `Clone` implementation does. This is not enforced by the
+    // compiler, so the specification is only provided by `prusti-std`.
+    #[refine_spec(where Self: Copy, [
+        ensures(result === *self),
+    ])]
+    fn clone(&self) -> Self;
+}
+
+// Finite iterators are modelled as the sequence of the elements that they
+// have yet to produce: `iter_len` is the length of this sequence and
+// `iter_get` its elements. Infinite iterators only have elements.
+
+/// The number of elements that the finite iterator `iter` has yet to produce.
+#[pure]
+#[trusted]
+pub fn iter_len<I: Iterator>(_iter: &I) -> usize {
+    unimplemented!()
+}
+
+/// The element at position `index` among the elements that `iter` has yet
+/// to produce.
+#[pure]
+#[trusted]
+pub fn iter_get<I: Iterator>(_iter: &I, _index: usize) -> I::Item {
+    unimplemented!()
+}
+
+/// Whether `iter` never stops producing elements, in which case `iter_len`
+/// is meaningless.
+#[pure]
+#[trusted]
+pub fn iter_is_infinite<I: Iterator>(_iter: &I) -> bool {
+    unimplemented!()
+}
+
+#[extern_spec(std::iter)]
+#[ensures(!iter_is_infinite(&result))]
+#[ensures(iter_len(&result) == 1)]
+#[ensures(iter_get(&result, 0) === value)]
+fn once<T>(value: T) -> ::std::iter::Once<T>;
+
+#[extern_spec(std::iter)]
+#[ensures(iter_is_infinite(&result))]
+#[ensures(forall(|index: usize| iter_get(&result, index) === elt))]
+fn repeat<T: Clone>(elt: T) -> ::std::iter::Repeat<T>;
+
+#[extern_spec]
+trait Iterator {
+    #[ensures(!iter_is_infinite(&result))]
+    #[ensures(iter_is_infinite(&self) ==> iter_len(&result) == n)]
+    #[ensures(!iter_is_infinite(&self) && iter_len(&self) < n ==>
+        iter_len(&result) == iter_len(&self))]
+    #[ensures(!iter_is_infinite(&self) && iter_len(&self) >= n ==> iter_len(&result) == n)]
+    #[ensures(forall(|index: usize| index < iter_len(&result) ==>
+        iter_get(&result, index) === iter_get(&self, index)))]
+    fn take(self, n: usize) -> ::std::iter::Take<Self>
+    where
+        Self: Sized;
+}
@@ -7,7 +7,7 @@ use prusti_rustc_interface::{
     },
     span::def_id::{DefId, LocalDefId},
 };
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use std::{cell::RefCell, collections::hash_map::Entry, rc::Rc};
 
 use crate::environment::{borrowck::facts::BorrowckFacts, mir_storage};
@@ -53,6 +53,13 @@ impl<'tcx> PreLoadedBodies<'tcx> {
             self.external.get(&def_id).cloned()
         }
     }
+    fn contains(&self, def_id: DefId) -> bool {
+        if let Some(def_id) = def_id.as_local() {
+            self.local.contains_key(&def_id)
+        } else {
+            self.external.contains_key(&def_id)
+        }
+    }
     // For debugging use this rather than simply unwrap
     fn expect(&self, def_id: DefId) -> MirBody<'tcx> {
         let res = self.get(def_id);
@@ -277,6 +284,16 @@ impl<'tcx> EnvBody<'tcx> {
         self.set_monomorphised(def_id, substs, Some(caller_def_id), body)
     }
 
+    /// Whether the MIR body of a local or external spec has been loaded.
+    pub fn has_spec_body(&self, def_id: DefId) -> bool {
+        self.specs.contains(def_id)
+    }
+
+    /// Whether the MIR body of a local or external predicate has been loaded.
+    pub fn has_predicate_body(&self, def_id: DefId) -> bool {
+        self.predicates.contains(def_id)
+    }
+
     /// Get Polonius facts of a local procedure.
     pub fn local_mir_borrowck_facts(&self, def_id: LocalDefId) -> Rc<BorrowckFacts> {
         self.try_get_local_mir_borrowck_facts(def_id).unwrap()
@@ -354,6 +371,31 @@ pub(crate) struct CrossCrateBodies<'tcx> {
     closures: FxHashMap<DefId, MirBody<'tcx>>,
 }
 
+impl<'tcx> CrossCrateBodies<'tcx> {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.pure_fns.is_empty()
+            && self.predicates.is_empty()
+            && self.specs.is_empty()
+            && self.closures.is_empty()
+    }
+
+    /// Keeps only the bodies of the specification functions for which `keep`
+    /// holds, together with the closures (e.g. quantifiers) defined in them.
+    /// The bodies of pure functions and predicates are all kept, since the
+    /// kept specifications may call them even if they are private.
+    pub(crate) fn retain_specs(&mut self, tcx: TyCtxt<'tcx>, keep: impl Fn(DefId) -> bool) {
+        let dropped: FxHashSet<DefId> = self
+            .specs
+            .keys()
+            .copied()
+            .filter(|def_id| !keep(*def_id))
+            .collect();
+        self.specs.retain(|def_id, _| !dropped.contains(def_id));
+        self.closures
+            .retain(|def_id, _| !dropped.contains(&tcx.typeck_root_def_id(*def_id)));
+    }
+}
+
 impl<'tcx> From<&EnvBody<'tcx>> for CrossCrateBodies<'tcx> {
     fn from(body: &EnvBody<'tcx>) -> Self {
         let clone_map = |map: &FxHashMap<LocalDefId, MirBody<'tcx>>| {
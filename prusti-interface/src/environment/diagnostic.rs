@@ -1,12 +1,17 @@
+use crate::utils::read_prusti_allow_attrs;
 use prusti_rustc_interface::{
-    errors::{DiagnosticBuilder, EmissionGuarantee, MultiSpan},
+    errors::{DiagnosticBuilder, DiagnosticId, EmissionGuarantee, MultiSpan},
     middle::ty::TyCtxt,
+    span::Span,
 };
 use std::cell::RefCell;
 
 pub struct EnvDiagnostic<'tcx> {
     tcx: TyCtxt<'tcx>,
     warn_buffer: RefCell<Vec<prusti_rustc_interface::errors::Diagnostic>>,
+    /// The spans of the items annotated with `#[prusti::allow(...)]`,
+    /// together with the allowed error codes. Collected on first use.
+    allowed_codes: RefCell<Option<Vec<(Span, Vec<String>)>>>,
 }
 
 impl<'tcx> EnvDiagnostic<'tcx> {
@@ -14,16 +19,50 @@ impl<'tcx> EnvDiagnostic<'tcx> {
         EnvDiagnostic {
             tcx,
             warn_buffer: RefCell::new(Vec::new()),
+            allowed_codes: RefCell::new(None),
+        }
+    }
+
+    fn collect_allowed_codes(&self) -> Vec<(Span, Vec<String>)> {
+        let hir = self.tcx.hir();
+        let mut allowed_codes = Vec::new();
+        for local_def_id in self.tcx.hir_crate_items(()).definitions() {
+            let hir_id = hir.local_def_id_to_hir_id(local_def_id);
+            let codes = read_prusti_allow_attrs(hir.attrs(hir_id));
+            if !codes.is_empty() {
+                allowed_codes.push((hir.span_with_body(hir_id), codes));
+            }
+        }
+        allowed_codes
+    }
+
+    /// Returns true if the given error code is allowed by a
+    /// `#[prusti::allow(...)]` attribute on an item enclosing `span`.
+    pub fn is_allowed(&self, code: &str, span: &MultiSpan) -> bool {
+        if let Some(primary_span) = span.primary_span() {
+            let mut allowed_codes = self.allowed_codes.borrow_mut();
+            allowed_codes
+                .get_or_insert_with(|| self.collect_allowed_codes())
+                .iter()
+                .any(|(item_span, codes)| {
+                    item_span.contains(primary_span) && codes.iter().any(|allowed| allowed == code)
+                })
+        } else {
+            false
         }
     }
 
     fn configure_diagnostic<S: Into<MultiSpan> + Clone, T: EmissionGuarantee>(
         diagnostic: &mut DiagnosticBuilder<T>,
         sp: S,
+        code: Option<DiagnosticId>,
         help: &Option<String>,
         notes: &[(String, Option<S>)],
     ) {
         diagnostic.set_span(sp);
+        if let Some(code) = code {
+            diagnostic.code(code);
+        }
         if let Some(help_msg) = help {
             diagnostic.help(help_msg);
         }
@@ -36,16 +75,28 @@ impl<'tcx> EnvDiagnostic<'tcx> {
         }
     }
 
+    /// The code of a warning is attached as the name of a lint, like the
+    /// codes of the warnings of rustc, rather than as an error code.
+    fn warning_code(code: &'static str) -> DiagnosticId {
+        DiagnosticId::Lint {
+            name: code.to_string(),
+            has_future_breakage: false,
+            is_force_warn: false,
+        }
+    }
+
     /// Emits an error message.
     pub fn span_err_with_help_and_notes<S: Into<MultiSpan> + Clone>(
         &self,
         sp: S,
         msg: &str,
+        code: Option<&'static str>,
         help: &Option<String>,
         notes: &[(String, Option<S>)],
     ) {
         let mut diagnostic = self.tcx.sess.struct_err(msg);
-        Self::configure_diagnostic(&mut diagnostic, sp, help, notes);
+        let code = code.map(|code| DiagnosticId::Error(code.to_string()));
+        Self::configure_diagnostic(&mut diagnostic, sp, code, help, notes);
         for warn in self.warn_buffer.borrow_mut().iter_mut() {
             self.tcx.sess.diagnostic().emit_diagnostic(warn);
         }
@@ -57,11 +108,13 @@ impl<'tcx> EnvDiagnostic<'tcx> {
         &self,
         sp: S,
         msg: &str,
+        code: Option<&'static str>,
         help: &Option<String>,
         notes: &[(String, Option<S>)],
     ) {
         let mut diagnostic = self.tcx.sess.struct_warn(msg);
-        Self::configure_diagnostic(&mut diagnostic, sp, help, notes);
+        let code = code.map(Self::warning_code);
+        Self::configure_diagnostic(&mut diagnostic, sp, code, help, notes);
         diagnostic.emit();
     }
 
@@ -70,11 +123,13 @@ impl<'tcx> EnvDiagnostic<'tcx> {
         &self,
         sp: S,
         msg: &str,
+        code: Option<&'static str>,
         help: &Option<String>,
         notes: &[(String, Option<S>)],
     ) {
         let mut diagnostic = self.tcx.sess.struct_warn(msg);
-        Self::configure_diagnostic(&mut diagnostic, sp, help, notes);
+        let code = code.map(Self::warning_code);
+        Self::configure_diagnostic(&mut diagnostic, sp, code, help, notes);
         diagnostic.buffer(&mut self.warn_buffer.borrow_mut());
     }
 
@@ -4,6 +4,7 @@ use crate::data::ProcedureDefId;
 use log::debug;
 use prusti_rustc_interface::{
     ast::ast::Attribute,
+    data_structures::fx::FxHashSet,
     hir::hir_id::HirId,
     middle::{
         hir::map::Map,
@@ -13,9 +14,9 @@ use prusti_rustc_interface::{
         },
     },
     span::{
-        def_id::{DefId, LocalDefId},
+        def_id::{DefId, LocalDefId, CRATE_DEF_INDEX},
         source_map::SourceMap,
-        Span,
+        Span, Symbol,
     },
     trait_selection::{
         infer::{InferCtxtExt, TyCtxtInferExt},
@@ -153,6 +154,55 @@ impl<'tcx> EnvQuery<'tcx> {
             == prusti_rustc_interface::hir::Unsafety::Unsafe
     }
 
+    /// Returns true iff `def_id` is an `async fn`.
+    pub fn is_async_fn(self, def_id: impl IntoParam<ProcedureDefId>) -> bool {
+        self.tcx.asyncness(def_id.into_param()) == prusti_rustc_interface::hir::IsAsync::Async
+    }
+
+    /// Returns true iff `def_id` is the `main` function written by the user.
+    /// With `--test`, the entry function of the crate is the `main` generated
+    /// by the test harness instead, so the user's `main` is identified by its
+    /// name and its position at the root of the crate.
+    pub fn is_main_fn(self, def_id: impl IntoParam<ProcedureDefId>) -> bool {
+        let def_id = def_id.into_param();
+        def_id.is_local()
+            && self.tcx.def_key(def_id).parent == Some(CRATE_DEF_INDEX)
+            && self.tcx.opt_item_name(def_id) == Some(Symbol::intern("main"))
+            && !self.tcx.def_span(def_id).from_expansion()
+    }
+
+    /// Returns the functions annotated with `#[test]`, together with the closures that the test
+    /// harness generates to call them. The harness records the path of each test function in
+    /// the `#[rustc_test_marker]` attribute of a generated constant, so the result is empty
+    /// unless the crate is compiled with `--test`.
+    pub fn get_test_fns(self) -> FxHashSet<DefId> {
+        let marker = Symbol::intern("rustc_test_marker");
+        let crate_items = self.tcx.hir_crate_items(());
+        let mut test_paths = FxHashSet::default();
+        let mut test_markers = FxHashSet::default();
+        for item_id in crate_items.items() {
+            let def_id = item_id.owner_id.def_id;
+            for attr in self.get_local_attributes(def_id) {
+                if let Some(path) = attr.has_name(marker).then(|| attr.value_str()).flatten() {
+                    test_paths.insert(path.to_string());
+                    test_markers.insert(def_id.to_def_id());
+                }
+            }
+        }
+        let mut test_fns = FxHashSet::default();
+        for def_id in crate_items.body_owners() {
+            let def_id = def_id.to_def_id();
+            let is_test_fn = !self.tcx.is_closure(def_id)
+                && test_paths.contains(&self.tcx.def_path_str(def_id));
+            let is_harness_closure = self.tcx.is_closure(def_id)
+                && test_markers.contains(&self.tcx.typeck_root_def_id(def_id));
+            if is_test_fn || is_harness_closure {
+                test_fns.insert(def_id);
+            }
+        }
+        test_fns
+    }
+
     /// Computes the signature of the function with subst applied.
     pub fn get_fn_sig(
         self,
@@ -29,6 +29,9 @@ pub struct PrustiError {
     /// field should be removed.
     is_disabled: bool,
     message: String,
+    /// A stable, machine-readable code identifying the error (e.g. `P0004`),
+    /// reported like the error codes of rustc.
+    code: Option<&'static str>,
     span: Box<MultiSpan>,
     help: Option<String>,
     notes: Vec<(String, Option<MultiSpan>)>,
@@ -63,6 +66,7 @@ impl PrustiError {
             kind: PrustiErrorKind::Error,
             is_disabled: false,
             message,
+            code: None,
             span: Box::new(span),
             help: None,
             notes: vec![],
@@ -162,6 +166,39 @@ impl PrustiError {
         self.is_disabled
     }
 
+    /// Set the stable code of the error. The codes currently in use are:
+    /// * `P0001`: type-conditional spec refinement of a non-trusted function
+    /// * `P0002`: external specification of a function that has a specification
+    /// * `P0003`: type invariant without `enable_type_invariants`
+    /// * `P0004`: duplicate external specification
+    /// * `P0005`: invalid external specification
+    /// * `P0006`: duplicate specification imported from different crates
+    /// * `P0007`: pledge of a pure function
+    /// * `P0008`: `#[prusti::transparent]` on a type that is not a newtype
+    /// * `P0009`: precondition of a call might not hold
+    /// * `P0010`: accessor of a `#[prusti::transparent]` type that is not a field access
+    /// * `P0011`: invalid or unsupported `#[modifies(...)]` annotation
+    ///
+    /// The code of a warning is reported as the name of a lint, so like for the
+    /// lints of rustc it is only shown in the JSON output.
+    #[must_use]
+    pub fn set_code(mut self, code: &'static str) -> Self {
+        self.code = Some(code);
+        self
+    }
+
+    pub fn code(&self) -> Option<&'static str> {
+        self.code
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn span(&self) -> &MultiSpan {
+        &self.span
+    }
+
     #[must_use]
     pub fn set_help<S: ToString>(mut self, message: S) -> Self {
         self.help = Some(message.to_string());
@@ -182,24 +219,35 @@ impl PrustiError {
     /// Report the encoding error using the compiler's interface.
     /// Warnings are not immediately emitted, but buffered and only shown
     /// if an error is emitted (i.e. verification failure)
+    ///
+    /// Warnings whose code is allowed by a `#[prusti::allow(...)]` attribute
+    /// on an enclosing item are not reported. Errors cannot be suppressed.
     pub fn emit(self, env_diagnostic: &EnvDiagnostic) {
         assert!(!self.is_disabled);
+        if let Some(code) = self.code {
+            if !self.is_error() && env_diagnostic.is_allowed(code, &self.span) {
+                return;
+            }
+        }
         match self.kind {
             PrustiErrorKind::Error => env_diagnostic.span_err_with_help_and_notes(
                 *self.span,
                 &self.message,
+                self.code,
                 &self.help,
                 &self.notes,
             ),
             PrustiErrorKind::Warning => env_diagnostic.span_warn_with_help_and_notes(
                 *self.span,
                 &self.message,
+                self.code,
                 &self.help,
                 &self.notes,
             ),
             PrustiErrorKind::WarningOnError => env_diagnostic.span_warn_on_err_with_help_and_notes(
                 *self.span,
                 &self.message,
+                self.code,
                 &self.help,
                 &self.notes,
             ),
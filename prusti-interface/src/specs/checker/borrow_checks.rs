@@ -0,0 +1,248 @@
+use super::common::*;
+use crate::{
+    environment::{EnvName, EnvQuery, Environment},
+    utils::{read_prusti_attr, read_prusti_attrs},
+    PrustiError,
+};
+use log::debug;
+use prusti_rustc_interface::{
+    data_structures::fx::FxHashMap,
+    errors::MultiSpan,
+    hir::{self as hir, def::Res, def_id::LocalDefId, intravisit},
+    middle::{
+        hir::map::Map,
+        ty::{self, subst::GenericArgKind},
+    },
+    span::Span,
+};
+
+const RESULT_BORROWS_FROM: &str = "prusti_contracts::result_borrows_from";
+const LAZY_ITERATOR: &str = "prusti_contracts::lazy_iterator";
+
+/// Checks that `result_borrows_from(result, arg)` and `lazy_iterator(result,
+/// arg)` in postconditions agree with the signature of the specified function,
+/// i.e. that every lifetime of the result is a lifetime of `arg` and of no
+/// other argument.
+pub struct ResultBorrowsFromChecker;
+
+impl<'tcx> SpecCheckerStrategy<'tcx> for ResultBorrowsFromChecker {
+    #[tracing::instrument(
+        name = "ResultBorrowsFromChecker::check",
+        level = "debug",
+        skip(self, env)
+    )]
+    fn check(&self, env: &Environment<'tcx>) -> Vec<PrustiError> {
+        let mut collect = CollectPostconditionsVisitor {
+            env_query: env.query,
+            spec_functions: FxHashMap::default(),
+            postconditions: Vec::new(),
+        };
+        env.query.hir().walk_toplevel_module(&mut collect);
+        debug!("Postconditions: {:?}", collect.postconditions);
+
+        let mut errors = Vec::new();
+        for (proc_id, spec_ids) in collect.postconditions {
+            for spec_id in spec_ids {
+                if let Some(spec_body) = collect.spec_functions.get(&spec_id) {
+                    let mut visit = ResultBorrowsFromVisitor {
+                        env_query: env.query,
+                        env_name: env.name,
+                        proc_id,
+                        spec_body: *spec_body,
+                        errors: Vec::new(),
+                    };
+                    let body = env.query.hir().body(*spec_body);
+                    intravisit::Visitor::visit_body(&mut visit, body);
+                    errors.extend(visit.errors);
+                }
+            }
+        }
+        errors
+    }
+}
+
+/// Collects the bodies of specification functions and the postconditions
+/// attached to each function.
+struct CollectPostconditionsVisitor<'tcx> {
+    env_query: EnvQuery<'tcx>,
+    spec_functions: FxHashMap<String, hir::BodyId>,
+    postconditions: Vec<(LocalDefId, Vec<String>)>,
+}
+
+impl<'tcx> CollectPostconditionsVisitor<'tcx> {
+    fn collect_postconditions(&mut self, local_id: LocalDefId) {
+        let attrs = self.env_query.get_local_attributes(local_id);
+        let spec_ids = read_prusti_attrs("post_spec_id_ref", attrs);
+        if !spec_ids.is_empty() {
+            self.postconditions.push((local_id, spec_ids));
+        }
+    }
+}
+
+impl<'tcx> intravisit::Visitor<'tcx> for CollectPostconditionsVisitor<'tcx> {
+    type Map = Map<'tcx>;
+    type NestedFilter = prusti_rustc_interface::middle::hir::nested_filter::All;
+
+    fn nested_visit_map(&mut self) -> Self::Map {
+        self.env_query.hir()
+    }
+
+    fn visit_fn(
+        &mut self,
+        fk: intravisit::FnKind<'tcx>,
+        fd: &'tcx hir::FnDecl<'tcx>,
+        b: hir::BodyId,
+        _s: Span,
+        local_id: LocalDefId,
+    ) {
+        let attrs = self.env_query.get_local_attributes(local_id);
+        if let Some(spec_id) = read_prusti_attr("spec_id", attrs) {
+            self.spec_functions.insert(spec_id, b);
+        }
+        self.collect_postconditions(local_id);
+
+        intravisit::walk_fn(self, fk, fd, b, local_id);
+    }
+
+    fn visit_trait_item(&mut self, ti: &'tcx hir::TraitItem<'tcx>) {
+        // Trait methods with a body are visited by `visit_fn`.
+        if let hir::TraitItemKind::Fn(_, hir::TraitFn::Required(_)) = &ti.kind {
+            self.collect_postconditions(ti.owner_id.def_id);
+        }
+
+        intravisit::walk_trait_item(self, ti);
+    }
+}
+
+/// Checks the `result_borrows_from` and `lazy_iterator` calls in the body of a
+/// postcondition.
+struct ResultBorrowsFromVisitor<'tcx> {
+    env_query: EnvQuery<'tcx>,
+    env_name: EnvName<'tcx>,
+    proc_id: LocalDefId,
+    spec_body: hir::BodyId,
+    errors: Vec<PrustiError>,
+}
+
+impl<'tcx> ResultBorrowsFromVisitor<'tcx> {
+    /// Returns the index of the parameter of the specification function that
+    /// is referred to by `expr`, if any.
+    fn get_param_index(&self, expr: &'tcx hir::Expr<'tcx>) -> Option<usize> {
+        if let hir::ExprKind::Path(hir::QPath::Resolved(None, path)) = expr.kind {
+            if let Res::Local(hir_id) = path.res {
+                let body = self.env_query.hir().body(self.spec_body);
+                return body
+                    .params
+                    .iter()
+                    .position(|param| param.pat.hir_id == hir_id);
+            }
+        }
+        None
+    }
+
+    fn check_call(&mut self, fn_name: &str, call_span: Span, args: &'tcx [hir::Expr<'tcx>]) {
+        let sig = self
+            .env_query
+            .get_fn_sig(self.proc_id, self.env_query.identity_substs(self.proc_id))
+            .skip_binder();
+        let result_index = sig.inputs().len();
+        let arg_index = match (self.get_param_index(&args[0]), self.get_param_index(&args[1])) {
+            (Some(result), Some(arg)) if result == result_index && arg < result_index => arg,
+            _ => {
+                self.errors.push(PrustiError::incorrect(
+                    format!("`{fn_name}` expects `result` and an argument of the function"),
+                    MultiSpan::from_span(call_span),
+                ));
+                return;
+            }
+        };
+        let result_regions = regions_of(sig.output());
+        let arg_regions = regions_of(sig.inputs()[arg_index]);
+        let other_regions: Vec<_> = sig
+            .inputs()
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| *index != arg_index)
+            .flat_map(|(_, input)| regions_of(*input))
+            .collect();
+        debug!(
+            "Result regions: {:?}, argument regions: {:?}, other regions: {:?}",
+            result_regions, arg_regions, other_regions
+        );
+        let arg_span = args[1].span;
+        let arg_name = self
+            .env_query
+            .codemap()
+            .span_to_snippet(arg_span)
+            .unwrap_or_else(|_| format!("argument {arg_index}"));
+        if result_regions.is_empty()
+            || result_regions
+                .iter()
+                .any(|region| !arg_regions.contains(region))
+        {
+            self.errors.push(
+                PrustiError::incorrect(
+                    format!("the result does not borrow from `{arg_name}`"),
+                    MultiSpan::from_span(call_span),
+                )
+                .add_note(
+                    "the lifetimes of the result must be lifetimes of the argument",
+                    Some(self.env_query.get_def_span(self.proc_id)),
+                ),
+            );
+        } else if result_regions
+            .iter()
+            .any(|region| other_regions.contains(region))
+        {
+            self.errors.push(
+                PrustiError::incorrect(
+                    format!("the result may borrow from other arguments than `{arg_name}`"),
+                    MultiSpan::from_span(call_span),
+                )
+                .add_note(
+                    "the lifetimes of the result must not be lifetimes of the other arguments",
+                    Some(self.env_query.get_def_span(self.proc_id)),
+                ),
+            );
+        }
+    }
+}
+
+impl<'tcx> intravisit::Visitor<'tcx> for ResultBorrowsFromVisitor<'tcx> {
+    type Map = Map<'tcx>;
+    type NestedFilter = prusti_rustc_interface::middle::hir::nested_filter::OnlyBodies;
+
+    fn nested_visit_map(&mut self) -> Self::Map {
+        self.env_query.hir()
+    }
+
+    fn visit_expr(&mut self, ex: &'tcx hir::Expr<'tcx>) {
+        if let hir::ExprKind::Call(callee, args) = ex.kind {
+            if let hir::ExprKind::Path(ref qpath) = callee.kind {
+                let res = self
+                    .env_query
+                    .tcx()
+                    .typeck_body(self.spec_body)
+                    .qpath_res(qpath, callee.hir_id);
+                if let Res::Def(_, def_id) = res {
+                    let item_name = self.env_name.get_absolute_item_name(def_id);
+                    if item_name == RESULT_BORROWS_FROM || item_name == LAZY_ITERATOR {
+                        let fn_name = item_name.trim_start_matches("prusti_contracts::");
+                        self.check_call(fn_name, ex.span, args);
+                    }
+                }
+            }
+        }
+        intravisit::walk_expr(self, ex);
+    }
+}
+
+/// Returns the regions occurring in the type `ty`.
+fn regions_of(ty: ty::Ty<'_>) -> Vec<ty::Region<'_>> {
+    ty.walk()
+        .filter_map(|arg| match arg.unpack() {
+            GenericArgKind::Lifetime(region) => Some(region),
+            _ => None,
+        })
+        .collect()
+}
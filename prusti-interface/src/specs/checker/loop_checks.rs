@@ -0,0 +1,98 @@
+use super::common::*;
+use crate::{
+    environment::{EnvName, EnvQuery, Environment},
+    utils::has_prusti_attr,
+    PrustiError,
+};
+use prusti_rustc_interface::{
+    errors::MultiSpan,
+    hir::{self as hir, def::Res, def_id::LocalDefId, intravisit},
+    middle::hir::map::Map,
+    span::Span,
+};
+
+const ITERATIONS: &str = "prusti_contracts::iterations";
+const OLD_PREV_ITERATION: &str = "prusti_contracts::old_prev_iteration";
+
+/// Checks that `iterations()` and `old[prev](..)`, which refer to the
+/// iterations of the enclosing loop, are only used in `body_invariant!(..)`.
+pub struct LoopOnlyUsagesChecker;
+
+impl<'tcx> SpecCheckerStrategy<'tcx> for LoopOnlyUsagesChecker {
+    #[tracing::instrument(
+        name = "LoopOnlyUsagesChecker::check",
+        level = "debug",
+        skip(self, env)
+    )]
+    fn check(&self, env: &Environment<'tcx>) -> Vec<PrustiError> {
+        let mut visit = LoopOnlyUsagesVisitor {
+            env_query: env.query,
+            env_name: env.name,
+            in_loop_invariant: false,
+            errors: Vec::new(),
+        };
+        env.query.hir().walk_toplevel_module(&mut visit);
+        env.query.hir().walk_attributes(&mut visit);
+        visit.errors
+    }
+}
+
+struct LoopOnlyUsagesVisitor<'tcx> {
+    env_query: EnvQuery<'tcx>,
+    env_name: EnvName<'tcx>,
+    in_loop_invariant: bool,
+    errors: Vec<PrustiError>,
+}
+
+impl<'tcx> intravisit::Visitor<'tcx> for LoopOnlyUsagesVisitor<'tcx> {
+    type Map = Map<'tcx>;
+    type NestedFilter = prusti_rustc_interface::middle::hir::nested_filter::All;
+
+    fn nested_visit_map(&mut self) -> Self::Map {
+        self.env_query.hir()
+    }
+
+    fn visit_fn(
+        &mut self,
+        fk: intravisit::FnKind<'tcx>,
+        fd: &'tcx hir::FnDecl<'tcx>,
+        b: hir::BodyId,
+        _s: Span,
+        local_id: LocalDefId,
+    ) {
+        let attrs = self.env_query.get_local_attributes(local_id);
+        let in_loop_invariant = self.in_loop_invariant;
+        self.in_loop_invariant |= has_prusti_attr(attrs, "loop_body_invariant_spec");
+        intravisit::walk_fn(self, fk, fd, b, local_id);
+        self.in_loop_invariant = in_loop_invariant;
+    }
+
+    fn visit_expr(&mut self, ex: &'tcx hir::Expr<'tcx>) {
+        let owner_def_id = ex.hir_id.owner.def_id;
+        if let hir::ExprKind::Call(callee, _) = ex.kind {
+            if let hir::ExprKind::Path(ref qpath) = callee.kind {
+                if !self.in_loop_invariant && self.env_query.has_body(owner_def_id) {
+                    let res = self
+                        .env_query
+                        .tcx()
+                        .typeck(owner_def_id)
+                        .qpath_res(qpath, callee.hir_id);
+                    if let Res::Def(_, def_id) = res {
+                        let usage = match self.env_name.get_absolute_item_name(def_id).as_str() {
+                            ITERATIONS => Some("`iterations()`"),
+                            OLD_PREV_ITERATION => Some("`old[prev](..)`"),
+                            _ => None,
+                        };
+                        if let Some(usage) = usage {
+                            self.errors.push(PrustiError::incorrect(
+                                format!("{usage} can only be used in `body_invariant!(..)`"),
+                                MultiSpan::from_span(ex.span),
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+        intravisit::walk_expr(self, ex);
+    }
+}
@@ -1,13 +1,19 @@
 //! Module for verifying user-provided specifications after macro expansion
 
+mod borrow_checks;
 mod common;
+mod loop_checks;
 mod predicate_checks;
+mod snapshot_checks;
 mod type_model_checks;
 mod version_checks;
 
+use borrow_checks::ResultBorrowsFromChecker;
 use crate::environment::Environment;
 use common::*;
+use loop_checks::LoopOnlyUsagesChecker;
 use predicate_checks::IllegalPredicateUsagesChecker;
+use snapshot_checks::DuplicateSnapshotLabelsChecker;
 use type_model_checks::{IllegalModelUsagesChecker, ModelDefinedOnTypeWithoutFields};
 use version_checks::MismatchedVersionsChecker;
 
@@ -31,6 +37,9 @@ impl<'tcx> SpecChecker<'tcx> {
                 Box::new(IllegalPredicateUsagesChecker {}),
                 Box::new(IllegalModelUsagesChecker {}),
                 Box::new(ModelDefinedOnTypeWithoutFields {}),
+                Box::new(ResultBorrowsFromChecker {}),
+                Box::new(LoopOnlyUsagesChecker {}),
+                Box::new(DuplicateSnapshotLabelsChecker {}),
             ],
         }
     }
@@ -0,0 +1,92 @@
+use super::common::*;
+use crate::{
+    environment::{EnvName, EnvQuery, Environment},
+    PrustiError,
+};
+use prusti_rustc_interface::{
+    data_structures::fx::FxHashMap,
+    errors::MultiSpan,
+    hir::{self as hir, def::Res, def_id::LocalDefId, intravisit},
+    middle::{hir::map::Map, ty},
+    span::{Span, Symbol},
+};
+
+const SNAPSHOT: &str = "prusti_contracts::snapshot";
+
+/// Checks that every `snapshot!(label)` in a function uses a different label,
+/// since `old[label](..)` must refer to a single state.
+pub struct DuplicateSnapshotLabelsChecker;
+
+impl<'tcx> SpecCheckerStrategy<'tcx> for DuplicateSnapshotLabelsChecker {
+    #[tracing::instrument(
+        name = "DuplicateSnapshotLabelsChecker::check",
+        level = "debug",
+        skip(self, env)
+    )]
+    fn check(&self, env: &Environment<'tcx>) -> Vec<PrustiError> {
+        let mut visit = SnapshotLabelsVisitor {
+            env_query: env.query,
+            env_name: env.name,
+            snapshots: FxHashMap::default(),
+            errors: Vec::new(),
+        };
+        env.query.hir().walk_toplevel_module(&mut visit);
+        visit.errors
+    }
+}
+
+struct SnapshotLabelsVisitor<'tcx> {
+    env_query: EnvQuery<'tcx>,
+    env_name: EnvName<'tcx>,
+    /// The span of the first snapshot of each label, per function.
+    snapshots: FxHashMap<(LocalDefId, Symbol), Span>,
+    errors: Vec<PrustiError>,
+}
+
+impl<'tcx> intravisit::Visitor<'tcx> for SnapshotLabelsVisitor<'tcx> {
+    type Map = Map<'tcx>;
+    type NestedFilter = prusti_rustc_interface::middle::hir::nested_filter::All;
+
+    fn nested_visit_map(&mut self) -> Self::Map {
+        self.env_query.hir()
+    }
+
+    fn visit_expr(&mut self, ex: &'tcx hir::Expr<'tcx>) {
+        let owner_def_id = ex.hir_id.owner.def_id;
+        if let hir::ExprKind::Call(callee, _) = ex.kind {
+            if let hir::ExprKind::Path(ref qpath) = callee.kind {
+                if self.env_query.has_body(owner_def_id) {
+                    let typeck_results = self.env_query.tcx().typeck(owner_def_id);
+                    let res = typeck_results.qpath_res(qpath, callee.hir_id);
+                    if let Res::Def(_, def_id) = res {
+                        if self.env_name.get_absolute_item_name(def_id) == SNAPSHOT {
+                            let label_ty = typeck_results.node_substs(callee.hir_id).type_at(0);
+                            if let ty::TyKind::Adt(adt_def, _) = label_ty.kind() {
+                                let label = self.env_query.tcx().item_name(adt_def.did());
+                                self.check_label(owner_def_id, label, ex.span);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        intravisit::walk_expr(self, ex);
+    }
+}
+
+impl<'tcx> SnapshotLabelsVisitor<'tcx> {
+    fn check_label(&mut self, owner_def_id: LocalDefId, label: Symbol, span: Span) {
+        if let Some(first_span) = self.snapshots.get(&(owner_def_id, label)) {
+            let label = label.as_str().trim_start_matches("prusti_snapshot_");
+            self.errors.push(
+                PrustiError::incorrect(
+                    format!("the snapshot label `{label}` is used more than once"),
+                    MultiSpan::from_span(span),
+                )
+                .add_note("the label is first used here", Some(*first_span)),
+            );
+        } else {
+            self.snapshots.insert((owner_def_id, label), span);
+        }
+    }
+}
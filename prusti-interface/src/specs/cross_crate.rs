@@ -1,9 +1,11 @@
+use prusti_common::config;
 use prusti_rustc_interface::{
+    hir::def_id::DefId,
     metadata::creader::CStore,
     serialize::{Decodable, Encodable},
     span::DUMMY_SP,
 };
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use std::{fs, io, path};
 
 use crate::{
@@ -79,6 +81,11 @@ impl CrossCrateSpecs {
                 }
             }
         }
+        // The bodies of the specifications of the local crate have already
+        // been loaded, so only the imported specifications can be inconsistent.
+        for error in def_spec.validate(env) {
+            error.emit(&env.diagnostic);
+        }
     }
 
     fn write_into_file(
@@ -88,9 +95,31 @@ impl CrossCrateSpecs {
     ) -> io::Result<usize> {
         use std::io::Write;
         let mut encoder = DefSpecsEncoder::new(env.tcx());
-        def_spec.proc_specs.encode(&mut encoder);
-        def_spec.type_specs.encode(&mut encoder);
-        CrossCrateBodies::from(&env.body).encode(&mut encoder);
+        let mut bodies = CrossCrateBodies::from(&env.body);
+        if config::export_only_public_specs() {
+            let mut exported_specs = FxHashSet::default();
+            def_spec.for_each_spec_reference(|item, _, spec| {
+                if Self::is_exported(env, item) {
+                    exported_specs.insert(spec);
+                }
+            });
+            bodies.retain_specs(env.tcx(), |def_id| exported_specs.contains(&def_id));
+        }
+        // Crates without bodies to export (e.g. crates containing only
+        // `#[extern_spec]` blocks) use a compact encoding, which omits the
+        // bodies section and is flagged in the header.
+        let has_bodies = !bodies.is_empty();
+        has_bodies.encode(&mut encoder);
+        if config::export_only_public_specs() {
+            Self::public_specs(env, &def_spec.proc_specs).encode(&mut encoder);
+            Self::public_specs(env, &def_spec.type_specs).encode(&mut encoder);
+        } else {
+            def_spec.proc_specs.encode(&mut encoder);
+            def_spec.type_specs.encode(&mut encoder);
+        }
+        if has_bodies {
+            bodies.encode(&mut encoder);
+        }
 
         // Probably not needed; dir should already exist?
         fs::create_dir_all(path.parent().unwrap())?;
@@ -98,6 +127,28 @@ impl CrossCrateSpecs {
         file.write(&encoder.into_inner())
     }
 
+    /// Keeps only the specifications of items that are reachable from other
+    /// crates.
+    fn public_specs<'a, T>(
+        env: &Environment,
+        specs: &'a FxHashMap<DefId, T>,
+    ) -> FxHashMap<DefId, &'a T> {
+        specs
+            .iter()
+            .filter(|(def_id, _)| Self::is_exported(env, **def_id))
+            .map(|(def_id, spec)| (*def_id, spec))
+            .collect()
+    }
+
+    /// Whether other crates can use the item, e.g. a `pub` function that is
+    /// not inside of a private module. The specifications of items of other
+    /// crates, given by `#[extern_spec]`, are always exported.
+    fn is_exported(env: &Environment, def_id: DefId) -> bool {
+        def_id.as_local().map_or(true, |local_id| {
+            env.tcx().effective_visibilities(()).is_reachable(local_id)
+        })
+    }
+
     #[tracing::instrument(level = "debug", skip(env, def_spec))]
     fn import_from_file(
         env: &mut Environment,
@@ -111,11 +162,14 @@ impl CrossCrateSpecs {
         file.read_to_end(&mut data)?;
         let mut decoder = DefSpecsDecoder::new(env.tcx(), &data, path.clone(), crate_name);
 
+        let has_bodies = bool::decode(&mut decoder);
         let proc_specs = FxHashMap::decode(&mut decoder);
         let type_specs = FxHashMap::decode(&mut decoder);
-        let mirs_of_specs = CrossCrateBodies::decode(&mut decoder);
+        let mirs_of_specs = has_bodies.then(|| CrossCrateBodies::decode(&mut decoder));
         def_spec.import_external(proc_specs, type_specs, env);
-        env.body.import_external_bodies(mirs_of_specs);
+        if let Some(mirs_of_specs) = mirs_of_specs {
+            env.body.import_external_bodies(mirs_of_specs);
+        }
         Ok(())
     }
 }
@@ -247,6 +247,7 @@ impl<'tcx> ExternSpecResolver<'tcx> {
                         MultiSpan::from_span(*span),
                     )
                     .add_note(err_note, None)
+                    .set_code("P0005")
                     .emit(env_diagnostic);
                 }
                 ExternSpecResolverError::InvalidGenerics(diff, def_id, span) => {
@@ -257,6 +258,7 @@ impl<'tcx> ExternSpecResolver<'tcx> {
                         MultiSpan::from_span(*span),
                     )
                     .add_note(err_note, None)
+                    .set_code("P0005")
                     .emit(env_diagnostic);
                 }
                 ExternSpecResolverError::ResolvedToDefault(def_id, span) => {
@@ -267,6 +269,7 @@ impl<'tcx> ExternSpecResolver<'tcx> {
                         MultiSpan::from_span(*span),
                     )
                     .add_note(err_note, None)
+                    .set_code("P0005")
                     .emit(env_diagnostic);
                 }
             }
@@ -282,6 +285,7 @@ impl<'tcx> ExternSpecResolver<'tcx> {
                 format!("duplicate specification for {function_name}"),
                 MultiSpan::from_spans(specs.iter().map(|s| s.1).collect()),
             )
+            .set_code("P0004")
             .emit(env_diagnostic);
         }
     }
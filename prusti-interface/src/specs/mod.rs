@@ -1,5 +1,5 @@
 use crate::{
-    environment::Environment,
+    environment::{EnvQuery, Environment},
     utils::{
         has_abstract_predicate_attr, has_extern_spec_attr, has_prusti_attr, has_to_model_fn_attr,
         read_prusti_attr, read_prusti_attrs,
@@ -10,7 +10,7 @@ use log::debug;
 use prusti_common::config;
 use prusti_rustc_interface::{
     ast::ast,
-    data_structures::fx::FxHashMap,
+    data_structures::fx::{FxHashMap, FxHashSet},
     errors::MultiSpan,
     hir::{
         self,
@@ -43,12 +43,15 @@ struct ProcedureSpecRefs {
     pure: bool,
     abstract_predicate: bool,
     trusted: bool,
+    trusted_reason: Option<String>,
+    no_panic: bool,
+    modifies: Option<Vec<String>>,
 }
 
 impl From<&ProcedureSpecRefs> for ProcedureSpecificationKind {
     fn from(refs: &ProcedureSpecRefs) -> Self {
         if refs.abstract_predicate {
-            ProcedureSpecificationKind::Predicate(None)
+            ProcedureSpecificationKind::Predicate(vec![])
         } else if refs.pure {
             ProcedureSpecificationKind::Pure
         } else {
@@ -60,7 +63,10 @@ impl From<&ProcedureSpecRefs> for ProcedureSpecificationKind {
 #[derive(Debug, Default)]
 struct TypeSpecRefs {
     invariants: Vec<LocalDefId>,
+    variant_invariants: Vec<(String, LocalDefId)>,
+    trait_invariants: Vec<DefId>,
     trusted: bool,
+    transparent: bool,
     model: Option<(String, LocalDefId)>,
     countexample_print: Vec<(Option<String>, LocalDefId)>,
 }
@@ -81,8 +87,11 @@ pub struct SpecCollector<'a, 'tcx> {
     loop_specs: Vec<LocalDefId>,
     loop_variants: Vec<LocalDefId>,
     type_specs: FxHashMap<LocalDefId, TypeSpecRefs>,
+    /// Invariants declared on traits with `#[invariant]`.
+    trait_invariants: Vec<LocalDefId>,
     prusti_assertions: Vec<LocalDefId>,
     prusti_assumptions: Vec<LocalDefId>,
+    prusti_invariant_assumptions: Vec<LocalDefId>,
     prusti_refutations: Vec<LocalDefId>,
     ghost_begin: Vec<LocalDefId>,
     ghost_end: Vec<LocalDefId>,
@@ -98,8 +107,10 @@ impl<'a, 'tcx> SpecCollector<'a, 'tcx> {
             loop_specs: vec![],
             loop_variants: vec![],
             type_specs: FxHashMap::default(),
+            trait_invariants: vec![],
             prusti_assertions: vec![],
             prusti_assumptions: vec![],
+            prusti_invariant_assumptions: vec![],
             prusti_refutations: vec![],
             ghost_begin: vec![],
             ghost_end: vec![],
@@ -118,11 +129,16 @@ impl<'a, 'tcx> SpecCollector<'a, 'tcx> {
         self.determine_procedure_specs(&mut def_spec);
         self.determine_extern_specs(&mut def_spec);
         self.determine_loop_specs(&mut def_spec);
+        self.determine_trait_invariants();
         self.determine_type_specs(&mut def_spec);
         self.determine_prusti_assertions(&mut def_spec);
         self.determine_prusti_assumptions(&mut def_spec);
+        self.determine_prusti_invariant_assumptions(&mut def_spec);
         self.determine_prusti_refutations(&mut def_spec);
         self.determine_ghost_begin_ends(&mut def_spec);
+        if config::warn_uninstantiated_generic_specs() {
+            self.warn_uninstantiated_generic_specs();
+        }
         // TODO: remove spec functions (make sure none are duplicated or left over)
         // Load all local spec MIR bodies, for export and later use
         self.ensure_local_mirs_fetched(&def_spec);
@@ -137,7 +153,7 @@ impl<'a, 'tcx> SpecCollector<'a, 'tcx> {
             // This would lead to refinement inheritance if there is a trait involved.
             // Instead, we require the user to explicitly make annotations.
             spec.set_kind(refs.into());
-            let mut kind_override = None;
+            let mut predicate_bodies = vec![];
 
             for spec_id_ref in &refs.spec_id_refs {
                 match spec_id_ref {
@@ -154,18 +170,29 @@ impl<'a, 'tcx> SpecCollector<'a, 'tcx> {
                         spec.add_purity(*self.spec_functions.get(spec_id).unwrap(), self.env);
                     }
                     SpecIdRef::Pledge { lhs, rhs } => {
+                        if refs.pure {
+                            PrustiError::warning(
+                                "pledges of pure functions have no effect, because pure \
+                                functions cannot return mutable references",
+                                MultiSpan::from(self.env.query.get_def_span(*local_id)),
+                            )
+                            .set_code("P0007")
+                            .emit(&self.env.diagnostic);
+                        }
+                        let rhs = self.spec_functions.get(rhs).unwrap();
+                        let rhs_attrs = self.env.query.get_local_attributes(*rhs);
                         spec.add_pledge(typed::Pledge {
-                            reference: None, // FIXME: Currently only `result` is supported.
+                            reference: read_prusti_attr("pledge_reference", rhs_attrs)
+                                .map(|index| index.parse().unwrap()),
                             lhs: lhs.as_ref().map(|spec_id| {
                                 self.spec_functions.get(spec_id).unwrap().to_def_id()
                             }),
-                            rhs: self.spec_functions.get(rhs).unwrap().to_def_id(),
+                            rhs: rhs.to_def_id(),
                         });
                     }
                     SpecIdRef::Predicate(spec_id) => {
-                        kind_override = Some(ProcedureSpecificationKind::Predicate(Some(
-                            self.spec_functions.get(spec_id).unwrap().to_def_id(),
-                        )));
+                        predicate_bodies
+                            .push(self.spec_functions.get(spec_id).unwrap().to_def_id());
                     }
                     SpecIdRef::Terminates(spec_id) => {
                         spec.set_terminates(*self.spec_functions.get(spec_id).unwrap());
@@ -174,9 +201,15 @@ impl<'a, 'tcx> SpecCollector<'a, 'tcx> {
             }
 
             spec.set_trusted(refs.trusted);
+            spec.set_trusted_reason(refs.trusted_reason.clone());
+            spec.set_no_panic(refs.no_panic);
+            if let Some(modified_fields) = &refs.modifies {
+                self.check_modified_fields(*local_id, modified_fields);
+            }
+            spec.set_modifies(refs.modifies.clone());
 
-            if let Some(kind) = kind_override {
-                spec.set_kind(kind);
+            if !predicate_bodies.is_empty() {
+                spec.set_kind(ProcedureSpecificationKind::Predicate(predicate_bodies));
             }
 
             if !spec.specs_with_constraints.is_empty() && !*spec.base_spec.trusted.expect_inherent()
@@ -186,6 +219,7 @@ impl<'a, 'tcx> SpecCollector<'a, 'tcx> {
                     "Type-conditional spec refinements can only be applied to trusted functions",
                     MultiSpan::from(span),
                 )
+                .set_code("P0001")
                 .emit(&self.env.diagnostic);
             } else {
                 def_spec.proc_specs.insert(local_id.to_def_id(), spec);
@@ -206,6 +240,7 @@ impl<'a, 'tcx> SpecCollector<'a, 'tcx> {
                     ),
                     MultiSpan::from_span(self.env.query.get_def_span(spec_id)),
                 )
+                .set_code("P0002")
                 .emit(&self.env.diagnostic);
             }
 
@@ -229,14 +264,39 @@ impl<'a, 'tcx> SpecCollector<'a, 'tcx> {
         }
     }
 
+    /// Attaches the invariants of traits to the local types that implement
+    /// them.
+    fn determine_trait_invariants(&mut self) {
+        let tcx = self.env.tcx();
+        for local_id in self.trait_invariants.iter() {
+            let trait_id = tcx.parent(local_id.to_def_id());
+            for impl_id in tcx.all_impls(trait_id) {
+                let self_ty = tcx.type_of(impl_id).subst_identity();
+                if let ty::TyKind::Adt(adt_def, _) = self_ty.kind() {
+                    if let Some(type_id) = adt_def.did().as_local() {
+                        self.type_specs
+                            .entry(type_id)
+                            .or_default()
+                            .trait_invariants
+                            .push(local_id.to_def_id());
+                    }
+                }
+            }
+        }
+    }
+
     fn determine_type_specs(&self, def_spec: &mut typed::DefSpecificationMap) {
         for (type_id, refs) in self.type_specs.iter() {
-            if !refs.invariants.is_empty() && !prusti_common::config::enable_type_invariants() {
+            let has_invariants = !refs.invariants.is_empty()
+                || !refs.variant_invariants.is_empty()
+                || !refs.trait_invariants.is_empty();
+            if has_invariants && !prusti_common::config::enable_type_invariants() {
                 let span = self.env.query.get_def_span(*type_id);
                 PrustiError::unsupported(
                     "Type invariants need to be enabled with the feature flag `enable_type_invariants`",
                     MultiSpan::from(span),
                 )
+                .set_code("P0003")
                 .emit(&self.env.diagnostic);
             }
 
@@ -251,7 +311,14 @@ impl<'a, 'tcx> SpecCollector<'a, 'tcx> {
                             .map(LocalDefId::to_def_id)
                             .collect(),
                     ),
+                    variant_invariants: refs
+                        .variant_invariants
+                        .iter()
+                        .map(|(variant, local_id)| (variant.clone(), local_id.to_def_id()))
+                        .collect(),
+                    trait_invariants: refs.trait_invariants.clone(),
                     trusted: SpecificationItem::Inherent(refs.trusted),
+                    transparent: refs.transparent,
                     model: refs.model.clone(),
                     counterexample_print: refs.countexample_print.clone(),
                 },
@@ -278,6 +345,16 @@ impl<'a, 'tcx> SpecCollector<'a, 'tcx> {
             );
         }
     }
+    fn determine_prusti_invariant_assumptions(&self, def_spec: &mut typed::DefSpecificationMap) {
+        for local_id in self.prusti_invariant_assumptions.iter() {
+            def_spec.prusti_invariant_assumptions.insert(
+                local_id.to_def_id(),
+                typed::PrustiInvariantAssumption {
+                    assumption: *local_id,
+                },
+            );
+        }
+    }
     fn determine_prusti_refutations(&self, def_spec: &mut typed::DefSpecificationMap) {
         for local_id in self.prusti_refutations.iter() {
             def_spec.prusti_refutations.insert(
@@ -302,6 +379,45 @@ impl<'a, 'tcx> SpecCollector<'a, 'tcx> {
         }
     }
 
+    /// Warns about generic functions with specifications that are never
+    /// referenced in the crate, so no call ever exercises their specifications.
+    /// Methods of traits and trait implementations are skipped, because they
+    /// are usually called through the trait.
+    fn warn_uninstantiated_generic_specs(&self) {
+        let tcx = self.env.tcx();
+        let mut visitor = CollectReferencedFnsVisitor {
+            env_query: self.env.query,
+            result: FxHashSet::default(),
+        };
+        self.env.query.hir().walk_toplevel_module(&mut visitor);
+
+        for (local_id, refs) in self.procedure_specs.iter() {
+            let def_id = local_id.to_def_id();
+            let is_trait_item = tcx.trait_of_item(def_id).is_some()
+                || tcx
+                    .impl_of_method(def_id)
+                    .and_then(|impl_id| tcx.trait_id_of_impl(impl_id))
+                    .is_some();
+            if refs.spec_id_refs.is_empty()
+                || refs.trusted
+                || is_trait_item
+                || !tcx.generics_of(def_id).requires_monomorphization(tcx)
+                || visitor.result.contains(&def_id)
+            {
+                continue;
+            }
+            PrustiError::warning(
+                format!(
+                    "the specification of the generic function `{}` is never exercised, \
+                    because the function is not used in this crate",
+                    self.env.name.get_item_name(def_id),
+                ),
+                MultiSpan::from(self.env.query.get_def_span(def_id)),
+            )
+            .emit(&self.env.diagnostic);
+        }
+    }
+
     fn ensure_local_mirs_fetched(&mut self, def_spec: &typed::DefSpecificationMap) {
         let (specs, pure_fns, predicates) = def_spec.defid_for_export();
         for def_id in &specs {
@@ -328,6 +444,155 @@ impl<'a, 'tcx> SpecCollector<'a, 'tcx> {
             self.env.body.load_closure_body(def_id);
         }
     }
+
+    /// The encoder treats the `Transparent`, `Deref`, `AsRef` and `Borrow`
+    /// implementations of a `#[prusti::transparent]` type as accesses to its
+    /// field, which is only sound if they do nothing else.
+    fn check_transparent_accessors(&self, impl_: &'tcx hir::Impl<'tcx>) {
+        let Some(trait_id) = impl_
+            .of_trait
+            .as_ref()
+            .and_then(|trait_ref| trait_ref.trait_def_id())
+        else {
+            return;
+        };
+        let trait_name = self.env.name.get_absolute_item_name(trait_id);
+        if !matches!(
+            trait_name.as_str(),
+            "std::ops::Deref"
+                | "core::ops::Deref"
+                | "std::convert::AsRef"
+                | "core::convert::AsRef"
+                | "std::borrow::Borrow"
+                | "core::borrow::Borrow"
+                | "prusti_contracts::Transparent"
+        ) {
+            return;
+        }
+        let hir::TyKind::Path(hir::QPath::Resolved(None, path)) = impl_.self_ty.kind else {
+            return;
+        };
+        let is_transparent = path
+            .res
+            .opt_def_id()
+            .and_then(DefId::as_local)
+            .map_or(false, |local_id| {
+                has_prusti_attr(self.env.query.get_local_attributes(local_id), "transparent")
+            });
+        if !is_transparent {
+            return;
+        }
+        let hir = self.env.query.hir();
+        for item_ref in impl_.items {
+            if let hir::ImplItemKind::Fn(_, body_id) = hir.impl_item(item_ref.id).kind {
+                let body = hir.body(body_id);
+                if !is_field_reference(body) {
+                    let trait_name = trait_name.rsplit("::").next().unwrap();
+                    PrustiError::incorrect(
+                        format!(
+                            "the `{trait_name}` implementation of a `#[prusti::transparent]` type \
+                            must only return a reference to its field"
+                        ),
+                        MultiSpan::from(body.value.span),
+                    )
+                    .set_code("P0010")
+                    .emit(&self.env.diagnostic);
+                }
+            }
+        }
+    }
+
+    /// Checks that `#[modifies(...)]` is on a method taking `&mut self` of a
+    /// struct, that it only names fields of the struct, and that the fields
+    /// it does not name can be framed by the encoder.
+    fn check_modified_fields(&self, local_id: LocalDefId, modified_fields: &[String]) {
+        let tcx = self.env.tcx();
+        let span = MultiSpan::from(self.env.query.get_def_span(local_id));
+        let sig = self
+            .env
+            .query
+            .get_fn_sig(local_id, self.env.query.identity_substs(local_id))
+            .skip_binder();
+        let ty::TyKind::Ref(_, self_ty, hir::Mutability::Mut) = sig.inputs()[0].kind() else {
+            PrustiError::incorrect(
+                "the `#[modifies]` attribute can only be used on methods taking `&mut self`",
+                span,
+            )
+            .set_code("P0011")
+            .emit(&self.env.diagnostic);
+            return;
+        };
+        let (adt_def, adt_substs) = match self_ty.kind() {
+            ty::TyKind::Adt(adt_def, adt_substs) if adt_def.is_struct() => (adt_def, adt_substs),
+            _ => {
+                PrustiError::unsupported(
+                    format!("`#[modifies]` is not supported on methods of `{self_ty}`"),
+                    span,
+                )
+                .set_code("P0011")
+                .emit(&self.env.diagnostic);
+                return;
+            }
+        };
+        let fields = &adt_def.non_enum_variant().fields;
+        for modified_field in modified_fields {
+            if !fields
+                .iter()
+                .any(|field| field.ident(tcx).as_str() == modified_field)
+            {
+                PrustiError::incorrect(
+                    format!("`{modified_field}` is not a field of `{self_ty}`"),
+                    span.clone(),
+                )
+                .set_code("P0011")
+                .emit(&self.env.diagnostic);
+            }
+        }
+        for field in fields {
+            let field_name = field.ident(tcx).to_string();
+            let field_ty = field.ty(tcx, adt_substs);
+            if !modified_fields.contains(&field_name)
+                && matches!(field_ty.kind(), ty::TyKind::Ref(..) | ty::TyKind::RawPtr(..))
+            {
+                PrustiError::unsupported(
+                    format!(
+                        "the unmodified field `{field_name}` has the reference type `{field_ty}`, \
+                        which `#[modifies]` cannot frame"
+                    ),
+                    span.clone(),
+                )
+                .add_note(
+                    format!("list `self.{field_name}` among the modified fields"),
+                    None,
+                )
+                .set_code("P0011")
+                .emit(&self.env.diagnostic);
+            }
+        }
+    }
+}
+
+/// Checks whether `body` is `&self.field`, i.e. only returns a shared reference
+/// to a field of its first parameter.
+fn is_field_reference(body: &hir::Body) -> bool {
+    let hir::ExprKind::Block(block, _) = body.value.kind else {
+        return false;
+    };
+    let (Some(expr), Some(param)) = (block.expr, body.params.first()) else {
+        return false;
+    };
+    let hir::ExprKind::AddrOf(hir::BorrowKind::Ref, hir::Mutability::Not, inner) = expr.kind else {
+        return false;
+    };
+    let hir::ExprKind::Field(base, _) = inner.kind else {
+        return false;
+    };
+    block.stmts.is_empty()
+        && matches!(
+            base.kind,
+            hir::ExprKind::Path(hir::QPath::Resolved(None, path))
+                if path.res == hir::def::Res::Local(param.pat.hir_id)
+        )
 }
 
 /// Collects the LocalDefId of all closures. This is used to find all
@@ -354,6 +619,40 @@ impl<'tcx> intravisit::Visitor<'tcx> for CollectAllClosureDefsVisitor<'tcx> {
     }
 }
 
+/// Collects the functions that are referenced anywhere in the crate, either by
+/// their path or as the target of a method call.
+struct CollectReferencedFnsVisitor<'tcx> {
+    env_query: EnvQuery<'tcx>,
+    result: FxHashSet<DefId>,
+}
+
+impl<'tcx> intravisit::Visitor<'tcx> for CollectReferencedFnsVisitor<'tcx> {
+    type Map = Map<'tcx>;
+    type NestedFilter = prusti_rustc_interface::middle::hir::nested_filter::All;
+
+    fn nested_visit_map(&mut self) -> Self::Map {
+        self.env_query.hir()
+    }
+
+    fn visit_expr(&mut self, expr: &'tcx hir::Expr<'tcx>) {
+        let owner_def_id = expr.hir_id.owner.def_id;
+        if self.env_query.has_body(owner_def_id) {
+            let typeck_results = self.env_query.tcx().typeck(owner_def_id);
+            if let hir::ExprKind::Path(ref qpath) = expr.kind {
+                if let hir::def::Res::Def(_, def_id) = typeck_results.qpath_res(qpath, expr.hir_id)
+                {
+                    self.result.insert(def_id);
+                }
+            }
+            if let Some(def_id) = typeck_results.type_dependent_def_id(expr.hir_id) {
+                self.result.insert(def_id);
+            }
+        }
+
+        intravisit::walk_expr(self, expr)
+    }
+}
+
 fn parse_spec_id(spec_id: String, def_id: DefId) -> SpecificationId {
     spec_id
         .try_into()
@@ -392,8 +691,6 @@ fn get_procedure_spec_ids(def_id: DefId, attrs: &[ast::Attribute]) -> Option<Pro
             .map(|raw_spec_id| SpecIdRef::Terminates(parse_spec_id(raw_spec_id, def_id))),
     );
     spec_id_refs.extend(
-        // TODO: pledges with LHS that is not "result" would need to carry the
-        // LHS expression through typing
         read_prusti_attrs("pledge_spec_id_ref", attrs)
             .into_iter()
             .map(|raw_spec_id| SpecIdRef::Pledge {
@@ -415,7 +712,8 @@ fn get_procedure_spec_ids(def_id: DefId, attrs: &[ast::Attribute]) -> Option<Pro
         _ => unreachable!(),
     }
     spec_id_refs.extend(
-        read_prusti_attr("pred_spec_id_ref", attrs)
+        read_prusti_attrs("pred_spec_id_ref", attrs)
+            .into_iter()
             .map(|raw_spec_id| SpecIdRef::Predicate(parse_spec_id(raw_spec_id, def_id))),
     );
     let is_predicate = matches!(spec_id_refs.last(), Some(SpecIdRef::Predicate(..)));
@@ -427,14 +725,32 @@ fn get_procedure_spec_ids(def_id: DefId, attrs: &[ast::Attribute]) -> Option<Pro
     let pure = has_prusti_attr(attrs, "pure");
     let trusted = has_prusti_attr(attrs, "trusted")
         || (!is_predicate && config::opt_in_verification() && !has_prusti_attr(attrs, "verified"));
+    let trusted_reason = read_prusti_attr("trusted_reason", attrs);
+    let no_panic = has_prusti_attr(attrs, "no_panic");
+    let modifies = read_prusti_attr("modifies", attrs).map(|fields| {
+        fields
+            .split(',')
+            .filter(|field| !field.is_empty())
+            .map(ToString::to_string)
+            .collect()
+    });
     let abstract_predicate = has_abstract_predicate_attr(attrs);
 
-    if abstract_predicate || pure || trusted || !spec_id_refs.is_empty() {
+    if abstract_predicate
+        || pure
+        || trusted
+        || no_panic
+        || modifies.is_some()
+        || !spec_id_refs.is_empty()
+    {
         Some(ProcedureSpecRefs {
             spec_id_refs,
             pure,
             abstract_predicate,
             trusted,
+            trusted_reason,
+            no_panic,
+            modifies,
         })
     } else {
         None
@@ -463,6 +779,32 @@ impl<'a, 'tcx> intravisit::Visitor<'tcx> for SpecCollector<'a, 'tcx> {
         }
     }
 
+    fn visit_item(&mut self, item: &'tcx hir::Item<'tcx>) {
+        intravisit::walk_item(self, item);
+
+        // Collect transparent newtypes
+        let local_id = item.owner_id.def_id;
+        let attrs = self.env.query.get_local_attributes(local_id);
+        if has_prusti_attr(attrs, "transparent") {
+            if let hir::ItemKind::Struct(variant_data, _) = &item.kind {
+                if variant_data.fields().len() == 1 {
+                    self.type_specs.entry(local_id).or_default().transparent = true;
+                    return;
+                }
+            }
+            PrustiError::incorrect(
+                "only structs with a single field can be marked as `#[prusti::transparent]`",
+                MultiSpan::from(item.span),
+            )
+            .set_code("P0008")
+            .emit(&self.env.diagnostic);
+        }
+
+        if let hir::ItemKind::Impl(impl_) = &item.kind {
+            self.check_transparent_accessors(impl_);
+        }
+    }
+
     fn visit_fn(
         &mut self,
         fn_kind: intravisit::FnKind<'tcx>,
@@ -505,6 +847,24 @@ impl<'a, 'tcx> intravisit::Visitor<'tcx> for SpecCollector<'a, 'tcx> {
                     .push(local_id);
             }
 
+            // Collect invariants of traits
+            if has_prusti_attr(attrs, "trait_invariant_spec") {
+                self.trait_invariants.push(local_id);
+            }
+
+            // Collect invariants of enum variants
+            if let Some(variant) = read_prusti_attr("variant_invariant_spec", attrs) {
+                let self_id = fn_decl.inputs[0].hir_id;
+                let hir = self.env.query.hir();
+                let impl_id = hir.parent_id(hir.parent_id(self_id));
+                let type_id = get_type_id_from_impl_node(hir.get(impl_id)).unwrap();
+                self.type_specs
+                    .entry(type_id.as_local().unwrap())
+                    .or_default()
+                    .variant_invariants
+                    .push((variant, local_id));
+            }
+
             // Collect trusted type flag
             if has_prusti_attr(attrs, "trusted_type") {
                 let self_id = fn_decl.inputs[0].hir_id;
@@ -539,6 +899,10 @@ impl<'a, 'tcx> intravisit::Visitor<'tcx> for SpecCollector<'a, 'tcx> {
                 self.prusti_assumptions.push(local_id);
             }
 
+            if has_prusti_attr(attrs, "prusti_invariant_assumption") {
+                self.prusti_invariant_assumptions.push(local_id);
+            }
+
             if has_prusti_attr(attrs, "prusti_refutation") {
                 self.prusti_refutations.push(local_id);
             }
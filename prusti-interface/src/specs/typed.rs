@@ -17,6 +17,7 @@ pub struct DefSpecificationMap {
     pub type_specs: FxHashMap<DefId, TypeSpecification>,
     pub prusti_assertions: FxHashMap<DefId, PrustiAssertion>,
     pub prusti_assumptions: FxHashMap<DefId, PrustiAssumption>,
+    pub prusti_invariant_assumptions: FxHashMap<DefId, PrustiInvariantAssumption>,
     pub prusti_refutations: FxHashMap<DefId, PrustiRefutation>,
     pub ghost_begin: FxHashMap<DefId, GhostBegin>,
     pub ghost_end: FxHashMap<DefId, GhostEnd>,
@@ -47,6 +48,10 @@ impl DefSpecificationMap {
         self.prusti_assumptions.get(def_id)
     }
 
+    pub fn get_invariant_assumption(&self, def_id: &DefId) -> Option<&PrustiInvariantAssumption> {
+        self.prusti_invariant_assumptions.get(def_id)
+    }
+
     pub fn get_refutation(&self, def_id: &DefId) -> Option<&PrustiRefutation> {
         self.prusti_refutations.get(def_id)
     }
@@ -59,6 +64,158 @@ impl DefSpecificationMap {
         self.ghost_end.get(def_id)
     }
 
+    /// Returns the local procedures and types that are marked as `#[trusted]`.
+    pub fn trusted_items(&self) -> Vec<DefId> {
+        let trusted_procs = self
+            .proc_specs
+            .iter()
+            .filter(|(_, spec_graph)| {
+                spec_graph
+                    .base_spec
+                    .trusted
+                    .extract_inherit()
+                    .unwrap_or(false)
+            })
+            .map(|(def_id, _)| *def_id);
+        let trusted_types = self
+            .type_specs
+            .iter()
+            .filter(|(_, spec)| spec.trusted.extract_inherit().unwrap_or(false))
+            .map(|(def_id, _)| *def_id);
+        trusted_procs
+            .chain(trusted_types)
+            .filter(|def_id| def_id.is_local())
+            .collect()
+    }
+
+    /// Returns the reason given for trusting the local procedure `def_id`, if any.
+    pub fn get_trusted_reason(&self, def_id: &DefId) -> Option<&str> {
+        self.proc_specs
+            .get(def_id)?
+            .base_spec
+            .trusted_reason
+            .extract_with_selective_replacement()?
+            .as_deref()
+    }
+
+    /// Compares the procedure and type specifications of `self` with the ones
+    /// of `other`. Specifications that are only in `other` are reported as
+    /// added, the ones that are only in `self` as removed.
+    pub fn diff(&self, other: &DefSpecificationMap) -> SpecDiff {
+        let (added_proc_specs, removed_proc_specs, changed_proc_specs) =
+            diff_specs(&self.proc_specs, &other.proc_specs);
+        let (added_type_specs, removed_type_specs, changed_type_specs) =
+            diff_specs(&self.type_specs, &other.type_specs);
+        SpecDiff {
+            added_proc_specs,
+            removed_proc_specs,
+            changed_proc_specs,
+            added_type_specs,
+            removed_type_specs,
+            changed_type_specs,
+        }
+    }
+
+    /// Checks the referential integrity of the map: every specification
+    /// function referenced by a procedure or type specification must have its
+    /// MIR body loaded. The violations are returned as internal errors.
+    pub fn validate(&self, env: &Environment) -> Vec<PrustiError> {
+        self.inconsistencies(
+            |def_id| env.body.has_spec_body(def_id),
+            |def_id| env.body.has_predicate_body(def_id),
+        )
+        .into_iter()
+        .map(|inconsistency| {
+            PrustiError::internal(
+                format!(
+                    "the {} of `{}` refers to `{}`, whose body is not loaded",
+                    inconsistency.role,
+                    env.name.get_item_name(inconsistency.item),
+                    env.name.get_item_name(inconsistency.spec),
+                ),
+                crate::specs::MultiSpan::from_span(env.query.get_def_span(inconsistency.item)),
+            )
+        })
+        .collect()
+    }
+
+    /// Returns the specification functions referenced by the map whose bodies
+    /// are not available according to `has_spec_body` (or `has_predicate_body`
+    /// for the bodies of predicates), sorted by the referencing item.
+    fn inconsistencies(
+        &self,
+        has_spec_body: impl Fn(DefId) -> bool,
+        has_predicate_body: impl Fn(DefId) -> bool,
+    ) -> Vec<SpecInconsistency> {
+        let mut inconsistencies = Vec::new();
+        self.for_each_spec_reference(|item, role, spec| {
+            let has_body = if role == PREDICATE_BODY {
+                has_predicate_body(spec)
+            } else {
+                has_spec_body(spec)
+            };
+            if !has_body {
+                inconsistencies.push(SpecInconsistency { item, spec, role });
+            }
+        });
+        inconsistencies.sort_by_key(|inconsistency| {
+            let (item, spec) = (inconsistency.item, inconsistency.spec);
+            (item.krate, item.index, spec.krate, spec.index)
+        });
+        inconsistencies.dedup();
+        inconsistencies
+    }
+
+    /// Calls `f` with every specification function referenced by the
+    /// procedure and type specifications of the map, together with the
+    /// referencing item and the role of the specification function.
+    pub(crate) fn for_each_spec_reference(&self, mut f: impl FnMut(DefId, &'static str, DefId)) {
+        for (item, spec_graph) in &self.proc_specs {
+            let all_specs = std::iter::once(&spec_graph.base_spec)
+                .chain(spec_graph.specs_with_constraints.values());
+            for spec in all_specs {
+                for pre in spec.pres.extract_with_selective_replacement().into_iter().flatten() {
+                    f(*item, "precondition", *pre);
+                }
+                for post in spec.posts.extract_with_selective_replacement().into_iter().flatten() {
+                    f(*item, "postcondition", *post);
+                }
+                for pledge in spec
+                    .pledges
+                    .extract_with_selective_replacement()
+                    .into_iter()
+                    .flatten()
+                {
+                    for part in pledge.lhs.iter().chain(std::iter::once(&pledge.rhs)) {
+                        f(*item, "pledge", *part);
+                    }
+                }
+                if let Some(Some(term)) = spec.terminates.extract_with_selective_replacement() {
+                    f(*item, "termination measure", term.to_def_id());
+                }
+                if let Some(ProcedureSpecificationKind::Predicate(bodies)) =
+                    spec.kind.extract_with_selective_replacement()
+                {
+                    for body in bodies {
+                        f(*item, PREDICATE_BODY, *body);
+                    }
+                }
+            }
+        }
+        for (item, spec) in &self.type_specs {
+            let invariants = spec
+                .invariant
+                .extract_with_selective_replacement()
+                .into_iter()
+                .flatten()
+                .chain(spec.variant_invariants.iter().map(|(_, def_id)| def_id))
+                .chain(spec.trait_invariants.iter());
+            for invariant in invariants {
+                f(*item, "type invariant", *invariant);
+            }
+        }
+    }
+
     pub(crate) fn defid_for_export(
         &self,
     ) -> (
@@ -92,13 +249,13 @@ impl DefSpecificationMap {
                 let is_trusted = spec.trusted.extract_inherit().expect("Expected trusted")
                 // It has to be non-extern_spec which is trusted (since extern_specs are always trusted)
                     && (*def_id == spec.source || !def_id.is_local());
-                if spec.kind.is_pure().expect("Expected pure") && !is_trusted {
+                if spec.is_pure().expect("Expected pure") && !is_trusted {
                     pure_fns.push(*def_id)
                 }
-                if let Some(ProcedureSpecificationKind::Predicate(Some(def_id))) =
+                if let Some(ProcedureSpecificationKind::Predicate(bodies)) =
                     spec.kind.extract_with_selective_replacement()
                 {
-                    predicates.push(*def_id);
+                    predicates.extend(bodies);
                 }
             }
         }
@@ -106,6 +263,8 @@ impl DefSpecificationMap {
             if let Some(invariants) = spec.invariant.extract_with_selective_replacement() {
                 specs.extend(invariants);
             }
+            specs.extend(spec.variant_invariants.iter().map(|(_, def_id)| def_id));
+            specs.extend(spec.trait_invariants.iter());
         }
         (specs, pure_fns, predicates)
     }
@@ -129,6 +288,7 @@ impl DefSpecificationMap {
                     env.query.get_def_span(spec_id_b),
                 ]),
             )
+            .set_code("P0006")
             .emit(&env.diagnostic)
         };
         for (k, v) in proc_specs {
@@ -171,6 +331,11 @@ impl DefSpecificationMap {
             .values()
             .map(|spec| format!("{spec:?}"))
             .collect();
+        let invariant_assumptions: Vec<_> = self
+            .prusti_invariant_assumptions
+            .values()
+            .map(|spec| format!("{spec:?}"))
+            .collect();
         let refutations: Vec<_> = self
             .prusti_refutations
             .values()
@@ -182,6 +347,7 @@ impl DefSpecificationMap {
         values.extend(type_specs);
         values.extend(asserts);
         values.extend(assumptions);
+        values.extend(invariant_assumptions);
         values.extend(refutations);
         if hide_uuids {
             let uuid =
@@ -202,7 +368,73 @@ impl DefSpecificationMap {
     }
 }
 
-#[derive(Debug, Clone, TyEncodable, TyDecodable)]
+/// The role of the bodies of predicates, which are loaded separately from the
+/// other specification functions.
+const PREDICATE_BODY: &str = "predicate body";
+
+/// A specification function referenced by a [DefSpecificationMap] whose body
+/// is not loaded, found by [DefSpecificationMap::validate].
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct SpecInconsistency {
+    /// The procedure or type whose specification refers to `spec`.
+    item: DefId,
+    spec: DefId,
+    /// How `item` uses `spec`, e.g. "precondition".
+    role: &'static str,
+}
+
+/// The differences between two [DefSpecificationMap]s, computed by
+/// [DefSpecificationMap::diff]. Each list is sorted by `DefId`.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct SpecDiff {
+    pub added_proc_specs: Vec<DefId>,
+    pub removed_proc_specs: Vec<DefId>,
+    pub changed_proc_specs: Vec<DefId>,
+    pub added_type_specs: Vec<DefId>,
+    pub removed_type_specs: Vec<DefId>,
+    pub changed_type_specs: Vec<DefId>,
+}
+
+impl SpecDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added_proc_specs.is_empty()
+            && self.removed_proc_specs.is_empty()
+            && self.changed_proc_specs.is_empty()
+            && self.added_type_specs.is_empty()
+            && self.removed_type_specs.is_empty()
+            && self.changed_type_specs.is_empty()
+    }
+}
+
+/// Returns the keys that are only in `new`, the keys that are only in `old`,
+/// and the keys whose specifications differ.
+fn diff_specs<T: PartialEq>(
+    old: &FxHashMap<DefId, T>,
+    new: &FxHashMap<DefId, T>,
+) -> (Vec<DefId>, Vec<DefId>, Vec<DefId>) {
+    let sorted = |mut def_ids: Vec<DefId>| {
+        def_ids.sort_by_key(|def_id| (def_id.krate, def_id.index));
+        def_ids
+    };
+    let added = new
+        .keys()
+        .filter(|def_id| !old.contains_key(def_id))
+        .copied()
+        .collect();
+    let removed = old
+        .keys()
+        .filter(|def_id| !new.contains_key(def_id))
+        .copied()
+        .collect();
+    let changed = old
+        .iter()
+        .filter(|(def_id, spec)| matches!(new.get(def_id), Some(other) if other != *spec))
+        .map(|(def_id, _)| *def_id)
+        .collect();
+    (sorted(added), sorted(removed), sorted(changed))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, TyEncodable, TyDecodable)]
 pub struct ProcedureSpecification {
     // DefId of fn signature to which the spec was attached.
     // For `extern_spec` it differs to the key in `proc_specs`
@@ -212,11 +444,33 @@ pub struct ProcedureSpecification {
     pub posts: SpecificationItem<Vec<DefId>>,
     pub pledges: SpecificationItem<Vec<Pledge>>,
     pub trusted: SpecificationItem<bool>,
+    /// The reason given in `#[trusted("...")]`, if any.
+    pub trusted_reason: SpecificationItem<Option<String>>,
+    pub no_panic: SpecificationItem<bool>,
+    /// The fields of `self` that the procedure may modify, given by
+    /// `#[modifies(...)]`. `None` if the procedure may modify all fields.
+    pub modifies: SpecificationItem<Option<Vec<String>>>,
     pub terminates: SpecificationItem<Option<LocalDefId>>,
     pub purity: SpecificationItem<Option<DefId>>, // for type-conditional spec refinements
 }
 
 impl ProcedureSpecification {
+    pub fn is_pure(&self) -> Result<bool, ProcedureSpecificationKindError> {
+        self.kind.is_pure()
+    }
+
+    pub fn is_impure(&self) -> Result<bool, ProcedureSpecificationKindError> {
+        self.kind.is_impure()
+    }
+
+    pub fn is_abstract_predicate(&self) -> Result<bool, ProcedureSpecificationKindError> {
+        self.kind.is_abstract_predicate()
+    }
+
+    pub fn is_concrete_predicate(&self) -> Result<bool, ProcedureSpecificationKindError> {
+        self.kind.is_concrete_predicate()
+    }
+
     pub fn empty(source: DefId) -> Self {
         ProcedureSpecification {
             source,
@@ -227,19 +481,22 @@ impl ProcedureSpecification {
             posts: SpecificationItem::Empty,
             pledges: SpecificationItem::Empty,
             trusted: SpecificationItem::Inherent(false),
+            trusted_reason: SpecificationItem::Inherent(None),
+            no_panic: SpecificationItem::Inherent(false),
+            modifies: SpecificationItem::Inherent(None),
             terminates: SpecificationItem::Inherent(None),
             purity: SpecificationItem::Inherent(None),
         }
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, TyEncodable, TyDecodable)]
+#[derive(Clone, Debug, PartialEq, Eq, TyEncodable, TyDecodable)]
 pub enum ProcedureSpecificationKind {
     Impure,
     Pure,
-    /// The specification is a predicate with the enclosed body.
-    /// The body can be None to account for abstract predicates.
-    Predicate(Option<DefId>),
+    /// The specification is a predicate whose body is the conjunction of the
+    /// enclosed bodies. There are no bodies for abstract predicates.
+    Predicate(Vec<DefId>),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, TyEncodable, TyDecodable)]
@@ -260,6 +517,28 @@ impl ProcedureSpecificationKind {
     pub fn is_impure(&self) -> bool {
         matches!(self, ProcedureSpecificationKind::Impure)
     }
+
+    /// Predicates are pure as well.
+    pub fn is_pure(&self) -> bool {
+        matches!(
+            self,
+            ProcedureSpecificationKind::Pure | ProcedureSpecificationKind::Predicate(_)
+        )
+    }
+
+    pub fn is_predicate(&self) -> bool {
+        matches!(self, ProcedureSpecificationKind::Predicate(_))
+    }
+
+    /// A predicate without a body.
+    pub fn is_abstract_predicate(&self) -> bool {
+        matches!(self, ProcedureSpecificationKind::Predicate(bodies) if bodies.is_empty())
+    }
+
+    /// A predicate with a body.
+    pub fn is_concrete_predicate(&self) -> bool {
+        matches!(self, ProcedureSpecificationKind::Predicate(bodies) if !bodies.is_empty())
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -269,14 +548,22 @@ pub enum LoopSpecification {
 }
 
 /// Specification of a type.
-#[derive(Debug, Clone, TyEncodable, TyDecodable)]
+#[derive(Debug, Clone, PartialEq, Eq, TyEncodable, TyDecodable)]
 pub struct TypeSpecification {
     // DefId of type defn to which the spec was attached.
     // Currently identical to the key in `type_specs`, but once
     // `extern_spec` for type invs is supported it could differ.
     pub source: DefId,
     pub invariant: SpecificationItem<Vec<DefId>>,
+    /// Invariants of single enum variants, with the name of the variant.
+    pub variant_invariants: Vec<(String, DefId)>,
+    /// Invariants of the traits implemented by the type, which are encoded
+    /// with the type as `Self`.
+    pub trait_invariants: Vec<DefId>,
     pub trusted: SpecificationItem<bool>,
+    /// Whether the type is a single-field struct marked with
+    /// `#[prusti::transparent]`, which specifications can treat like its field.
+    pub transparent: bool,
     pub model: Option<(String, LocalDefId)>,
     pub counterexample_print: Vec<(Option<String>, LocalDefId)>,
 }
@@ -286,7 +573,10 @@ impl TypeSpecification {
         TypeSpecification {
             source,
             invariant: SpecificationItem::Empty,
+            variant_invariants: vec![],
+            trait_invariants: vec![],
             trusted: SpecificationItem::Inherent(false),
+            transparent: false,
             model: None,
             counterexample_print: vec![],
         }
@@ -303,6 +593,13 @@ pub struct PrustiAssumption {
     pub assumption: LocalDefId,
 }
 
+/// The marker of `prusti_assume_invariant!`; the value whose type invariant is
+/// assumed is the place borrowed by the closure.
+#[derive(Debug, Clone)]
+pub struct PrustiInvariantAssumption {
+    pub assumption: LocalDefId,
+}
+
 #[derive(Debug, Clone)]
 pub struct PrustiRefutation {
     pub refutation: LocalDefId,
@@ -322,7 +619,7 @@ pub struct GhostEnd {
 /// A contract can be divided into multiple specifications:
 /// - **Base spec**: A spec without constraints.
 /// - **Constrained specs**: Multiple specs which have [SpecConstraintKind] constraints.
-#[derive(Default, Debug, Clone, TyEncodable, TyDecodable)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, TyEncodable, TyDecodable)]
 pub struct SpecGraph<T> {
     /// The base specification which has no constraints
     pub base_spec: T,
@@ -486,6 +783,31 @@ impl SpecGraph<ProcedureSpecification> {
             .for_each(|s| s.trusted.set(trusted));
     }
 
+    /// Sets the reason for trusting the procedure for the base spec and all
+    /// constrained specs.
+    pub fn set_trusted_reason(&mut self, reason: Option<String>) {
+        self.base_spec.trusted_reason.set(reason.clone());
+        self.specs_with_constraints
+            .values_mut()
+            .for_each(|s| s.trusted_reason.set(reason.clone()));
+    }
+
+    /// Sets the no-panic flag for the base spec and all constrained specs.
+    pub fn set_no_panic(&mut self, no_panic: bool) {
+        self.base_spec.no_panic.set(no_panic);
+        self.specs_with_constraints
+            .values_mut()
+            .for_each(|s| s.no_panic.set(no_panic));
+    }
+
+    /// Sets the modified fields for the base spec and all constrained specs.
+    pub fn set_modifies(&mut self, modifies: Option<Vec<String>>) {
+        self.base_spec.modifies.set(modifies.clone());
+        self.specs_with_constraints
+            .values_mut()
+            .for_each(|s| s.modifies.set(modifies.clone()));
+    }
+
     /// Sets the termination flag for the base spec and all constrained specs.
     pub fn set_terminates(&mut self, terminates: LocalDefId) {
         self.base_spec.terminates.set(Some(terminates));
@@ -496,10 +818,10 @@ impl SpecGraph<ProcedureSpecification> {
 
     /// Sets the [ProcedureSpecificationKind] for the base spec and all constrained specs.
     pub fn set_kind(&mut self, kind: ProcedureSpecificationKind) {
-        self.base_spec.kind.set(kind);
+        self.base_spec.kind.set(kind.clone());
         self.specs_with_constraints
             .values_mut()
-            .for_each(|s| s.kind.set(kind));
+            .for_each(|s| s.kind.set(kind.clone()));
     }
 
     /// Lazily gets/creates a constrained spec.
@@ -532,7 +854,9 @@ impl SpecGraph<ProcedureSpecification> {
 
 #[derive(Debug, Clone, PartialEq, Eq, TyEncodable, TyDecodable)]
 pub struct Pledge {
-    pub reference: Option<()>, // TODO: pledge references
+    /// The index of the argument whose borrow the pledge is attached to, or `None` if the
+    /// pledge is attached to the borrow of the result.
+    pub reference: Option<usize>,
     pub lhs: Option<DefId>,
     pub rhs: DefId,
 }
@@ -673,12 +997,28 @@ impl SpecificationItem<ProcedureSpecificationKind> {
         })
     }
 
-    pub fn get_predicate_body(&self) -> Result<Option<&DefId>, ProcedureSpecificationKindError> {
+    pub fn is_abstract_predicate(&self) -> Result<bool, ProcedureSpecificationKindError> {
+        self.validate()?;
+
+        Ok(self
+            .extract_with_selective_replacement()
+            .map_or(false, ProcedureSpecificationKind::is_abstract_predicate))
+    }
+
+    pub fn is_concrete_predicate(&self) -> Result<bool, ProcedureSpecificationKindError> {
+        self.validate()?;
+
+        Ok(self
+            .extract_with_selective_replacement()
+            .map_or(false, ProcedureSpecificationKind::is_concrete_predicate))
+    }
+
+    pub fn get_predicate_bodies(&self) -> Result<&[DefId], ProcedureSpecificationKindError> {
         self.validate()?;
 
         Ok(match self.extract_with_selective_replacement() {
-            Some(ProcedureSpecificationKind::Predicate(pred_id)) => pred_id.as_ref(),
-            _ => None,
+            Some(ProcedureSpecificationKind::Predicate(bodies)) => bodies,
+            _ => &[],
         })
     }
 
@@ -692,7 +1032,8 @@ impl SpecificationItem<ProcedureSpecificationKind> {
                     Ok(())
                 }
                 _ => Err(ProcedureSpecificationKindError::InvalidSpecKindRefinement(
-                    *base, *refined,
+                    base.clone(),
+                    refined.clone(),
                 )),
             }
         } else {
@@ -780,6 +1121,9 @@ impl Refinable for ProcedureSpecification {
             pledges: self.pledges.refine(replace_empty(&EMPTYP, &other.pledges)),
             kind: self.kind.refine(&other.kind),
             trusted: self.trusted.refine(&other.trusted),
+            trusted_reason: self.trusted_reason.refine(&other.trusted_reason),
+            no_panic: self.no_panic.refine(&other.no_panic),
+            modifies: self.modifies.refine(&other.modifies),
             terminates: self.terminates.refine(&other.terminates),
             purity: self.purity.refine(&other.purity),
         }
@@ -789,6 +1133,17 @@ impl Refinable for ProcedureSpecification {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use prusti_rustc_interface::hir::def_id::DefIndex;
+
+    fn def_id(index: u32) -> DefId {
+        DefId::local(DefIndex::from_u32(index))
+    }
+
+    fn map_with_proc_spec(spec: ProcedureSpecification) -> DefSpecificationMap {
+        let mut map = DefSpecificationMap::new();
+        map.proc_specs.insert(spec.source, SpecGraph::new(spec));
+        map
+    }
 
     mod refinement {
         use crate::specs::typed::{Refinable, SpecificationItem};
@@ -936,7 +1291,7 @@ mod tests {
 
             #[test]
             fn refine_impure_with_predicate() {
-                let item = Refined(Impure, Predicate(None));
+                let item = Refined(Impure, Predicate(vec![]));
                 let result = item.validate().expect_err("Expected error");
                 assert!(matches!(
                     result,
@@ -956,7 +1311,7 @@ mod tests {
 
             #[test]
             fn refine_pure_with_predicate() {
-                let item = Refined(Pure, Predicate(None));
+                let item = Refined(Pure, Predicate(vec![]));
                 let result = item.validate().expect_err("Expected error");
                 assert!(matches!(
                     result,
@@ -966,7 +1321,7 @@ mod tests {
 
             #[test]
             fn refine_predicate_with_pure() {
-                let item = Refined(Predicate(None), Pure);
+                let item = Refined(Predicate(vec![]), Pure);
                 let result = item.validate().expect_err("Expected error");
                 assert!(matches!(
                     result,
@@ -976,7 +1331,7 @@ mod tests {
 
             #[test]
             fn refine_predicate_with_impure() {
-                let item = Refined(Predicate(None), Impure);
+                let item = Refined(Predicate(vec![]), Impure);
                 let result = item.validate().expect_err("Expected error");
                 assert!(matches!(
                     result,
@@ -1006,14 +1361,14 @@ mod tests {
                     empty: (Empty, true),
                     inherent_impure: (Inherent(Impure), true),
                     inherent_pure: (Inherent(Pure), false),
-                    inherent_predicate: (Inherent(Predicate(None)), false),
+                    inherent_predicate: (Inherent(Predicate(vec![])), false),
                     inherited_impure: (Inherited(Impure), true),
                     inherited_pure: (Inherited(Pure), false),
-                    inherited_predicate: (Inherited(Predicate(None)), false),
+                    inherited_predicate: (Inherited(Predicate(vec![])), false),
                     refined_impure_parent_impure_child: (Refined(Impure, Impure), true),
                     refined_impure_parent_pure_child: (Refined(Impure, Pure), false),
                     refined_pure_parent_with_pure_child: (Refined(Pure, Pure), false),
-                    refined_predicate_parent_with_predicate_child: (Refined(Predicate(None), Predicate(None)), false),
+                    refined_predicate_parent_with_predicate_child: (Refined(Predicate(vec![]), Predicate(vec![])), false),
             );
         }
 
@@ -1038,15 +1393,243 @@ mod tests {
                     empty: (Empty, false),
                     inherent_impure: (Inherent(Impure), false),
                     inherent_pure: (Inherent(Pure), true),
-                    inherent_predicate: (Inherent(Predicate(None)), true),
+                    inherent_predicate: (Inherent(Predicate(vec![])), true),
                     inherited_impure: (Inherited(Impure), false),
                     inherited_pure: (Inherited(Pure), true),
-                    inherited_predicate: (Inherited(Predicate(None)), true),
+                    inherited_predicate: (Inherited(Predicate(vec![])), true),
                     refined_impure_parent_impure_child: (Refined(Impure, Impure), false),
                     refined_impure_parent_pure_child: (Refined(Impure, Pure), true),
                     refined_pure_parent_with_pure: (Refined(Pure, Pure), true),
-                    refined_predicate_parent_with_predicate_child: (Refined(Predicate(None), Predicate(None)), true),
+                    refined_predicate_parent_with_predicate_child: (Refined(Predicate(vec![]), Predicate(vec![])), true),
             );
         }
+
+        mod is_abstract_predicate {
+            use super::*;
+
+            macro_rules! abstract_predicate_checks {
+                    ($($name:ident: $value:expr,)*) => {
+                        $(
+                            #[test]
+                            fn $name() {
+                                let (item, expected) = $value;
+                                let item: SpecificationItem<ProcedureSpecificationKind> = item;
+                                let result = item.is_abstract_predicate().expect("Expected abstract predicate");
+                                assert_eq!(result, expected);
+                            }
+                        )*
+                    }
+                }
+
+            abstract_predicate_checks!(
+                    empty: (Empty, false),
+                    inherent_impure: (Inherent(Impure), false),
+                    inherent_pure: (Inherent(Pure), false),
+                    inherent_abstract_predicate: (Inherent(Predicate(vec![])), true),
+                    inherent_concrete_predicate: (Inherent(Predicate(vec![def_id(1)])), false),
+                    inherited_abstract_predicate: (Inherited(Predicate(vec![])), true),
+                    refined_impure_parent_pure_child: (Refined(Impure, Pure), false),
+                    refined_abstract_predicate_parent_with_concrete_predicate_child: (Refined(Predicate(vec![]), Predicate(vec![def_id(1)])), false),
+            );
+        }
+
+        mod is_concrete_predicate {
+            use super::*;
+
+            macro_rules! concrete_predicate_checks {
+                    ($($name:ident: $value:expr,)*) => {
+                        $(
+                            #[test]
+                            fn $name() {
+                                let (item, expected) = $value;
+                                let item: SpecificationItem<ProcedureSpecificationKind> = item;
+                                let result = item.is_concrete_predicate().expect("Expected concrete predicate");
+                                assert_eq!(result, expected);
+                            }
+                        )*
+                    }
+                }
+
+            concrete_predicate_checks!(
+                    empty: (Empty, false),
+                    inherent_impure: (Inherent(Impure), false),
+                    inherent_pure: (Inherent(Pure), false),
+                    inherent_abstract_predicate: (Inherent(Predicate(vec![])), false),
+                    inherent_concrete_predicate: (Inherent(Predicate(vec![def_id(1)])), true),
+                    inherited_concrete_predicate: (Inherited(Predicate(vec![def_id(1)])), true),
+                    refined_impure_parent_pure_child: (Refined(Impure, Pure), false),
+                    refined_abstract_predicate_parent_with_concrete_predicate_child: (Refined(Predicate(vec![]), Predicate(vec![def_id(1)])), true),
+            );
+        }
+    }
+
+    mod diff {
+        use super::*;
+
+        fn map_with_post(proc_id: DefId, post: DefId) -> DefSpecificationMap {
+            let mut spec = ProcedureSpecification::empty(proc_id);
+            spec.posts = SpecificationItem::Inherent(vec![post]);
+            let mut map = map_with_proc_spec(spec);
+            map.type_specs.insert(def_id(10), TypeSpecification::empty(def_id(10)));
+            map
+        }
+
+        #[test]
+        fn identical_maps() {
+            let old = map_with_post(def_id(1), def_id(2));
+            let new = map_with_post(def_id(1), def_id(2));
+            assert!(old.diff(&new).is_empty());
+        }
+
+        #[test]
+        fn changed_postcondition() {
+            let old = map_with_post(def_id(1), def_id(2));
+            let new = map_with_post(def_id(1), def_id(3));
+            let diff = old.diff(&new);
+            assert_eq!(
+                diff,
+                SpecDiff {
+                    changed_proc_specs: vec![def_id(1)],
+                    ..SpecDiff::default()
+                }
+            );
+        }
+
+        #[test]
+        fn added_and_removed_procedures() {
+            let old = map_with_post(def_id(1), def_id(2));
+            let new = map_with_post(def_id(4), def_id(2));
+            let diff = old.diff(&new);
+            assert_eq!(diff.added_proc_specs, vec![def_id(4)]);
+            assert_eq!(diff.removed_proc_specs, vec![def_id(1)]);
+            assert!(diff.changed_proc_specs.is_empty());
+            assert!(diff.changed_type_specs.is_empty());
+        }
+    }
+
+    mod inconsistencies {
+        use super::*;
+
+        /// Bodies with an index below 100 are loaded.
+        fn is_loaded(def_id: DefId) -> bool {
+            def_id.index.as_u32() < 100
+        }
+
+        fn inconsistency(item: u32, spec: u32, role: &'static str) -> SpecInconsistency {
+            SpecInconsistency {
+                item: def_id(item),
+                spec: def_id(spec),
+                role,
+            }
+        }
+
+        #[test]
+        fn consistent_map() {
+            let mut spec = ProcedureSpecification::empty(def_id(1));
+            spec.pres = SpecificationItem::Inherent(vec![def_id(2)]);
+            spec.posts = SpecificationItem::Inherent(vec![def_id(3)]);
+            let map = map_with_proc_spec(spec);
+            assert_eq!(map.inconsistencies(is_loaded, is_loaded), vec![]);
+        }
+
+        #[test]
+        fn postcondition_without_body() {
+            let mut spec = ProcedureSpecification::empty(def_id(1));
+            spec.pres = SpecificationItem::Inherent(vec![def_id(2)]);
+            spec.posts = SpecificationItem::Inherent(vec![def_id(102)]);
+            let map = map_with_proc_spec(spec);
+            assert_eq!(
+                map.inconsistencies(is_loaded, is_loaded),
+                vec![inconsistency(1, 102, "postcondition")]
+            );
+        }
+
+        #[test]
+        fn pledge_without_body() {
+            let mut spec = ProcedureSpecification::empty(def_id(1));
+            spec.pledges = SpecificationItem::Inherent(vec![Pledge {
+                reference: None,
+                lhs: Some(def_id(2)),
+                rhs: def_id(103),
+            }]);
+            let map = map_with_proc_spec(spec);
+            assert_eq!(
+                map.inconsistencies(is_loaded, is_loaded),
+                vec![inconsistency(1, 103, "pledge")]
+            );
+        }
+
+        #[test]
+        fn predicate_without_body() {
+            let mut spec = ProcedureSpecification::empty(def_id(1));
+            let bodies = vec![def_id(2)];
+            spec.kind = SpecificationItem::Inherent(ProcedureSpecificationKind::Predicate(bodies));
+            let map = map_with_proc_spec(spec);
+            // The body is loaded as a spec, but not as a predicate.
+            assert_eq!(
+                map.inconsistencies(is_loaded, |_| false),
+                vec![inconsistency(1, 2, "predicate body")]
+            );
+        }
+
+        #[test]
+        fn type_invariant_without_body() {
+            let mut spec = TypeSpecification::empty(def_id(1));
+            spec.invariant = SpecificationItem::Inherent(vec![def_id(2)]);
+            spec.trait_invariants = vec![def_id(104)];
+            let mut map = DefSpecificationMap::new();
+            map.type_specs.insert(def_id(1), spec);
+            assert_eq!(
+                map.inconsistencies(is_loaded, is_loaded),
+                vec![inconsistency(1, 104, "type invariant")]
+            );
+        }
+    }
+
+    mod procedure_specification_kind {
+        use super::*;
+        use ProcedureSpecificationKind::*;
+
+        fn predicate_with_body() -> ProcedureSpecificationKind {
+            Predicate(vec![def_id(1)])
+        }
+
+        #[test]
+        fn impure() {
+            assert!(Impure.is_impure());
+            assert!(!Impure.is_pure());
+            assert!(!Impure.is_predicate());
+            assert!(!Impure.is_abstract_predicate());
+            assert!(!Impure.is_concrete_predicate());
+        }
+
+        #[test]
+        fn pure() {
+            assert!(!Pure.is_impure());
+            assert!(Pure.is_pure());
+            assert!(!Pure.is_predicate());
+            assert!(!Pure.is_abstract_predicate());
+            assert!(!Pure.is_concrete_predicate());
+        }
+
+        #[test]
+        fn abstract_predicate() {
+            let kind = Predicate(vec![]);
+            assert!(!kind.is_impure());
+            assert!(kind.is_pure());
+            assert!(kind.is_predicate());
+            assert!(kind.is_abstract_predicate());
+            assert!(!kind.is_concrete_predicate());
+        }
+
+        #[test]
+        fn concrete_predicate() {
+            let kind = predicate_with_body();
+            assert!(!kind.is_impure());
+            assert!(kind.is_pure());
+            assert!(kind.is_predicate());
+            assert!(!kind.is_abstract_predicate());
+            assert!(kind.is_concrete_predicate());
+        }
     }
 }
@@ -242,6 +242,22 @@ pub fn has_abstract_predicate_attr(attrs: &[ast::Attribute]) -> bool {
     has_prusti_attr(attrs, "abstract_predicate")
 }
 
+/// Read the error codes listed in the `prusti::allow(...)` attributes
+/// (e.g. `#[prusti::allow(P0007)]`).
+pub fn read_prusti_allow_attrs(attrs: &[ast::Attribute]) -> Vec<String> {
+    attrs
+        .iter()
+        .filter(|attr| {
+            matches!(&attr.kind, ast::AttrKind::Normal(normal_attr)
+                if normal_attr.item.path.segments.len() == 2
+                    && normal_attr.item.path.segments[0].ident.as_str() == "prusti"
+                    && normal_attr.item.path.segments[1].ident.as_str() == "allow")
+        })
+        .flat_map(|attr| attr.meta_item_list().unwrap_or_default())
+        .filter_map(|nested| nested.ident().map(|ident| ident.to_string()))
+        .collect()
+}
+
 /// Read the value stored in a Prusti attribute (e.g. `prusti::<attr_name>="...")`.
 pub fn read_prusti_attrs<T: Borrow<ast::Attribute>>(attr_name: &str, attrs: &[T]) -> Vec<String> {
     let mut strings = vec![];
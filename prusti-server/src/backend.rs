@@ -1,4 +1,4 @@
-use crate::dump_viper_program;
+use crate::{dump_viper_program, export_viper_prelude};
 use prusti_common::{
     config,
     vir::{LoweringContext, ToViper},
@@ -32,6 +32,11 @@ impl<'a> Backend<'a> {
                         );
                     }
 
+                    if config::export_viper_prelude() && program.is_viper_prelude() {
+                        stopwatch.start_next("exporting viper prelude");
+                        export_viper_prelude(&ast_utils, viper_program, program.get_name());
+                    }
+
                     stopwatch.start_next("viper verification");
                     viper.verify(viper_program)
                 })
@@ -10,7 +10,7 @@ use once_cell::sync::Lazy;
 use prusti_common::{
     config,
     report::log::{report, to_legal_file_name},
-    vir::{program_normalization::NormalizationInfo, ToViper},
+    vir::{program::VIPER_PRELUDE_NAME, program_normalization::NormalizationInfo, ToViper},
     Stopwatch,
 };
 use std::{fs::create_dir_all, path::PathBuf};
@@ -62,8 +62,15 @@ pub fn process_verification_request<'v, 't: 'v>(
             );
         }
 
+        if config::export_viper_prelude() && request.program.is_viper_prelude() {
+            stopwatch.start_next("exporting viper prelude");
+            export_viper_prelude(&ast_utils, viper_program, request.program.get_name());
+        }
+
         viper_program
     };
+    let dump_or_export = config::dump_viper_program()
+        || (config::export_viper_prelude() && request.program.is_viper_prelude());
 
     // Only for testing: Print the hash and skip verification.
     if config::print_hash() {
@@ -73,7 +80,7 @@ pub fn process_verification_request<'v, 't: 'v>(
         );
         println!("Hash of the request is: {hash}");
         // Some tests need the dump to report a diff of the Viper programs.
-        if config::dump_viper_program() {
+        if dump_or_export {
             ast_utils.with_local_frame(16, || {
                 let _ = build_or_dump_viper_program();
             });
@@ -89,7 +96,7 @@ pub fn process_verification_request<'v, 't: 'v>(
                 &result,
                 request.program.get_name()
             );
-            if config::dump_viper_program() {
+            if dump_or_export {
                 ast_utils.with_local_frame(16, || {
                     let _ = build_or_dump_viper_program();
                 });
@@ -142,6 +149,23 @@ pub fn dump_viper_program(
     report(namespace, filename, ast_utils.pretty_print(program));
 }
 
+/// Exports the program that contains the Viper prelude of a crate as a `.sil`
+/// file named after the crate.
+pub fn export_viper_prelude(
+    ast_utils: &viper::AstUtils,
+    program: viper::Program,
+    program_name: &str,
+) {
+    let namespace = "viper_prelude";
+    let crate_name = program_name
+        .strip_suffix(VIPER_PRELUDE_NAME)
+        .map(|name| name.trim_end_matches('_'))
+        .unwrap_or(program_name);
+    let filename = format!("{crate_name}.sil");
+    info!("Exporting Viper prelude to '{}/{}'", namespace, filename);
+    report(namespace, filename, ast_utils.pretty_print(program));
+}
+
 fn new_viper_verifier<'v, 't: 'v>(
     program_name: &str,
     verification_context: &'v viper::VerificationContext<'t>,
@@ -0,0 +1,8 @@
+use prusti_contracts::*;
+
+// The program is only encoded, so the failing postcondition is not reported.
+
+#[ensures(result > x)]
+pub fn wrong(x: u32) -> u32 {
+    x
+}
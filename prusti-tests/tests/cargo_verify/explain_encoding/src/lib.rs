@@ -0,0 +1,15 @@
+use prusti_contracts::*;
+
+#[ensures(result >= a && result >= b)]
+pub fn max(a: i32, b: i32) -> i32 {
+    if a > b {
+        a
+    } else {
+        b
+    }
+}
+
+#[ensures(result == a)]
+pub fn identity(a: i32) -> i32 {
+    a
+}
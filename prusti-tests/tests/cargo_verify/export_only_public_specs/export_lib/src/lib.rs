@@ -0,0 +1,14 @@
+use prusti_contracts::*;
+
+#[requires(x <= u32::MAX / 2)]
+#[ensures(result == 2 * x)]
+pub fn double(x: u32) -> u32 {
+    add(x, x)
+}
+
+// The specification of this function is not exported.
+#[requires(a <= u32::MAX - b)]
+#[ensures(result == a + b)]
+fn add(a: u32, b: u32) -> u32 {
+    a + b
+}
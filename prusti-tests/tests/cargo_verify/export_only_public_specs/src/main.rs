@@ -0,0 +1,14 @@
+use prusti_contracts::*;
+
+// Specifications of public functions are still exported when
+// `export_only_public_specs` is enabled.
+
+#[requires(x <= 1000)]
+fn test_public_spec(x: u32) {
+    let y = export_lib::double(x);
+    prusti_assert!(y == 2 * x);
+}
+
+fn main() {
+    test_public_spec(10);
+}
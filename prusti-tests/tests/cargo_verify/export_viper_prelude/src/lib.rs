@@ -0,0 +1,13 @@
+use prusti_contracts::*;
+
+#[pure]
+#[requires(n >= 0)]
+pub fn triangle(n: i64) -> i64 {
+    if n == 0 { 0 } else { n + triangle(n - 1) }
+}
+
+#[requires(n >= 0)]
+#[ensures(result == triangle(n))]
+pub fn compute_triangle(n: i64) -> i64 {
+    triangle(n)
+}
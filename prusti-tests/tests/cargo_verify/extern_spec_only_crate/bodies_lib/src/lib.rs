@@ -0,0 +1,17 @@
+use prusti_contracts::*;
+
+// The same `#[extern_spec]` blocks as in `extern_specs_lib`, together with a
+// pure function whose body is exported.
+
+#[extern_spec(std::cmp)]
+#[pure]
+fn max<T: Ord>(a: T, b: T) -> T;
+
+#[extern_spec(std::cmp)]
+#[pure]
+fn min<T: Ord>(a: T, b: T) -> T;
+
+#[pure]
+pub fn clamp(value: u32, low: u32, high: u32) -> u32 {
+    std::cmp::min(std::cmp::max(value, low), high)
+}
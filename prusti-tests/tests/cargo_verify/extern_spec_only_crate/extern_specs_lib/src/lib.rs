@@ -0,0 +1,13 @@
+use prusti_contracts::*;
+
+// This crate contains only `#[extern_spec]` blocks without pre- or
+// postconditions, so it has no bodies to export and its specs are written
+// using the compact encoding.
+
+#[extern_spec(std::cmp)]
+#[pure]
+fn max<T: Ord>(a: T, b: T) -> T;
+
+#[extern_spec(std::cmp)]
+#[pure]
+fn min<T: Ord>(a: T, b: T) -> T;
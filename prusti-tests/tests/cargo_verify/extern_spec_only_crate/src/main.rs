@@ -0,0 +1,22 @@
+// `extern crate` is required to force the crate to be used
+// otherwise Rust ignores it as it isn't used
+extern crate extern_specs_lib;
+use prusti_contracts::*;
+
+// The extern specs imported from a compactly encoded specs file are still
+// available: `max` and `min` can be used in specifications.
+
+#[ensures(result == std::cmp::max(a, b))]
+fn larger(a: u32, b: u32) -> u32 {
+    std::cmp::max(a, b)
+}
+
+#[ensures(result == std::cmp::min(a, b))]
+fn smaller(a: u32, b: u32) -> u32 {
+    std::cmp::min(a, b)
+}
+
+fn main() {
+    let _ = larger(1, 2);
+    let _ = smaller(1, 2);
+}
@@ -0,0 +1,38 @@
+// `extern crate` is required to force the crate to be used
+// otherwise Rust ignores it as it isn't used
+extern crate prusti_std;
+use prusti_contracts::*;
+
+// Verifying the extern specs of `Vec` imported from `prusti_std`.
+
+fn main() {
+    test_with_capacity();
+    test_reserve(Vec::new());
+    test_shrink_to_fit(Vec::new());
+}
+
+fn test_with_capacity() {
+    let v: Vec<i32> = Vec::with_capacity(10);
+    assert!(v.len() == 0);
+    assert!(v.capacity() >= 10);
+}
+
+#[requires(v.len() < 100)]
+fn test_reserve(mut v: Vec<i32>) {
+    v.push(1);
+    let len = v.len();
+    v.reserve(5);
+    assert!(v.len() == len);
+    assert!(v.capacity() >= v.len());
+    assert!(v.capacity() - v.len() >= 5);
+}
+
+#[requires(v.len() < 100)]
+fn test_shrink_to_fit(mut v: Vec<i32>) {
+    v.reserve(10);
+    v.push(1);
+    let len = v.len();
+    v.shrink_to_fit();
+    assert!(v.len() == len);
+    assert!(v.capacity() == v.len());
+}
@@ -0,0 +1,6 @@
+use prusti_contracts::*;
+
+#[requires(x < 10)]
+pub fn test(x: u32) -> u32 {
+    x + 1
+}
@@ -4,7 +4,7 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
-use cargo_test_support::{cargo_test, project, symlink_supported};
+use cargo_test_support::{cargo_test, project, symlink_supported, Project};
 use std::{
     fs,
     path::{Path, PathBuf},
@@ -90,7 +90,7 @@ error: could not compile `foo` due to previous error; 1 warning emitted
 ///
 /// For more details on the special syntax allowed in the `output.*` files, check the documentation
 /// of `cargo_test_support`: <https://doc.crates.io/contrib/tests/writing.html>.
-fn test_local_project<T: Into<PathBuf>>(project_name: T) {
+fn test_local_project<T: Into<PathBuf>>(project_name: T) -> Project {
     let mut project_builder = project().no_manifest();
     let relative_project_path = Path::new("tests/cargo_verify").join(project_name.into());
     let project_path = fs::canonicalize(&relative_project_path).unwrap_or_else(|_| {
@@ -164,6 +164,40 @@ fn test_local_project<T: Into<PathBuf>>(project_name: T) {
 
     // Run the test
     test_builder.run();
+    project
+}
+
+/// Returns the size of the specifications that `cargo-prusti` exported for the dependency
+/// `crate_name` of `project` into the target directory `target_dir`.
+fn exported_specs_size(project: &Project, target_dir: &str, crate_name: &str) -> u64 {
+    let deps_path = project.root().join(target_dir).join("verify/debug/deps");
+    let prefix = format!("lib{crate_name}");
+    fs::read_dir(&deps_path)
+        .unwrap_or_else(|_| panic!("Failed to read directory {}", deps_path.display()))
+        .map(|entry| entry.unwrap().path())
+        .find(|path| {
+            let file_name = path.file_name().unwrap().to_string_lossy();
+            file_name.starts_with(&prefix) && file_name.ends_with(".specs")
+        })
+        .map(|path| fs::metadata(path).unwrap().len())
+        .unwrap_or_else(|| panic!("No specifications of {crate_name} were exported"))
+}
+
+/// Tests a project whose expected output contains a panic message, which is
+/// affected by the `RUST_BACKTRACE` environment variable.
+fn test_local_project_without_backtrace(project_name: &str) {
+    let old_value = if let Ok(value) = std::env::var("RUST_BACKTRACE") {
+        // We need to remove this environment variable because it affects the
+        // compiler output.
+        std::env::remove_var("RUST_BACKTRACE");
+        Some(value)
+    } else {
+        None
+    };
+    test_local_project(project_name);
+    if let Some(value) = old_value {
+        std::env::set_var("RUST_BACKTRACE", value)
+    }
 }
 
 #[cargo_test]
@@ -175,6 +209,81 @@ fn test_symlinks() {
 // TODO: automatically create a test for each folder in `test/cargo_verify`.
 // Each of the following functions, listed in alphabetic order, test a crate in `cargo_verify/`.
 
+#[cargo_test]
+fn test_encode_only() {
+    let project = test_local_project("encode_only");
+    let dump_path = project.root().join("target/verify/log/vir_program_encode_only");
+    let programs: Vec<_> = fs::read_dir(&dump_path)
+        .unwrap_or_else(|_| panic!("Failed to read directory {}", dump_path.display()))
+        .map(|entry| fs::read_to_string(entry.unwrap().path()).unwrap())
+        .collect();
+    // The method of `wrong` is encoded, even though it does not verify.
+    assert!(programs
+        .iter()
+        .any(|program| program.contains("method ") && program.contains("wrong")));
+}
+
+#[cargo_test]
+fn test_explain_encoding() {
+    let project = test_local_project("explain_encoding");
+    let log_path = project.root().join("target/verify/log/explain_encoding");
+    let explanation = fs::read_to_string(log_path.join("max.vpr"))
+        .unwrap_or_else(|_| panic!("Failed to read the encoding of `max`"));
+    assert!(explanation.starts_with("method "));
+    // The postcondition is annotated with its source span.
+    assert!(explanation.contains("src/lib.rs:3:"));
+    assert!(explanation.contains("result >= a && result >= b"));
+    // Only the selected function is explained.
+    assert!(!log_path.join("identity.vpr").exists());
+}
+
+#[cargo_test]
+fn test_export_only_public_specs() {
+    let project = test_local_project("export_only_public_specs");
+    // Export the specifications again, this time including the one of the private `add`.
+    project
+        .process(cargo_prusti_path())
+        .arg("--quiet")
+        .env("CARGO_TARGET_DIR", "target/all_specs")
+        .env("PRUSTI_EXPORT_ONLY_PUBLIC_SPECS", "false")
+        .run();
+    assert!(
+        exported_specs_size(&project, "target", "export_lib")
+            < exported_specs_size(&project, "target/all_specs", "export_lib")
+    );
+}
+
+#[cargo_test]
+fn test_export_viper_prelude() {
+    let project = test_local_project("export_viper_prelude");
+    let prelude_path = project
+        .root()
+        .join("target/verify/log/viper_prelude/export_viper_prelude.sil");
+    let prelude = fs::read_to_string(&prelude_path)
+        .unwrap_or_else(|_| panic!("Failed to read {}", prelude_path.display()));
+    assert!(prelude.contains("function"));
+    assert!(prelude.contains("triangle"));
+    assert!(!prelude.contains("\nmethod "));
+}
+
+#[cargo_test]
+fn test_extern_spec_only_crate() {
+    let project = test_local_project("extern_spec_only_crate");
+    // `bodies_lib` contains the same extern specs as `extern_specs_lib`, but
+    // also a body to export, so its specs include the bodies section.
+    project
+        .process(cargo_prusti_path())
+        .arg("--quiet")
+        .arg("--manifest-path")
+        .arg("bodies_lib/Cargo.toml")
+        .env("CARGO_TARGET_DIR", "target/bodies_lib")
+        .run();
+    assert!(
+        exported_specs_size(&project, "target", "extern_specs_lib")
+            < exported_specs_size(&project, "target/bodies_lib", "bodies_lib")
+    );
+}
+
 #[cargo_test]
 fn test_failing_crate() {
     test_local_project("failing_crate");
@@ -205,6 +314,11 @@ fn test_overflow_checks() {
     test_local_project("overflow_checks");
 }
 
+#[cargo_test]
+fn test_prusti_std_vec() {
+    test_local_project("prusti_std_vec");
+}
+
 #[cargo_test]
 fn test_prusti_toml() {
     test_local_project("prusti_toml");
@@ -212,18 +326,12 @@ fn test_prusti_toml() {
 
 #[cargo_test]
 fn test_prusti_toml_fail() {
-    let old_value = if let Ok(value) = std::env::var("RUST_BACKTRACE") {
-        // We need to remove this environment variable because it affects the
-        // compiler output.
-        std::env::remove_var("RUST_BACKTRACE");
-        Some(value)
-    } else {
-        None
-    };
-    test_local_project("prusti_toml_fail");
-    if let Some(value) = old_value {
-        std::env::set_var("RUST_BACKTRACE", value)
-    }
+    test_local_project_without_backtrace("prusti_toml_fail");
+}
+
+#[cargo_test]
+fn test_prusti_toml_invalid_value() {
+    test_local_project_without_backtrace("prusti_toml_invalid_value");
 }
 
 // `#![no_std]` binaries on Windows are not a thing yet,
@@ -0,0 +1,10 @@
+use prusti_contracts::*;
+
+#[ensures(*x == old[mid](*x) + 1)] //~ ERROR: `old[label](..)` can only be used in assertions in the body of the function that takes the snapshot
+fn inc_twice(x: &mut u32) {
+    *x += 1;
+    snapshot!(mid);
+    *x += 1;
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use prusti_contracts::*;
+
+#[requires(x < 100)]
+#[ensures(result == 2 * x)]
+async fn double(x: u32) -> u32 {
+    2 * x
+}
+
+#[trusted]
+async fn trusted_double(x: u32) -> u32 {
+    2 * x
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use prusti_contracts::*;
+
+#[requires(true)]
+#[trusted("checked by hand")]
+fn trusted_after_requires() {}
+
+#[model]
+#[trusted("checked by hand")]
+struct TrustedAfterModel { x: i32 }
+
+fn main() {}
@@ -0,0 +1,26 @@
+use prusti_contracts::*;
+
+// Every field of a nested projection in an external specification must be
+// visible at the location of the `#[extern_spec]` block.
+
+#[extern_spec(external)]
+#[ensures(result.inner.len == len)] //~ ERROR field `len` of struct `Inner` is private
+fn with_len(len: usize) -> Outer;
+
+mod external {
+    pub struct Inner {
+        len: usize,
+    }
+
+    pub struct Outer {
+        pub inner: Inner,
+    }
+
+    pub fn with_len(len: usize) -> Outer {
+        Outer {
+            inner: Inner { len },
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+// This test checks that an `if` without an `else` branch is rejected in specifications.
+
+use prusti_contracts::*;
+
+#[ensures(if result { a > 0 })] //~ ERROR `if` may be missing an `else` clause
+fn is_positive(a: i32) -> bool {
+    a > 0
+}
+
+fn main() {}
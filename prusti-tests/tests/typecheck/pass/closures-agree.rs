@@ -0,0 +1,24 @@
+use prusti_contracts::*;
+
+#[pure]
+fn increment(x: i32) -> i32 {
+    x + 1
+}
+
+#[ensures(closures_agree!(result, |x: i32| x + 1, x: i32))]
+#[ensures(closures_agree!(result, increment, x: i32))]
+fn incrementer() -> impl Fn(i32) -> i32 {
+    closure!(
+        ensures(result == x + 1),
+        |x: i32| -> i32 { x + 1 }
+    )
+}
+
+#[requires(closures_agree!(f, g, a: u32, b: u32))]
+fn interchangeable<F: Fn(u32, u32) -> bool, G: Fn(u32, u32) -> bool>(f: F, g: G) {}
+
+fn main() {
+    let f = incrementer();
+    let _ = f(1);
+    interchangeable(|a: u32, b: u32| a < b, |a: u32, b: u32| b > a);
+}
@@ -0,0 +1,24 @@
+// compile-flags: -Pcheck_panics=false -Pcheck_overflows=false
+use prusti_contracts::*;
+
+fn main() {}
+
+fn unchecked_third(s: &[i32]) -> i32 {
+    s[2]
+}
+
+#[no_panic]
+fn third(s: &[i32]) -> i32 {
+    s[2]  //~ ERROR the array or slice index may be out of bounds
+}
+
+#[no_panic]
+#[requires(s.len() > 2)]
+fn checked_third(s: &[i32]) -> i32 {
+    s[2]
+}
+
+#[no_panic]
+fn increment(x: u32) -> u32 {
+    x + 1  //~ ERROR assertion might fail with "attempt to add with overflow"
+}
@@ -0,0 +1,21 @@
+use prusti_contracts::*;
+
+#[pure]
+#[requires(hi <= a.len())]
+fn sub_len_unordered(a: &[i32], lo: usize, hi: usize) -> usize {
+    a[lo..hi].len() //~ ERROR the range may be out of bounds when slicing
+}
+
+#[pure]
+#[requires(lo <= hi)]
+fn sub_len_too_long(a: &[i32], lo: usize, hi: usize) -> usize {
+    a[lo..hi].len() //~ ERROR the range may be out of bounds when slicing
+}
+
+#[pure]
+#[requires(lo <= hi && hi <= a.len())]
+fn sub_len_inclusive(a: &[i32], lo: usize, hi: usize) -> usize {
+    a[lo..=hi].len() //~ ERROR the range may be out of bounds when slicing
+}
+
+fn main() {}
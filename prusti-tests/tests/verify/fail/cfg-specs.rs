@@ -0,0 +1,51 @@
+// compile-flags: --cfg strict
+use prusti_contracts::*;
+
+#[cfg_attr(strict, requires(x > 0))]
+fn strict_only(x: i32) {}
+
+#[cfg_attr(not(strict), requires(x > 0))]
+fn lenient_only(x: i32) {}
+
+trait Checker {
+    fn check(&self, x: i32);
+}
+
+struct Strict;
+
+#[refine_trait_spec]
+impl Checker for Strict {
+    #[cfg(strict)]
+    #[requires(x > 0)]
+    fn check(&self, x: i32) {}
+
+    #[cfg(not(strict))]
+    #[requires(false)]
+    fn check(&self, x: i32) {}
+}
+
+struct Lenient;
+
+#[refine_trait_spec]
+impl Checker for Lenient {
+    #[cfg(not(strict))]
+    #[requires(x > 0)]
+    fn check(&self, x: i32) {}
+
+    #[cfg(strict)]
+    fn check(&self, x: i32) {}
+}
+
+fn test_functions() {
+    strict_only(1);
+    strict_only(0); //~ ERROR precondition might not hold
+    lenient_only(0);
+}
+
+fn test_methods() {
+    Strict.check(1);
+    Strict.check(0); //~ ERROR precondition might not hold
+    Lenient.check(0);
+}
+
+fn main() {}
@@ -0,0 +1,29 @@
+// compile-flags: -Pcheck_panics=false -Pcheck_assertions=true
+
+use prusti_contracts::*;
+
+#[requires(x > 10)]
+fn checked(x: u32) {
+    assert!(x > 5);
+    debug_assert!(x > 10);
+}
+
+fn unchecked(x: u32) {
+    assert!(x > 5); //~ ERROR the asserted expression might not hold
+}
+
+#[requires(x > 5)]
+fn unchecked_debug(x: u32) {
+    debug_assert!(x > 10); //~ ERROR the asserted expression might not hold
+}
+
+fn other_panics(x: u32) {
+    if x == 0 {
+        panic!();
+    }
+    if x == 1 {
+        unreachable!();
+    }
+}
+
+fn main() {}
@@ -0,0 +1,28 @@
+use prusti_contracts::*;
+
+#[pure]
+fn increment(x: u32) -> u32 {
+    x + 1
+}
+
+#[pure]
+fn double(x: u32) -> u32 {
+    x + x
+}
+
+#[ensures(closures_agree!(increment, double, x: u32))] //~ ERROR postcondition might not hold
+fn increment_is_double() {}
+
+#[requires(closures_agree!(f, g, x: u32))]
+fn interchangeable<F: Fn(u32) -> u32, G: Fn(u32) -> u32>(f: F, g: G) {}
+
+fn different_closures() {
+    interchangeable(|x: u32| x, |x: u32| x + 1); //~ ERROR precondition might not hold
+}
+
+#[ensures(closures_agree!(f, g, x: u32))] //~ ERROR postcondition might not hold
+fn unrelated_arguments<F: Fn(u32) -> u32, G: Fn(u32) -> u32>(f: F, g: G) {}
+
+fn main() {
+    increment_is_double();
+}
@@ -0,0 +1,31 @@
+use prusti_contracts::*;
+use std::cmp;
+
+#[ensures(result == cmp::max(a, b))]
+#[ensures(result >= a && result >= b)]
+fn max(a: i32, b: i32) -> i32 {
+    if a > b {
+        a
+    } else {
+        b
+    }
+}
+
+#[ensures(result == cmp::min(a, b))]
+fn min(a: u64, b: u64) -> u64 {
+    a.min(b)
+}
+
+#[ensures(result <= x)]
+fn clamp_to(x: usize, limit: usize) -> usize {
+    let r = cmp::min(x, limit);
+    assert!(r <= limit);
+    r
+}
+
+#[ensures(result == cmp::min(a, b))] //~ ERROR postcondition might not hold
+fn wrong_min(a: i32, b: i32) -> i32 {
+    a.max(b)
+}
+
+fn main() {}
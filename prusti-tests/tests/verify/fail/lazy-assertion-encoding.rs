@@ -0,0 +1,25 @@
+// compile-flags: -Popt_in_verification=true -Plazy_assertion_encoding=false
+
+// Without lazy encoding, the pure functions that are not verified are still
+// encoded, so the error in `unused` is reported although no verified function
+// calls it; compare with `verify/pass/lazy-assertion-encoding/lazy.rs`.
+
+use prusti_contracts::*;
+
+fn get_u32() -> u32 {
+    123
+}
+
+#[pure]
+fn unused() -> u32 {
+    get_u32() //~ ERROR use of impure function "get_u32" in pure code
+}
+
+#[verified]
+#[requires(x < 100)]
+#[ensures(result == x + 1)]
+fn client(x: u32) -> u32 {
+    x + 1
+}
+
+fn main() {}
@@ -0,0 +1,42 @@
+use prusti_contracts::*;
+
+struct Counter {
+    value: u32,
+}
+
+struct Drain<'a> {
+    counter: &'a mut Counter,
+}
+
+impl<'a> Iterator for Drain<'a> {
+    type Item = u32;
+
+    #[trusted]
+    fn next(&mut self) -> Option<u32> {
+        if self.counter.value == 0 {
+            None
+        } else {
+            self.counter.value -= 1;
+            Some(self.counter.value)
+        }
+    }
+}
+
+#[ensures(lazy_iterator(result, counter))]
+fn drain(counter: &mut Counter) -> Drain<'_> {
+    Drain { counter }
+}
+
+#[requires(counter.value > 0)]
+#[ensures(lazy_iterator(result, counter))] //~ ERROR postcondition might not hold
+fn eager_drain(counter: &mut Counter) -> Drain<'_> {
+    counter.value -= 1;
+    Drain { counter }
+}
+
+#[ensures(lazy_iterator(result, c))] //~ ERROR the result does not borrow from `c`
+fn wrong_source<'a, 'b>(c: &'a mut Counter, d: &'b mut Counter) -> Drain<'b> {
+    Drain { counter: d }
+}
+
+fn main() {}
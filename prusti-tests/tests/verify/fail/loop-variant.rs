@@ -0,0 +1,27 @@
+use prusti_contracts::*;
+
+fn terminating(n: usize) {
+    let mut i = 0;
+    while i < n {
+        body_invariant!(i < n);
+        body_variant!(Int::new_usize(n) - Int::new_usize(i));
+        i += 1;
+    }
+}
+
+fn terminating_countdown(mut n: i64) {
+    while n > 0 {
+        body_variant!(Int::new(n));
+        n -= 1;
+    }
+}
+
+fn non_terminating(n: usize) {
+    let mut i = 0;
+    while i < n {
+        body_invariant!(i < n);
+        body_variant!(Int::new_usize(n) - Int::new_usize(i)); //~ ERROR The loop variant might not have decreased
+    }
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use prusti_contracts::*;
+
+#[ensures(iterations() == 0)] //~ ERROR `iterations()` can only be used in `body_invariant!(..)`
+fn in_postcondition() {}
+
+fn in_assertion(n: usize) {
+    let mut i = 0;
+    while i < n {
+        body_invariant!(iterations() == i);
+        prusti_assert!(iterations() == i); //~ ERROR `iterations()` can only be used in `body_invariant!(..)`
+        i += 1;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,46 @@
+use prusti_contracts::*;
+
+fn counted(n: usize) {
+    let mut i = 0;
+    while i < n {
+        body_invariant!(i < n);
+        body_invariant!(iterations() == i);
+        i += 1;
+    }
+}
+
+fn bounded_by_half(n: usize) -> usize {
+    let mut i = 0;
+    while i + 1 < n {
+        body_invariant!(i + 1 < n);
+        body_invariant!(2 * iterations() <= i);
+        i += 2;
+    }
+    i
+}
+
+fn nested(n: usize) {
+    let mut i = 0;
+    while i < n {
+        body_invariant!(i < n);
+        body_invariant!(iterations() == i);
+        let mut j = 0;
+        while j < i {
+            body_invariant!(j < i);
+            body_invariant!(iterations() == j);
+            j += 1;
+        }
+        i += 1;
+    }
+}
+
+fn too_many(n: usize) {
+    let mut i = 0;
+    while i < n {
+        body_invariant!(i < n);
+        body_invariant!(iterations() < i); //~ ERROR loop invariant might not hold
+        i += 1;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use prusti_contracts::*;
+
+#[ensures(old[prev](x) == x)] //~ ERROR `old[prev](..)` can only be used in `body_invariant!(..)`
+fn in_postcondition(x: u32) {}
+
+fn in_assertion(n: usize) {
+    let mut i = 0;
+    while i < n {
+        body_invariant!(i == 0 || i == old[prev](i) + 1);
+        prusti_assert!(i == 0 || i == old[prev](i) + 1); //~ ERROR `old[prev](..)` can only be used in `body_invariant!(..)`
+        i += 1;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,55 @@
+use prusti_contracts::*;
+
+fn step(n: usize) {
+    let mut i = 0;
+    while i < n {
+        body_invariant!(i < n);
+        body_invariant!(i == 0 || i == old[prev](i) + 1);
+        i += 1;
+    }
+}
+
+fn growing(n: usize) {
+    let mut i = 0;
+    let mut j = 0;
+    while i < n {
+        body_invariant!(i < n && j <= i);
+        body_invariant!(i >= old[prev](i) && j >= old[prev](j));
+        if j < i {
+            j += 1;
+        }
+        i += 1;
+    }
+}
+
+fn with_entry(n: usize) {
+    let mut i = 0;
+    let mut last = 0;
+    while i < n {
+        body_invariant!(i < n);
+        body_invariant!(last == old[prev](i));
+        last = i;
+        i += 1;
+    }
+}
+
+fn unchanged(n: usize) {
+    let mut i = 0;
+    while i < n {
+        body_invariant!(i < n);
+        body_invariant!(i == old[prev](i)); //~ ERROR loop invariant might not hold after a loop iteration
+        i += 1;
+    }
+}
+
+fn arbitrary_previous_state(n: usize) {
+    let mut i = 0;
+    while i < n {
+        body_invariant!(i < n);
+        body_invariant!(i == 0 || i == old[prev](i) + 1);
+        assert!(i == 0); //~ ERROR the asserted expression might not hold
+        i += 1;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,34 @@
+use prusti_contracts::*;
+
+struct Counter {
+    count: u32,
+    steps: u32,
+}
+
+impl Counter {
+    #[modifies(self.total)]
+    fn unknown_field(&mut self) {} //~ ERROR `total` is not a field of `Counter`
+
+    #[modifies(self.count)]
+    fn shared_receiver(&self) {} //~ ERROR can only be used on methods taking `&mut self`
+}
+
+struct Cursor<'a> {
+    position: usize,
+    data: &'a [u8],
+}
+
+impl<'a> Cursor<'a> {
+    #[modifies(self.position)]
+    fn advance(&mut self) { //~ ERROR the unmodified field `data` has the reference type `&'a [u8]`, which `#[modifies]` cannot frame
+        self.position += 1;
+    }
+
+    #[modifies(self.position, self.data)]
+    fn reset(&mut self, data: &'a [u8]) {
+        self.position = 0;
+        self.data = data;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,40 @@
+use prusti_contracts::*;
+
+struct Counter {
+    count: u32,
+    steps: u32,
+    limit: u32,
+}
+
+impl Counter {
+    #[modifies(self.count)]
+    #[requires(self.count < self.limit)]
+    #[ensures(self.count == old(self.count) + 1)]
+    fn increment(&mut self) {
+        self.count += 1;
+    }
+
+    #[modifies(self.count, self.steps)]
+    #[requires(self.count < self.limit && self.steps < 100)]
+    fn step(&mut self) {
+        self.count += 1;
+        self.steps += 1;
+    }
+
+    #[modifies(self.count)]
+    fn reset_steps(&mut self) { //~ ERROR postcondition might not hold
+        self.steps = 0;
+    }
+}
+
+fn client(counter: &mut Counter) {
+    let steps = counter.steps;
+    let limit = counter.limit;
+    if counter.count < counter.limit {
+        counter.increment();
+    }
+    assert!(counter.steps == steps);
+    assert!(counter.limit == limit);
+}
+
+fn main() {}
@@ -0,0 +1,73 @@
+use prusti_contracts::*;
+use std::ops::{Add, Mul, Sub};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Matrix {
+    a: i32,
+    b: i32,
+    c: i32,
+    d: i32,
+}
+
+impl Mul for Matrix {
+    type Output = Matrix;
+
+    #[pure]
+    fn mul(self, other: Matrix) -> Matrix {
+        Matrix {
+            a: self.a * other.a + self.b * other.c,
+            b: self.a * other.b + self.b * other.d,
+            c: self.c * other.a + self.d * other.c,
+            d: self.c * other.b + self.d * other.d,
+        }
+    }
+}
+
+impl Add for Matrix {
+    type Output = Matrix;
+
+    #[pure]
+    fn add(self, other: Matrix) -> Matrix {
+        Matrix {
+            a: self.a + other.a,
+            b: self.b + other.b,
+            c: self.c + other.c,
+            d: self.d + other.d,
+        }
+    }
+}
+
+impl Sub for Matrix {
+    type Output = Matrix;
+
+    fn sub(self, other: Matrix) -> Matrix {
+        Matrix {
+            a: self.a - other.a,
+            b: self.b - other.b,
+            c: self.c - other.c,
+            d: self.d - other.d,
+        }
+    }
+}
+
+#[ensures(result == a * b)]
+fn product(a: Matrix, b: Matrix) -> Matrix {
+    a * b
+}
+
+#[ensures(result == a * b + a)]
+fn product_plus(a: Matrix, b: Matrix) -> Matrix {
+    a * b + a
+}
+
+#[ensures(result == a * b)] //~ ERROR postcondition might not hold
+fn wrong_product(a: Matrix, b: Matrix) -> Matrix {
+    b * a
+}
+
+#[ensures(result == a - b)] //~ ERROR use of impure function
+fn difference(a: Matrix, b: Matrix) -> Matrix {
+    a - b
+}
+
+fn main() {}
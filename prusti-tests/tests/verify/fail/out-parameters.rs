@@ -0,0 +1,33 @@
+use prusti_contracts::*;
+
+#[requires(divisor != 0)]
+#[ensures(*quotient == dividend / divisor)]
+#[ensures(*remainder == dividend % divisor)]
+#[ensures(result == (old(*quotient) != *quotient))]
+fn div_rem(dividend: u32, divisor: u32, quotient: &mut u32, remainder: &mut u32) -> bool {
+    let changed = *quotient != dividend / divisor;
+    *quotient = dividend / divisor;
+    *remainder = dividend % divisor;
+    changed
+}
+
+#[ensures(*out == old(*out) + 1)] //~ ERROR postcondition might not hold
+fn forgets_to_write(out: &mut u32) {}
+
+#[ensures(*out == old(*out))]
+fn leaves_unchanged(out: &mut u32) {}
+
+fn caller() {
+    let mut q = 7;
+    let mut r = 0;
+    let changed = div_rem(17, 5, &mut q, &mut r);
+    assert!(q == 3 && r == 2);
+    assert!(changed);
+    let changed = div_rem(17, 5, &mut q, &mut r);
+    assert!(!changed);
+    leaves_unchanged(&mut r);
+    assert!(r == 2);
+    assert!(q == 4); //~ ERROR the asserted expression might not hold
+}
+
+fn main() {}
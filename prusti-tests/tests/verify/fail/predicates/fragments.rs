@@ -0,0 +1,28 @@
+use prusti_contracts::*;
+
+pub struct Range {
+    lo: i32,
+    hi: i32,
+}
+
+predicate! {
+    fn in_range(r: &Range, x: i32) -> bool {
+        r.lo <= x
+    } {
+        x < r.hi
+    }
+}
+
+#[requires(in_range(r, x))]
+#[ensures(r.lo <= x && x < r.hi)]
+fn use_both_fragments(r: &Range, x: i32) {}
+
+#[requires(r.lo <= x)]
+#[ensures(in_range(r, x))] //~ ERROR postcondition might not hold
+fn only_first_fragment(r: &Range, x: i32) {}
+
+#[requires(r.lo <= x && x < r.hi)]
+#[ensures(in_range(r, x))]
+fn establish_both_fragments(r: &Range, x: i32) {}
+
+fn main() {}
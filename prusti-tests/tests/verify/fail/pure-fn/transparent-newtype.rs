@@ -0,0 +1,18 @@
+use prusti_contracts::*;
+
+#[prusti::transparent]
+struct Point(u32, u32); //~ ERROR: only structs with a single field can be marked as `#[prusti::transparent]` [P0008]
+
+#[prusti::transparent]
+struct Meters(u32);
+
+static ZERO: u32 = 0;
+
+impl std::ops::Deref for Meters {
+    type Target = u32;
+    fn deref(&self) -> &u32 { //~ ERROR: the `Deref` implementation of a `#[prusti::transparent]` type must only return a reference to its field [P0010]
+        &ZERO
+    }
+}
+
+fn main() {}
@@ -0,0 +1,28 @@
+use prusti_contracts::*;
+
+pub struct VecWrapper {
+    v: Vec<isize>,
+}
+
+impl VecWrapper {
+    #[trusted]
+    #[pure]
+    pub fn len(&self) -> isize {
+        self.v.len() as isize
+    }
+
+    #[trusted]
+    #[pure]
+    #[requires(0 <= index && index < self.len())]
+    pub fn lookup(&self, index: isize) -> isize {
+        self.v[index as usize]
+    }
+}
+
+#[ensures(exists(|i: isize| 0 <= i && i < v.len() && v.lookup(i) == target))] //~ ERROR postcondition might not hold
+fn no_witness(v: &VecWrapper, target: isize) {}
+
+#[ensures(exists(|x: u32| 5 < x && x < 6))] //~ ERROR postcondition might not hold
+fn empty_range() {}
+
+fn main() {}
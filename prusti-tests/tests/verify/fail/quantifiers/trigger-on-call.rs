@@ -0,0 +1,33 @@
+use prusti_contracts::*;
+
+#[pure]
+#[trigger_on_call]
+fn marked(_n: isize) -> bool {
+    true
+}
+
+#[pure]
+#[trusted]
+fn value(_n: isize) -> isize {
+    unimplemented!()
+}
+
+#[requires(forall(|n: isize| marked(n) ==> value(n) == 0))]
+pub fn test1() {
+    marked(4);
+    assert!(value(4) == 0);
+}
+
+#[requires(forall(|n: isize| marked(n) ==> value(n) == 0))]
+pub fn test2() {
+    // Only calls of `marked` instantiate the quantifier.
+    assert!(value(4) == 0); //~ ERROR the asserted expression might not hold
+}
+
+#[requires(forall(|n: isize| marked(n) ==> value(n) == 0, triggers=[(value(n),)]))]
+pub fn test3() {
+    // Explicit triggers take precedence.
+    assert!(value(4) == 0);
+}
+
+fn main() {}
@@ -0,0 +1,35 @@
+use prusti_contracts::*;
+
+#[requires((0..n).contains(&i))]
+#[ensures(result < n)]
+fn below(i: usize, n: usize) -> usize {
+    i
+}
+
+#[requires((0..=n).contains(&i))]
+#[ensures(result <= n)]
+fn at_most(i: usize, n: usize) -> usize {
+    i
+}
+
+#[requires((0..=n).contains(&i))]
+#[ensures(result < n)] //~ ERROR postcondition might not hold
+fn inclusive_not_below(i: usize, n: usize) -> usize {
+    i
+}
+
+#[requires(forall(|i: usize| (0..a.len()).contains(&i) ==> a[i] > 0))]
+#[requires(k < a.len())]
+#[ensures(result > 0)]
+fn positive_element(a: &[u32], k: usize) -> u32 {
+    a[k]
+}
+
+#[requires(forall(|i: usize| (1..a.len()).contains(&i) ==> a[i] > 0))]
+#[requires(k < a.len())]
+#[ensures(result > 0)] //~ ERROR postcondition might not hold
+fn first_not_covered(a: &[u32], k: usize) -> u32 {
+    a[k]
+}
+
+fn main() {}
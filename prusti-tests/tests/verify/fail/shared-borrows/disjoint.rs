@@ -0,0 +1,20 @@
+use prusti_contracts::*;
+
+#[requires(prusti_disjoint!(a, b))]
+fn needs_disjoint(a: &i32, b: &i32) {}
+
+fn mutable_references(x: &mut i32, y: &mut i32) {
+    needs_disjoint(x, y);
+}
+
+fn different_locals() {
+    let x = 1;
+    let y = 1;
+    needs_disjoint(&x, &y);
+}
+
+fn same_reference(x: &i32) {
+    needs_disjoint(x, x); //~ ERROR precondition might not hold
+}
+
+fn main() {}
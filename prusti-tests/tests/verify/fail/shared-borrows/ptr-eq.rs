@@ -0,0 +1,26 @@
+use prusti_contracts::*;
+
+#[ensures(std::ptr::eq(result, x))]
+fn id(x: &i32) -> &i32 {
+    x
+}
+
+#[requires(std::ptr::eq(a, b))]
+fn needs_same(a: &i32, b: &i32) {}
+
+fn same_reference(x: &i32) {
+    needs_same(x, id(x));
+}
+
+fn equal_values() {
+    let x = 1;
+    let y = 1;
+    needs_same(&x, &y); //~ ERROR precondition might not hold
+}
+
+#[ensures(std::ptr::eq(result, x))] //~ ERROR postcondition might not hold
+fn copy_of<'a>(x: &'a i32, y: &'a i32) -> &'a i32 {
+    y
+}
+
+fn main() {}
@@ -0,0 +1,43 @@
+use prusti_contracts::*;
+
+struct Pair {
+    left: i32,
+    right: i32,
+}
+
+impl Pair {
+    #[ensures(result_borrows_from(result, self))]
+    fn right(&mut self) -> &mut i32 {
+        &mut self.right
+    }
+}
+
+#[ensures(result_borrows_from(result, p))]
+fn left<'a, 'b>(p: &'a mut Pair, q: &'b mut Pair) -> &'a mut i32 {
+    &mut p.left
+}
+
+// Claiming that the result borrows from `p` would let callers assume that
+// writes through the result cannot affect `q`.
+#[ensures(result_borrows_from(result, p))] //~ ERROR the result does not borrow from `p`
+fn wrong<'a, 'b>(p: &'a mut Pair, q: &'b mut Pair) -> &'b mut i32 {
+    &mut q.right
+}
+
+// The shared lifetime `'a` lets the result borrow from `q` as well.
+#[ensures(result_borrows_from(result, p))] //~ ERROR the result may borrow from other arguments than `p`
+fn shared_lifetime<'a>(p: &'a mut Pair, q: &'a mut Pair) -> &'a mut i32 {
+    &mut q.left
+}
+
+#[ensures(result_borrows_from(result, p))] //~ ERROR the result does not borrow from `p`
+fn not_a_reference(p: &Pair, q: &Pair) -> i32 {
+    p.left + q.left
+}
+
+#[ensures(result_borrows_from(p, q))] //~ ERROR `result_borrows_from` expects `result` and an argument of the function
+fn not_result<'a>(p: &'a Pair, q: &'a Pair) -> &'a Pair {
+    p
+}
+
+fn main() {}
@@ -0,0 +1,36 @@
+use prusti_contracts::*;
+
+// Specifications of items nested in `const` and `static` initializers are
+// collected like the ones of any other item.
+
+const LIMIT: u32 = {
+    #[requires(x < 10)]
+    #[ensures(result == x + 1)]
+    const fn inc(x: u32) -> u32 {
+        x + 1
+    }
+
+    fn check(x: u32) {
+        prusti_assert!(x < 10); //~ ERROR: asserted expression might not hold
+    }
+
+    inc(3)
+};
+
+static START: u32 = {
+    fn call_inc() {
+        #[requires(x < 10)]
+        #[ensures(result == x + 1)]
+        fn inc(x: u32) -> u32 {
+            x + 1
+        }
+
+        let y = inc(4);
+        prusti_assert!(y == 5);
+        inc(10); //~ ERROR precondition might not hold
+    }
+
+    0
+};
+
+fn main() {}
@@ -0,0 +1,23 @@
+use prusti_contracts::*;
+
+enum State {
+    Idle,
+    Ready(u32),
+}
+
+#[requires(matches!(state, State::Ready(_)))]
+fn ready_value(state: State) -> u32 {
+    match state {
+        State::Ready(value) => value,
+        State::Idle => unreachable!(),
+    }
+}
+
+#[ensures(matches!(result, State::Ready(_)))] //~ ERROR postcondition might not hold
+fn stop(state: State) -> State {
+    State::Idle
+}
+
+fn main() {
+    ready_value(State::Idle); //~ ERROR precondition might not hold
+}
@@ -0,0 +1,15 @@
+use prusti_contracts::*;
+
+fn duplicate_snapshot(b: bool) {
+    let mut a = 1;
+    if b {
+        snapshot!(mid);
+        a += 1;
+        prusti_assert!(a == old[mid](a) + 1);
+    } else {
+        snapshot!(mid); //~ ERROR: the snapshot label `mid` is used more than once
+        a += 2;
+    }
+}
+
+fn main() {}
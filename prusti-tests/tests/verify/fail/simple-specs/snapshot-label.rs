@@ -0,0 +1,11 @@
+use prusti_contracts::*;
+
+fn wrong_snapshot(x: &mut u32) {
+    prusti_assume!(*x < 100);
+    *x += 1;
+    snapshot!(mid);
+    *x += 1;
+    prusti_assert!(*x == old[mid](*x)); //~ ERROR: asserted expression might not hold
+}
+
+fn main() {}
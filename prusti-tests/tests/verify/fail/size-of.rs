@@ -0,0 +1,38 @@
+use prusti_contracts::*;
+use std::mem::{align_of, size_of};
+
+#[ensures(result == size_of::<u32>())]
+fn size_of_u32() -> usize {
+    4
+}
+
+#[ensures(result == align_of::<u64>() * 2)]
+fn twice_align_of_u64() -> usize {
+    16
+}
+
+#[requires(n <= usize::MAX / size_of::<u64>())]
+#[ensures(result == n * size_of::<u64>())]
+fn bytes_of_u64s(n: usize) -> usize {
+    n * 8
+}
+
+struct Pair {
+    a: u16,
+    b: u16,
+}
+
+#[ensures(result == size_of::<Pair>())]
+fn size_of_pair() -> usize {
+    4
+}
+
+#[ensures(result == size_of::<u32>())] //~ ERROR postcondition might not hold
+fn wrong_size_of_u32() -> usize {
+    8
+}
+
+fn main() {
+    prusti_assert!(size_of::<u32>() == 4);
+    prusti_assert!(size_of::<()>() == 0);
+}
@@ -1,7 +1,7 @@
 pub async fn connect<D>(dst: D)
 where
     D: std::convert::TryInto<u32>
-{ //~ ERROR unsupported type
+{ //~ ERROR verifying the body of an `async fn` is not supported
 }
 
 fn main(){}
@@ -0,0 +1,27 @@
+#![feature(generic_const_exprs)]
+#![allow(incomplete_features)]
+
+use prusti_contracts::*;
+
+const N: usize = 3;
+
+#[requires(a[N] > 0)]
+#[ensures(result == a[N])]
+fn last(a: [i32; N + 1]) -> i32 {
+    a[N]
+}
+
+#[trusted]
+#[requires(a[M] > 0)]
+#[ensures(result == a[M])]
+fn generic_last<const M: usize>(a: [i32; M + 1]) -> i32 {
+    a[M]
+}
+
+fn main() {
+    let a = [1, 2, 3, 4];
+    let x = last(a);
+    assert!(x == 4);
+    let y = generic_last::<2>([1, 2, 3]);
+    assert!(y == 3);
+}
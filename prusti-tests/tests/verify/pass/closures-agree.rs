@@ -0,0 +1,61 @@
+use prusti_contracts::*;
+
+#[pure]
+fn increment(x: u32) -> u32 {
+    x + 1
+}
+
+#[pure]
+fn plus_one(x: u32) -> u32 {
+    1 + x
+}
+
+#[pure]
+fn max(a: u32, b: u32) -> u32 {
+    if a < b {
+        b
+    } else {
+        a
+    }
+}
+
+#[pure]
+fn max_flipped(a: u32, b: u32) -> u32 {
+    if b <= a {
+        a
+    } else {
+        b
+    }
+}
+
+#[ensures(closures_agree!(increment, plus_one, x: u32))]
+fn increments_agree() {}
+
+#[ensures(closures_agree!(max, max_flipped, a: u32, b: u32))]
+fn maxima_agree() {}
+
+#[requires(closures_agree!(increment, plus_one, x: u32))]
+#[ensures(result == plus_one(x))]
+fn use_agreement(x: u32) -> u32 {
+    increment(x)
+}
+
+#[requires(closures_agree!(f, g, x: u32))]
+#[ensures(closures_agree!(g, f, x: u32))]
+fn swap_agreement<F: Fn(u32) -> u32, G: Fn(u32) -> u32>(f: F, g: G) {}
+
+#[requires(closures_agree!(f, g, x: u32) && closures_agree!(g, h, x: u32))]
+#[ensures(closures_agree!(f, h, x: u32))]
+fn chain_agreement<F, G, H>(f: F, g: G, h: H)
+where
+    F: Fn(u32) -> u32,
+    G: Fn(u32) -> u32,
+    H: Fn(u32) -> u32,
+{
+}
+
+fn main() {
+    increments_agree();
+    maxima_agree();
+    use_agreement(3);
+}
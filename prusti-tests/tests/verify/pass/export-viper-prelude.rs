@@ -0,0 +1,47 @@
+// compile-flags: -Pexport_viper_prelude=true
+
+// The exported prelude is verified as a separate program, which fails with a
+// consistency error if it is not a valid Viper program.
+
+use prusti_contracts::*;
+
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl Point {
+    #[pure]
+    pub fn x(&self) -> i32 {
+        self.x
+    }
+
+    #[pure]
+    pub fn is_origin(&self) -> bool {
+        self.x == 0 && self.y == 0
+    }
+}
+
+#[pure]
+#[requires(n >= 0)]
+fn triangle(n: i64) -> i64 {
+    if n == 0 { 0 } else { n + triangle(n - 1) }
+}
+
+#[ensures(result.x() == x)]
+#[ensures(result.is_origin() == (x == 0 && y == 0))]
+fn new(x: i32, y: i32) -> Point {
+    Point { x, y }
+}
+
+#[requires(n >= 0)]
+#[ensures(result == triangle(n))]
+fn compute_triangle(n: i64) -> i64 {
+    triangle(n)
+}
+
+fn main() {
+    let p = new(0, 0);
+    assert!(p.is_origin());
+    let _ = compute_triangle(2);
+}
@@ -0,0 +1,25 @@
+// The specification of `Clone::clone` is provided by `prusti_std`.
+extern crate prusti_std;
+
+use prusti_contracts::*;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[requires(p.x == 3)]
+fn clone_point(p: &Point) {
+    let q = p.clone();
+    prusti_assert!(q === *p);
+    assert!(q.x == 3);
+    assert!(q.y == p.y);
+}
+
+fn clone_int(a: u32) {
+    let b = a.clone();
+    assert!(a == b);
+}
+
+fn main() {}
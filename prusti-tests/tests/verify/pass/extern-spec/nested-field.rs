@@ -0,0 +1,59 @@
+use prusti_contracts::*;
+
+// A postcondition of an external specification may project through several
+// levels of fields of the returned value, as long as the fields are visible
+// at the location of the `#[extern_spec]` block.
+
+#[extern_spec(external)]
+#[ensures(result.inner.len == len)]
+#[ensures(result.inner.cap >= result.inner.len)]
+fn with_len(len: usize) -> Outer;
+
+#[extern_spec]
+impl external::Outer {
+    #[ensures(result == self.inner.len)]
+    fn len(&self) -> usize;
+
+    #[ensures(self.inner.len == old(self.inner.len) + 1)]
+    #[ensures(self.inner.cap == old(self.inner.cap))]
+    fn push(&mut self);
+}
+
+fn client() {
+    let mut outer = external::with_len(3);
+    assert!(outer.inner.len == 3);
+    assert!(outer.len() == 3);
+    if outer.inner.len < outer.inner.cap {
+        outer.push();
+        assert!(outer.inner.len == 4);
+    }
+}
+
+mod external {
+    pub struct Inner {
+        pub len: usize,
+        pub cap: usize,
+    }
+
+    pub struct Outer {
+        pub inner: Inner,
+    }
+
+    pub fn with_len(len: usize) -> Outer {
+        Outer {
+            inner: Inner { len, cap: len },
+        }
+    }
+
+    impl Outer {
+        pub fn len(&self) -> usize {
+            self.inner.len
+        }
+
+        pub fn push(&mut self) {
+            self.inner.len += 1;
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,41 @@
+use prusti_contracts::*;
+
+struct Account {
+    balance: u32,
+}
+
+impl Account {
+    #[ensures(result.is_err() ==> self.balance == old(self.balance))]
+    #[ensures(result.is_ok() ==> self.balance == old(self.balance) - amount)]
+    #[ensures(result.is_ok() ==> result.ok() === Some(self.balance))]
+    #[ensures(result.is_err() ==> result.err() === Some(amount - self.balance))]
+    fn withdraw(&mut self, amount: u32) -> Result<u32, u32> {
+        if amount > self.balance {
+            Err(amount - self.balance)
+        } else {
+            self.balance -= amount;
+            Ok(self.balance)
+        }
+    }
+}
+
+#[requires(account.balance < 100)]
+fn overdraw(account: &mut Account) {
+    let before = account.balance;
+    let result = account.withdraw(before + 1);
+    assert!(result.is_err());
+    assert!(account.balance == before);
+    prusti_assert!(result.err() === Some(1));
+    let _ = result.unwrap_err();
+}
+
+#[requires(account.balance >= 10)]
+fn withdraw_ten(account: &mut Account) {
+    let before = account.balance;
+    let result = account.withdraw(10);
+    assert!(result.is_ok());
+    prusti_assert!(result.ok() === Some(before - 10));
+    let _ = result.unwrap();
+}
+
+fn main() {}
@@ -0,0 +1,42 @@
+use prusti_contracts::*;
+
+trait Source {
+    type Item: Copy + PartialEq;
+
+    #[pure]
+    fn value(&self) -> Self::Item;
+}
+
+struct Constant(u32);
+
+impl Source for Constant {
+    type Item = u32;
+
+    #[pure]
+    fn value(&self) -> u32 {
+        self.0
+    }
+}
+
+// The postcondition mentions the associated type `S::Item`, which needs to be
+// normalized to `u32` at the call site in `client`.
+#[ensures(result === source.value())]
+fn read<S: Source>(source: &S) -> S::Item {
+    source.value()
+}
+
+#[ensures(result === (source.value(), source.value()))]
+fn read_twice<S: Source>(source: &S) -> (S::Item, S::Item) {
+    (read(source), read(source))
+}
+
+fn client() {
+    let constant = Constant(5);
+    let value = read(&constant);
+    assert!(value == 5);
+    let (first, second) = read_twice(&constant);
+    assert!(first == second);
+}
+
+#[trusted]
+fn main() {}
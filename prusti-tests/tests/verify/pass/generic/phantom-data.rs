@@ -0,0 +1,61 @@
+use prusti_contracts::*;
+use std::marker::PhantomData;
+
+struct Meters;
+
+struct Length<Unit> {
+    value: u32,
+    unit: PhantomData<Unit>,
+}
+
+impl<Unit> Length<Unit> {
+    #[ensures(result.value == value)]
+    fn new(value: u32) -> Self {
+        Length {
+            value,
+            unit: PhantomData,
+        }
+    }
+
+    #[pure]
+    fn value(&self) -> u32 {
+        self.value
+    }
+
+    #[requires(self.value() < 1000 && other.value() < 1000)]
+    #[ensures(result.value() == self.value() + other.value())]
+    fn add(&self, other: &Self) -> Self {
+        Length {
+            value: self.value + other.value,
+            unit: PhantomData,
+        }
+    }
+}
+
+#[pure]
+fn meters(value: u32) -> Length<Meters> {
+    Length {
+        value,
+        unit: PhantomData,
+    }
+}
+
+#[requires(length.value < 1000)]
+#[ensures(result.value == length.value + 1)]
+fn grow(length: Length<Meters>) -> Length<Meters> {
+    let Length { value, .. } = length;
+    meters(value + 1)
+}
+
+fn main() {
+    let a: Length<Meters> = Length::new(3);
+    let b = Length::new(4);
+    let c = a.add(&b);
+    assert!(c.value() == 7);
+    let mut d = c;
+    d.value += 1;
+    assert!(d.value() == 8);
+    let e = grow(d);
+    assert!(e.value() == 9);
+    assert!(meters(2).value == 2);
+}
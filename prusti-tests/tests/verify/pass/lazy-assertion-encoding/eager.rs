@@ -0,0 +1,39 @@
+// compile-flags: -Popt_in_verification=true -Plazy_assertion_encoding=false
+
+// Benchmark: the same crate as `lazy.rs`, but all contracts are encoded
+// eagerly, although only `client` is verified. The difference in time between
+// the two files measures the encoding work saved by lazy encoding. The
+// unsupported `unused` function of `lazy.rs` is left out, because eager
+// encoding reports it (see `verify/fail/lazy-assertion-encoding.rs`).
+
+use prusti_contracts::*;
+
+macro_rules! specified_functions {
+    ($($name:ident: $bound:literal),*) => {
+        $(
+            #[pure]
+            #[requires(x < $bound)]
+            #[ensures(result == x + 1)]
+            #[ensures(result <= $bound)]
+            fn $name(x: u32) -> u32 {
+                x + 1
+            }
+        )*
+    };
+}
+
+specified_functions!(
+    f00: 100, f01: 101, f02: 102, f03: 103, f04: 104, f05: 105, f06: 106, f07: 107,
+    f08: 108, f09: 109, f10: 110, f11: 111, f12: 112, f13: 113, f14: 114, f15: 115,
+    f16: 116, f17: 117, f18: 118, f19: 119, f20: 120, f21: 121, f22: 122, f23: 123,
+    f24: 124, f25: 125, f26: 126, f27: 127, f28: 128, f29: 129, f30: 130, f31: 131
+);
+
+#[verified]
+#[requires(x < 100)]
+#[ensures(result == x + 1)]
+fn client(x: u32) -> u32 {
+    f00(x)
+}
+
+fn main() {}
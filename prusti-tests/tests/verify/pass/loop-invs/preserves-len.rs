@@ -0,0 +1,45 @@
+use prusti_contracts::*;
+
+pub struct VecWrapperI32 {
+    v: Vec<i32>,
+}
+
+impl VecWrapperI32 {
+    #[trusted]
+    #[pure]
+    pub fn len(&self) -> usize {
+        self.v.len()
+    }
+
+    #[trusted]
+    #[requires(index < self.len())]
+    #[ensures(self.len() == old(self.len()))]
+    pub fn store(&mut self, index: usize, value: i32) {
+        self.v[index] = value;
+    }
+
+    #[preserves_len]
+    pub fn fill(&mut self, value: i32) {
+        let mut i = 0;
+        while i < self.len() {
+            body_invariant!(i < self.len());
+            self.store(i, value);
+            i += 1;
+        }
+    }
+
+    #[preserves_len]
+    #[requires(self.len() > 0)]
+    pub fn reset_first(&mut self) {
+        self.store(0, 0);
+    }
+}
+
+#[requires(v.len() > 0)]
+fn client(v: &mut VecWrapperI32) {
+    v.fill(1);
+    v.reset_first();
+    assert!(v.len() > 0);
+}
+
+fn main() {}
@@ -0,0 +1,28 @@
+// The specifications of `Vec` are provided by `prusti_std`.
+extern crate prusti_std;
+
+use prusti_contracts::*;
+use prusti_std::vec_get;
+
+#[trusted]
+#[requires(v.len() > 0)]
+#[ensures(*result == old(x))]
+#[assert_on_expiry(
+    *result >= old(x),
+    v.len() == old(v.len()) && vec_get(v, 0) >= old(x)
+)]
+fn swap_first<'a>(v: &'a mut Vec<i32>, x: i32) -> &'a mut i32 {
+    v[0] = x;
+    &mut v[0]
+}
+
+#[requires(v.len() > 0)]
+#[ensures(v.len() == old(v.len()))]
+#[ensures(vec_get(v, 0) >= 5)]
+fn client(v: &mut Vec<i32>) {
+    let r = swap_first(v, 5);
+    assert!(*r == 5);
+    *r = 7;
+}
+
+fn main() {}
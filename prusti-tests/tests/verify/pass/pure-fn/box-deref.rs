@@ -0,0 +1,44 @@
+use prusti_contracts::*;
+
+struct Tree {
+    val: i32,
+    left: Option<Box<Tree>>,
+}
+
+#[pure]
+fn left_val(tree: &Tree) -> i32 {
+    match tree.left {
+        Some(ref left) => (*left).val,
+        None => tree.val,
+    }
+}
+
+#[pure]
+fn left_val_deref(tree: &Tree) -> i32 {
+    match &tree.left {
+        Some(left) => left.as_ref().val,
+        None => tree.val,
+    }
+}
+
+#[pure]
+fn boxed_val(boxed: Box<Tree>) -> i32 {
+    (*boxed).val
+}
+
+#[requires(tree.val == 1)]
+#[ensures(left_val(&tree) == 1 && left_val_deref(&tree) == 1)]
+fn no_left(tree: Tree) -> Tree {
+    Tree { val: tree.val, left: None }
+}
+
+#[ensures(left_val(&result) == 2 && left_val_deref(&result) == 2)]
+fn with_left() -> Tree {
+    let left = Tree { val: 2, left: None };
+    Tree { val: 1, left: Some(Box::new(left)) }
+}
+
+#[ensures(boxed_val(Box::new(Tree { val: 3, left: None })) == 3)]
+fn boxed_in_spec() {}
+
+fn main() {}
@@ -0,0 +1,64 @@
+use prusti_contracts::*;
+
+#[derive(Clone, Copy)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+struct Points {
+    items: [Point; 8],
+}
+
+impl Points {
+    #[pure]
+    fn len(&self) -> usize {
+        8
+    }
+
+    #[pure]
+    #[requires(i < self.len())]
+    fn get(&self, i: usize) -> &Point {
+        &self.items[i]
+    }
+}
+
+#[requires(forall(|i: usize| i < p.len() ==> p.get(i).x >= 0))]
+#[ensures(forall(|i: usize| i < p.len() ==> p.get(i).x + 1 > 0))]
+fn shifted_positive(p: &Points) {}
+
+#[requires(forall(|i: usize| i < p.len() ==> p.get(i).y == 3))]
+fn use_point(p: &Points) {
+    let point = p.get(5);
+    assert!(point.y == 3);
+}
+
+#[pure]
+#[requires(i < s.len())]
+fn elem(s: &[u32], i: usize) -> &u32 {
+    &s[i]
+}
+
+#[requires(forall(|i: usize| i < s.len() ==> *elem(s, i) < 100))]
+#[ensures(forall(|i: usize| i < s.len() ==> *elem(s, i) <= 100))]
+fn bounded(s: &[u32]) {}
+
+#[requires(s.len() > 2)]
+#[requires(forall(|i: usize| i < s.len() ==> *elem(s, i) == 7))]
+fn use_elem(s: &[u32]) {
+    assert!(*elem(s, 1) == 7);
+}
+
+#[pure]
+#[requires(i < s.len())]
+fn float_elem(s: &[f64], i: usize) -> &f64 {
+    &s[i]
+}
+
+#[requires(s.len() > 0)]
+#[ensures(result === *float_elem(s, 0))]
+fn first_float(s: &[f64]) -> f64 {
+    s[0]
+}
+
+fn main() {}
@@ -0,0 +1,40 @@
+#![feature(let_chains)]
+
+use prusti_contracts::*;
+
+#[pure]
+fn both_positive(a: Option<i32>, b: Option<i32>) -> bool {
+    if let Some(x) = a && x > 0 && let Some(y) = b && y > 0 {
+        true
+    } else {
+        false
+    }
+}
+
+#[requires(both_positive(a, b))]
+#[ensures(result > 1)]
+fn sum(a: Option<i32>, b: Option<i32>) -> i32 {
+    match (a, b) {
+        (Some(x), Some(y)) => {
+            prusti_assume!(x < 1000 && y < 1000);
+            x + y
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[ensures(if let Some(x) = a && let Some(y) = b { result >= x && result >= y } else { result == 0 })]
+fn max_of(a: Option<u32>, b: Option<u32>) -> u32 {
+    if let Some(x) = a && let Some(y) = b {
+        if x > y { x } else { y }
+    } else {
+        0
+    }
+}
+
+fn main() {
+    assert!(both_positive(Some(1), Some(2)));
+    assert!(!both_positive(Some(1), None));
+    assert!(!both_positive(Some(-1), Some(2)));
+    assert!(sum(Some(3), Some(4)) > 1);
+}
@@ -0,0 +1,35 @@
+use prusti_contracts::*;
+
+#[pure]
+#[requires(d != 0)]
+fn div(n: u32, d: u32) -> u32 {
+    n / d
+}
+
+// The call to `div` is only evaluated when `d != 0`.
+#[ensures(d == 0 || div(n, d) == result)]
+#[ensures(d != 0 && div(n, d) <= n || d == 0)]
+fn safe_div(n: u32, d: u32) -> u32 {
+    if d == 0 {
+        0
+    } else {
+        n / d
+    }
+}
+
+#[pure]
+#[ensures(result == (d == 0 || div(n, d) == 1))]
+fn is_zero_or_same(n: u32, d: u32) -> bool {
+    d == 0 || div(n, d) == 1
+}
+
+fn client() {
+    let r = safe_div(10, 0);
+    assert!(r == 0);
+    let s = safe_div(10, 10);
+    assert!(s == 1);
+    assert!(is_zero_or_same(7, 0));
+    assert!(is_zero_or_same(7, 7));
+}
+
+fn main() {}
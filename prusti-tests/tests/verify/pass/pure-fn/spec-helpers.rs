@@ -0,0 +1,48 @@
+use prusti_contracts::*;
+
+#[derive(Clone, Copy)]
+struct Range {
+    lo: u32,
+    hi: u32,
+}
+
+#[pure]
+fn is_sorted(v: &[i32]) -> bool {
+    v.len() < 2 || v[0] <= v[1]
+}
+
+#[pure]
+fn is_ordered(r: &Range) -> bool {
+    r.lo <= r.hi
+}
+
+#[pure]
+#[requires(is_ordered(r))]
+fn width(r: &Range) -> u32 {
+    r.hi - r.lo
+}
+
+#[requires(v.len() == 2)]
+#[requires(is_sorted(v))]
+#[ensures(result == v[0])]
+fn min_of_two(v: &[i32]) -> i32 {
+    v[0]
+}
+
+#[requires(is_ordered(&r))]
+#[requires(r.hi <= 1000 && d <= 1000)]
+#[ensures(is_ordered(&result))]
+#[ensures(width(&result) == width(&r))]
+fn shift(r: Range, d: u32) -> Range {
+    Range {
+        lo: r.lo + d,
+        hi: r.hi + d,
+    }
+}
+
+fn main() {
+    let v = [1, 2];
+    assert!(min_of_two(&v) == 1);
+    let r = shift(Range { lo: 1, hi: 5 }, 10);
+    prusti_assert!(width(&r) == 4);
+}
@@ -0,0 +1,44 @@
+use prusti_contracts::*;
+use std::ops::Deref;
+
+#[prusti::transparent]
+#[derive(Clone, Copy)]
+struct Meters(u32);
+
+impl Deref for Meters {
+    type Target = u32;
+    fn deref(&self) -> &u32 {
+        &self.0
+    }
+}
+
+#[requires(*m < 100)]
+#[ensures(*result == *m + 1)]
+fn step(m: Meters) -> Meters {
+    Meters(m.0 + 1)
+}
+
+#[pure]
+#[requires(*a < 100 && *b < 100)]
+fn total(a: Meters, b: Meters) -> u32 {
+    *a + *b
+}
+
+#[transparent]
+struct Seconds {
+    value: u64,
+}
+
+#[requires(*s.field() < 60)]
+#[ensures(*result.field() == *s.field() * 1000)]
+fn to_millis(s: Seconds) -> Seconds {
+    Seconds { value: s.value * 1000 }
+}
+
+fn main() {
+    let s = to_millis(Seconds { value: 2 });
+    assert!(s.value == 2000);
+    let m = step(Meters(41));
+    assert!(m.0 == 42);
+    assert!(total(m, Meters(8)) == 50);
+}
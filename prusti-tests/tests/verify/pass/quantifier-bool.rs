@@ -0,0 +1,40 @@
+use prusti_contracts::*;
+
+#[pure]
+fn choose(b: bool) -> i32 {
+    if b {
+        1
+    } else {
+        2
+    }
+}
+
+#[ensures(forall(|b: bool| choose(b) >= 1 && choose(b) <= 2))]
+#[ensures(exists(|b: bool| choose(b) == 2))]
+fn choose_is_bounded() {}
+
+#[pure]
+fn pick(b: bool, x: i32, y: i32) -> i32 {
+    if b {
+        x
+    } else {
+        y
+    }
+}
+
+#[requires(forall(|b: bool| pick(b, x, y) > 0))]
+#[ensures(result > 1)]
+fn add_positive(x: i32, y: i32) -> i32 {
+    prusti_assert!(pick(true, x, y) > 0);
+    prusti_assert!(pick(false, x, y) > 0);
+    if x < 1000 && y < 1000 {
+        x + y
+    } else {
+        2
+    }
+}
+
+fn main() {
+    choose_is_bounded();
+    add_positive(1, 2);
+}
@@ -0,0 +1,40 @@
+use prusti_contracts::*;
+
+pub struct VecWrapper {
+    v: Vec<isize>,
+}
+
+impl VecWrapper {
+    #[trusted]
+    #[pure]
+    pub fn len(&self) -> isize {
+        self.v.len() as isize
+    }
+
+    #[trusted]
+    #[pure]
+    #[requires(0 <= index && index < self.len())]
+    pub fn lookup(&self, index: isize) -> isize {
+        self.v[index as usize]
+    }
+}
+
+#[requires(0 <= index && index < v.len())]
+#[requires(v.lookup(index) == target)]
+#[ensures(exists(|i: isize| 0 <= i && i < v.len() && v.lookup(i) == target))]
+fn witness_given(v: &VecWrapper, index: isize, target: isize) {}
+
+#[ensures(result ==> exists(|i: isize| 0 <= i && i < v.len() && v.lookup(i) == target))]
+fn contains(v: &VecWrapper, target: isize) -> bool {
+    let mut i = 0;
+    while i < v.len() {
+        body_invariant!(0 <= i && i < v.len());
+        if v.lookup(i) == target {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+fn main() {}
@@ -0,0 +1,40 @@
+use prusti_contracts::*;
+
+pub struct Store {
+    v: Vec<u32>,
+}
+
+impl Store {
+    #[trusted]
+    #[pure]
+    pub fn len(&self) -> usize {
+        self.v.len()
+    }
+
+    #[trusted]
+    #[pure]
+    #[requires(i < self.len())]
+    pub fn lookup(&self, i: usize) -> u32 {
+        self.v[i]
+    }
+
+    #[trusted]
+    #[requires(i < self.len())]
+    #[ensures(self.len() == old(self.len()))]
+    #[ensures(self.lookup(i) == value)]
+    #[ensures(forall(|j: usize| j < self.len() && j != i ==> self.lookup(j) == old(self.lookup(j)),
+        triggers=[(old(self.lookup(j)),)]))]
+    pub fn store(&mut self, i: usize, value: u32) {
+        self.v[i] = value;
+    }
+}
+
+#[requires(s.len() > 2)]
+#[requires(s.lookup(0) == 7)]
+#[ensures(s.lookup(0) == 7)]
+#[ensures(s.lookup(1) == 3)]
+fn client(s: &mut Store) {
+    s.store(1, 3);
+}
+
+fn main() {}
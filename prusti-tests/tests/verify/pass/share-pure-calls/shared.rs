@@ -0,0 +1,55 @@
+// compile-flags: -Poptimizations=all,share_pure_calls
+
+// Benchmark: the loop invariants call `double(n)` and `bound(n)` several times
+// each. With `share_pure_calls`, every assertion that is generated from an
+// invariant evaluates each of the calls once; `unshared.rs` is the same
+// program verified without the optimization.
+
+use prusti_contracts::*;
+
+#[pure]
+#[requires(n <= 1000)]
+#[ensures(result == 2 * n)]
+fn double(n: u32) -> u32 {
+    2 * n
+}
+
+#[pure]
+#[requires(n <= 1000)]
+#[ensures(result == double(n) + 1)]
+fn bound(n: u32) -> u32 {
+    double(n) + 1
+}
+
+#[requires(n <= 1000)]
+#[ensures(result == double(n))]
+fn sum_twos(n: u32) -> u32 {
+    let mut i = 0;
+    let mut sum = 0;
+    while i < n {
+        body_invariant!(n <= 1000);
+        body_invariant!(i < n && sum == 2 * i);
+        body_invariant!(double(n) >= sum && double(n) <= 2000 && double(n) == 2 * n);
+        body_invariant!(bound(n) > sum && bound(n) > double(n) && bound(n) <= 2001);
+        sum += 2;
+        i += 1;
+    }
+    sum
+}
+
+#[requires(n <= 1000)]
+#[ensures(result == double(n))]
+fn sum_twos_down(n: u32) -> u32 {
+    let mut i = n;
+    let mut sum = 0;
+    while i > 0 {
+        body_invariant!(n <= 1000 && i <= n);
+        body_invariant!(sum + 2 * i == double(n) && double(n) <= 2000);
+        body_invariant!(bound(n) == double(n) + 1 && sum < bound(n));
+        sum += 2;
+        i -= 1;
+    }
+    sum
+}
+
+fn main() {}
@@ -0,0 +1,25 @@
+//! Example: test `if`/`else` expressions in specifications
+
+use prusti_contracts::*;
+
+#[ensures(if result { a > 0 } else { a <= 0 })]
+fn is_positive(a: i32) -> bool {
+    a > 0
+}
+
+#[requires(if a < b { b - a < 100 } else if a > b { a - b < 100 } else { true })]
+#[ensures(result < 100)]
+#[ensures(if a < b { result == b - a } else { result == a - b })]
+fn distance(a: i32, b: i32) -> i32 {
+    if a < b {
+        b - a
+    } else {
+        a - b
+    }
+}
+
+fn main() {
+    assert!(is_positive(3));
+    assert!(!is_positive(-3));
+    assert!(distance(2, 5) == 3);
+}
@@ -0,0 +1,28 @@
+use prusti_contracts::*;
+
+#[ensures(if let Some(x) = result { x > 0 && x == n } else { n <= 0 })]
+fn positive(n: i32) -> Option<i32> {
+    if n > 0 {
+        Some(n)
+    } else {
+        None
+    }
+}
+
+#[requires(if let Some(x) = opt { x > 0 } else { true })]
+#[ensures(result ==> if let Some(x) = opt { x > 0 } else { false })]
+fn is_some_positive(opt: Option<i32>) -> bool {
+    match opt {
+        Some(_) => true,
+        None => false,
+    }
+}
+
+fn client() {
+    let p = positive(3);
+    assert!(is_some_positive(p));
+    let q = positive(-1);
+    assert!(!is_some_positive(q));
+}
+
+fn main() {}
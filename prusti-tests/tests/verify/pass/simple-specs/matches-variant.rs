@@ -0,0 +1,41 @@
+use prusti_contracts::*;
+
+enum State {
+    Idle,
+    Ready(u32),
+    Done { code: u32 },
+}
+
+#[requires(matches!(state, State::Ready(_)))]
+fn ready_value(state: State) -> u32 {
+    match state {
+        State::Ready(value) => value,
+        State::Idle | State::Done { .. } => unreachable!(),
+    }
+}
+
+#[requires(matches!(state, State::Ready(value) if *value > 0))]
+#[ensures(result > 0)]
+fn positive_value(state: &State) -> u32 {
+    match state {
+        State::Ready(value) => *value,
+        _ => unreachable!(),
+    }
+}
+
+#[requires(!matches!(state, State::Idle))]
+#[ensures(matches!(result, State::Done { .. }))]
+fn finish(state: State) -> State {
+    match state {
+        State::Ready(value) => State::Done { code: value },
+        State::Done { code } => State::Done { code },
+        State::Idle => unreachable!(),
+    }
+}
+
+fn main() {
+    ready_value(State::Ready(3));
+    positive_value(&State::Ready(1));
+    let done = finish(State::Ready(2));
+    finish(done);
+}
@@ -0,0 +1,31 @@
+use prusti_contracts::*;
+
+#[requires(matches!(x, 0..=10))]
+#[ensures(matches!(result, 1..=5))]
+fn halve_up(x: u32) -> u32 {
+    if x == 0 {
+        1
+    } else {
+        (x + 1) / 2
+    }
+}
+
+#[ensures(matches!(result, ..=0) == (x <= 0))]
+#[ensures(matches!(result, 1..) == (x > 0))]
+fn identity(x: i32) -> i32 {
+    x
+}
+
+#[pure]
+fn is_digit(c: u8) -> bool {
+    matches!(c, b'0'..=b'9')
+}
+
+fn main() {
+    let r = halve_up(7);
+    assert!(matches!(r, 1..=5));
+    assert!(is_digit(b'5'));
+    assert!(!is_digit(b'a'));
+    let i = identity(-3);
+    assert!(i < 0);
+}
@@ -0,0 +1,23 @@
+use prusti_contracts::*;
+
+#[ensures(*x == old(*x) + 2)]
+fn inc_twice(x: &mut u32) {
+    prusti_assume!(*x < 100);
+    *x += 1;
+    snapshot!(mid);
+    *x += 1;
+    prusti_assert!(*x == old[mid](*x) + 1);
+}
+
+fn local_snapshots() {
+    let mut a = 1;
+    snapshot!(first);
+    a += 10;
+    snapshot!(second);
+    a *= 2;
+    prusti_assert!(old[first](a) == 1);
+    prusti_assert!(old[second](a) == 11);
+    prusti_assert!(a == 22);
+}
+
+fn main() {}
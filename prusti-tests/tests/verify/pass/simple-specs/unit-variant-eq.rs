@@ -0,0 +1,29 @@
+use prusti_contracts::*;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+#[ensures(d == Direction::North ==> result == Direction::East)]
+#[ensures(d == Direction::East ==> result == Direction::South)]
+#[ensures(d == Direction::West ==> result == Direction::North)]
+#[ensures(result != d)]
+fn turn_right(d: Direction) -> Direction {
+    match d {
+        Direction::North => Direction::East,
+        Direction::East => Direction::South,
+        Direction::South => Direction::West,
+        Direction::West => Direction::North,
+    }
+}
+
+#[ensures(result == Direction::South)]
+fn south() -> Direction {
+    turn_right(turn_right(Direction::North))
+}
+
+fn main() {}
@@ -0,0 +1,30 @@
+use prusti_contracts::*;
+
+#[requires(s.len() > 1)]
+#[ensures(result == old(s[0]))]
+#[ensures(s[0] == old(s[1]))]
+#[ensures(s.len() == old(s.len()))]
+fn replace_first(s: &mut [i32]) -> i32 {
+    let first = s[0];
+    s[0] = s[1];
+    first
+}
+
+#[ensures(result == old(a[2]))]
+#[ensures(a[2] == 0)]
+#[ensures(a[0] == old(a[0]))]
+#[ensures(a[1] == old(a[1]))]
+fn take_last(a: &mut [i32; 3]) -> i32 {
+    let last = a[2];
+    a[2] = 0;
+    last
+}
+
+fn main() {
+    let mut a = [1, 2, 3];
+    let x = take_last(&mut a);
+    assert!(x == 3);
+    assert!(a[2] == 0);
+    let y = replace_first(&mut a);
+    assert!(y == 1);
+}
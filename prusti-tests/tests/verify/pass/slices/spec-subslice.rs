@@ -0,0 +1,33 @@
+use prusti_contracts::*;
+
+#[requires(a.len() >= 5)]
+#[ensures(a[1..4].len() == 3)]
+#[ensures(a[1..4][0] == a[1])]
+#[ensures(forall(|i: usize| i < 3 ==> a[1..4][i] == a[i + 1]))]
+#[ensures(a[1..=3].len() == 3)]
+#[ensures(a[1..=3][2] == a[3])]
+#[ensures(a[..=2].len() == 3)]
+fn sub_slices(a: &[i32]) {}
+
+#[pure]
+#[requires(lo <= hi && hi <= a.len())]
+#[ensures(result == hi - lo)]
+fn sub_len(a: &[i32], lo: usize, hi: usize) -> usize {
+    a[lo..hi].len()
+}
+
+#[pure]
+#[requires(lo <= hi && hi < a.len())]
+#[ensures(result == hi - lo + 1)]
+fn sub_len_inclusive(a: &[i32], lo: usize, hi: usize) -> usize {
+    a[lo..=hi].len()
+}
+
+#[requires(a.len() == 10)]
+fn client(a: &[i32]) {
+    sub_slices(a);
+    assert!(sub_len(a, 2, 5) == 3);
+    assert!(sub_len_inclusive(a, 2, 5) == 4);
+}
+
+fn main() {}
@@ -0,0 +1,28 @@
+use prusti_contracts::*;
+
+#[pure]
+#[after_expiry(result > 0)]
+#[prusti::allow(P0007)]
+fn allowed(x: &u32) -> u32 {
+    *x
+}
+
+#[pure]
+#[after_expiry(result > 0)]
+#[prusti::allow(P0001)]
+fn other_code_allowed(x: &u32) -> u32 {
+    *x
+}
+
+#[pure]
+#[after_expiry(result > 0)]
+fn not_allowed(x: &u32) -> u32 {
+    *x
+}
+
+fn main() {
+    let x = 5;
+    assert!(allowed(&x) == 5);
+    assert!(other_code_allowed(&x) == 5);
+    assert!(not_allowed(&x) == 5);
+}
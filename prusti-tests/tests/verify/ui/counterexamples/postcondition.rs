@@ -0,0 +1,12 @@
+// compile-flags: -Pcounterexample=true
+
+use prusti_contracts::*;
+
+#[requires(a == 3 && b == 4)] // force specific counterexample
+#[ensures(result == a * b)]
+fn sum(a: i32, b: i32) -> i32 {
+    let total = a + b;
+    total
+}
+
+fn main() {}
@@ -18,4 +18,7 @@ pub fn test2() {}
 #[requires(forall(|n: usize, res: usize| count(n) == res ==> true, triggers=[(res == count(n),)]))]
 pub fn test3() {}
 
+#[ensures(forall(|n: usize, res: usize| count(n) == res ==> true, triggers=[(old(count(n) + res),)]))]
+pub fn test4() {}
+
 fn main() {}
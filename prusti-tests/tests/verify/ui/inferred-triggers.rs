@@ -0,0 +1,17 @@
+// compile-flags: -Preport_inferred_triggers=true -Pdisable_name_mangling=true
+
+use prusti_contracts::*;
+
+#[pure]
+fn is_even(n: u32) -> bool {
+    n % 2 == 0
+}
+
+#[requires(forall(|i: u32| is_even(i) ==> is_even(i + 2)))]
+fn inferred() {}
+
+#[requires(forall(|i: u32| is_even(i), triggers=[(is_even(i),)]))]
+fn explicit() {}
+
+#[trusted]
+fn main() {}
@@ -0,0 +1,18 @@
+// compile-flags: -Plist_trusted=true -Pmax_trusted=2
+
+use prusti_contracts::*;
+
+#[trusted]
+fn a() {}
+
+#[trusted]
+fn b() {}
+
+#[trusted]
+fn c() {}
+
+fn main() {
+    a();
+    b();
+    c();
+}
@@ -0,0 +1,12 @@
+use prusti_contracts::*;
+
+#[pure]
+#[after_expiry(result > 0)]
+fn value(x: &u32) -> u32 {
+    *x
+}
+
+fn main() {
+    let x = 5;
+    assert!(value(&x) == 5);
+}
@@ -0,0 +1,20 @@
+// compile-flags: -Pmax_quantifier_alternations=2
+
+use prusti_contracts::*;
+
+#[pure]
+fn related(a: u32, b: u32) -> bool {
+    a <= b
+}
+
+#[requires(forall(|x: u32| exists(|y: u32| related(x, y))))]
+fn two_blocks() {}
+
+#[requires(forall(|x: u32| forall(|y: u32| exists(|z: u32| related(x, z) && related(y, z)))))]
+fn nested_forall() {}
+
+#[requires(forall(|x: u32| exists(|y: u32| forall(|z: u32| related(x, y) || related(z, y)))))]
+fn three_blocks() {}
+
+#[trusted]
+fn main() {}
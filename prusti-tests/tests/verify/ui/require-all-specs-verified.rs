@@ -0,0 +1,26 @@
+// compile-flags: -Popt_in_verification=true -Prequire_all_specs_verified=true
+
+use prusti_contracts::*;
+
+#[verified]
+#[ensures(result == 1)]
+fn checked() -> u32 {
+    1
+}
+
+#[ensures(result == 2)]
+fn skipped() -> u32 {
+    2
+}
+
+#[trusted]
+#[ensures(result == 3)]
+fn assumed() -> u32 {
+    3
+}
+
+fn main() {
+    checked();
+    skipped();
+    assumed();
+}
@@ -0,0 +1,15 @@
+// compile-flags: -Presults_json=-
+
+use prusti_contracts::*;
+
+#[requires(x > 10)]
+fn verified(x: u32) -> u32 {
+    x
+}
+
+fn failed() {
+    verified(1);
+}
+
+#[trusted]
+fn main() {}
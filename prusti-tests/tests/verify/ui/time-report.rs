@@ -0,0 +1,13 @@
+// compile-flags: -Ptime_report=true
+// normalize-stdout-test: "[0-9]+/.[0-9]{3} ms" -> "$(TIME) ms"
+
+use prusti_contracts::*;
+
+#[requires(x < 100)]
+#[ensures(result == x + 1)]
+fn verified(x: u32) -> u32 {
+    x + 1
+}
+
+#[trusted]
+fn main() {}
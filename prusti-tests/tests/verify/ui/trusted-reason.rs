@@ -0,0 +1,19 @@
+// compile-flags: -Plist_trusted=true
+
+use prusti_contracts::*;
+
+#[trusted("FFI boundary, reviewed in PR #42")]
+fn ffi_call() {}
+
+#[trusted]
+fn no_reason() {}
+
+#[requires(x > 0)]
+#[trusted("not an outer attribute")]
+fn reason_after_requires(x: i32) {}
+
+fn main() {
+    ffi_call();
+    no_reason();
+    reason_after_requires(1);
+}
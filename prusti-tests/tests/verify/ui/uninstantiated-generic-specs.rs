@@ -0,0 +1,22 @@
+// compile-flags: -Pwarn_uninstantiated_generic_specs=true
+
+use prusti_contracts::*;
+
+#[requires(x > 0)]
+fn used<T>(x: i32, _y: T) -> i32 {
+    x
+}
+
+#[ensures(result === x)]
+fn unused<T>(x: T) -> T {
+    x
+}
+
+#[ensures(result == x)]
+fn not_generic(x: i32) -> i32 {
+    x
+}
+
+fn main() {
+    used(1, true);
+}
@@ -0,0 +1,21 @@
+// compile-flags: -Pcheck_vacuous_preconditions=true
+
+use prusti_contracts::*;
+
+#[requires(x > 10)]
+#[requires(x < 5)]
+fn contradictory(x: i32) -> i32 {
+    x
+}
+
+#[requires(x > 10)]
+#[requires(x < 20)]
+fn satisfiable(x: i32) -> i32 {
+    x
+}
+
+fn no_preconditions(x: i32) -> i32 {
+    x
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+// compile-flags: -Punsafe_core_proof=true
+
+#![allow(unused)]
+
+use prusti_contracts::*;
+
+fn ghost_only_local() {
+    let g = ghost! {
+        let y = Ghost::new(5u32);
+        prusti_assert!(*y == 5);
+        prusti_assert!(*y == 6);     //~ ERROR: the asserted expression might not hold
+    };
+}
+
+fn outer_ghost_variable(x: u32) {
+    let g = Ghost::new(x);
+    ghost! {
+        prusti_assert!(*g == x);
+        prusti_assert!(*g != x);     //~ ERROR: the asserted expression might not hold
+    };
+}
+
+fn main() {}
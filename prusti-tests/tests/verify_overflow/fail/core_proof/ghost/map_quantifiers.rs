@@ -0,0 +1,35 @@
+// compile-flags: -Punsafe_core_proof=true
+
+#![allow(unused)]
+
+use prusti_contracts::*;
+
+struct Counters {
+    counts: Map<u32, u32>,
+}
+
+#[requires(forall(|k: u32| c.counts.contains_key(k) ==> c.counts.get(k) > 0))]
+#[requires(c.counts.contains_key(key))]
+#[ensures(result > 0)]
+fn positive(c: &Counters, key: u32) -> u32 {
+    c.counts.get(key)
+}
+
+#[requires(forall(|k: u32| c.counts.contains_key(k) ==> c.counts.get(k) > 0))]
+fn missing_key(c: &Counters, key: u32) -> u32 {
+    c.counts.get(key) //~ ERROR: the key might not be in the map
+}
+
+#[requires(forall(|k: u32| counts.contains_key(k) ==> counts.get(k) > 0))]
+#[ensures(forall(|k: u32| result.contains_key(k) ==> result.get(k) > 0))]
+fn insert_positive(counts: Map<u32, u32>, key: u32) -> Map<u32, u32> {
+    counts.insert(key, 1)
+}
+
+#[requires(forall(|k: u32| counts.contains_key(k) ==> counts.get(k) > 0))]
+#[ensures(forall(|k: u32| result.contains_key(k) ==> result.get(k) > 0))] //~ ERROR: postcondition might not hold
+fn insert_zero(counts: Map<u32, u32>, key: u32) -> Map<u32, u32> {
+    counts.insert(key, 0)
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+// compile-flags: -Punsafe_core_proof=true
+
+#![allow(unused)]
+
+use prusti_contracts::*;
+
+fn all_positive() {
+    let seq = seq![1u32, 2, 3];
+    prusti_assert!(forall(|i: usize| Int::new_usize(i) < seq.len() ==> seq[i] > 0));
+}
+
+fn all_small() {
+    let seq = seq![1u32, 2, 30];
+    prusti_assert!(forall(|i: usize| Int::new_usize(i) < seq.len() ==> seq[i] < 10)); //~ ERROR: the asserted expression might not hold
+}
+
+fn some_element() {
+    let seq = seq![1u32, 2, 3];
+    prusti_assert!(exists(|i: usize| Int::new_usize(i) < seq.len() && seq[i] == 2));
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+// compile-flags: -Punsafe_core_proof=true
+
+use std::marker::PhantomData;
+
+struct Tagged<T> {
+    value: u32,
+    tag: PhantomData<T>,
+}
+
+fn tag_of<T>(tagged: &Tagged<T>) -> PhantomData<T> {
+    tagged.tag //~ ERROR access to `PhantomData` fields is not supported
+}
+
+fn main() {}
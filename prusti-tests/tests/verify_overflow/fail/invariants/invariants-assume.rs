@@ -0,0 +1,35 @@
+// compile-flags: -Penable_type_invariants=true
+use prusti_contracts::*;
+
+#[invariant(self.value <= 100)]
+struct Percentage {
+    value: u8,
+}
+
+impl Percentage {
+    fn incr(&mut self) {
+        if self.value < 100 {
+            self.value += 1;
+        }
+    }
+}
+
+fn construct_and_assume(x: u8) {
+    // For example, the value was validated by some trusted means.
+    let mut perc = Percentage { value: x };
+    prusti_assume_invariant!(perc);
+    perc.incr();
+}
+
+fn construct_without_assume(x: u8) {
+    let mut perc = Percentage { value: x };
+    perc.incr(); //~ ERROR precondition might not hold
+}
+
+fn assume_field_of_wrapper(x: u8) {
+    let mut pair = (Percentage { value: x }, 0);
+    prusti_assume_invariant!(pair.0);
+    pair.0.incr();
+}
+
+fn main() {}
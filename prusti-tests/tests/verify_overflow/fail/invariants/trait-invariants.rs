@@ -0,0 +1,45 @@
+// compile-flags: -Penable_type_invariants=true
+use prusti_contracts::*;
+
+#[invariant(self.low() <= self.high())]
+trait Interval {
+    #[pure]
+    fn low(&self) -> u32;
+
+    #[pure]
+    fn high(&self) -> u32;
+}
+
+struct Closed {
+    lo: u32,
+    hi: u32,
+}
+
+impl Interval for Closed {
+    #[pure]
+    fn low(&self) -> u32 {
+        self.lo
+    }
+
+    #[pure]
+    fn high(&self) -> u32 {
+        self.hi
+    }
+}
+
+#[requires(lo <= hi)]
+fn closed(lo: u32, hi: u32) -> Closed {
+    Closed { lo, hi }
+}
+
+fn inverted() -> Closed { //~ ERROR type invariants
+    Closed { lo: 1, hi: 0 }
+}
+
+// The subtraction cannot underflow, because the argument satisfies the
+// invariant of `Interval`.
+fn width(interval: Closed) -> u32 {
+    interval.hi - interval.lo
+}
+
+fn main() {}
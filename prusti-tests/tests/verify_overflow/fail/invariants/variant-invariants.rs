@@ -0,0 +1,35 @@
+// compile-flags: -Penable_type_invariants=true
+use prusti_contracts::*;
+
+#[variant_invariant(Running(ticks) => ticks > 0)]
+#[variant_invariant(Stopped { code } => code <= 100)]
+enum Machine {
+    Running(u32),
+    Stopped { code: u8 },
+}
+
+fn start() -> Machine { //~ ERROR type invariants
+    Machine::Running(0)
+}
+
+fn stop(code: u8) -> Machine { //~ ERROR type invariants
+    Machine::Stopped { code }
+}
+
+#[ensures(result <= 100)]
+fn code(machine: Machine) -> u8 {
+    match machine {
+        Machine::Running(_) => 0,
+        Machine::Stopped { code } => code,
+    }
+}
+
+#[ensures(result > 1)] //~ ERROR postcondition might not hold
+fn ticks(machine: Machine) -> u32 {
+    match machine {
+        Machine::Running(ticks) => ticks,
+        Machine::Stopped { .. } => 2,
+    }
+}
+
+fn main() {}
@@ -0,0 +1,33 @@
+use prusti_contracts::*;
+
+struct Pair {
+    left: i32,
+    right: i32,
+}
+
+#[after_expiry(a => a.left == before_expiry(*result))]
+fn left_of<'a>(a: &'a mut Pair, b: &mut Pair) -> &'a mut i32 {
+    b.right = 0;
+    &mut a.left
+}
+
+#[after_expiry(a => a.left == before_expiry(*result) + 1)]
+fn wrong_left_of<'a>(a: &'a mut Pair, _b: &mut Pair) -> &'a mut i32 {
+    &mut a.left //~ ERROR pledge in the postcondition might not hold.
+}
+
+#[after_expiry(b => a.left == before_expiry(*result))]
+fn unrelated_left_of<'a>(a: &'a mut Pair, b: &mut Pair) -> &'a mut i32 { //~ ERROR the reference of the pledge is not reborrowed by the result
+    &mut a.left
+}
+
+fn use_left_of() {
+    let mut p = Pair { left: 1, right: 2 };
+    let mut q = Pair { left: 3, right: 4 };
+    let r = left_of(&mut p, &mut q);
+    *r = 5;
+    assert!(p.left == 5);
+    assert!(q.right == 0); //~ ERROR the asserted expression might not hold
+}
+
+fn main() {}
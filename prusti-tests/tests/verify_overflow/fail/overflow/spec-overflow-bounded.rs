@@ -0,0 +1,16 @@
+// compile-flags: -Pspec_arithmetic_mode=bounded
+use prusti_contracts::*;
+
+#[requires(x < 100)]
+#[ensures(x + 100 >= 100)]
+fn guarded(x: u8) -> u8 {
+    x
+}
+
+#[ensures(result == x as u16 + 100)]
+#[ensures(x + 100 >= 100)] //~ ERROR arithmetic in the specification might overflow
+fn add_hundred(x: u8) -> u16 {
+    x as u16 + 100
+}
+
+fn main() {}
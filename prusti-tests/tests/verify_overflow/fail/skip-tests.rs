@@ -0,0 +1,16 @@
+// compile-flags: --test -Pskip_main=true -Pskip_tests=true
+use prusti_contracts::*;
+
+fn main() {
+    assert!(false);
+}
+
+#[ensures(result > x)]
+fn increment(x: u32) -> u32 {
+    x + 1 //~ ERROR attempt to add with overflow
+}
+
+#[test]
+fn increment_is_positive() {
+    assert!(increment(u32::MAX - 1) == 0);
+}
@@ -0,0 +1,17 @@
+// compile-flags: -Pencode_bitvectors=true
+
+use prusti_contracts::*;
+
+#[ensures(x.count_ones() <= 8)]
+fn count_ones(x: u8) {}
+
+#[requires(x == 6)]
+#[ensures(x.count_ones() == 2 && x.count_zeros() == 6)]
+#[ensures(x.leading_zeros() == 5 && x.trailing_zeros() == 1)]
+fn bit_counts(x: u8) {}
+
+#[requires(x != 0)]
+#[ensures(x.leading_zeros() + x.trailing_zeros() < 32)]
+fn non_zero(x: u32) {}
+
+fn main() {}
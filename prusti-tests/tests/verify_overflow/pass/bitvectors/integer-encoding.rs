@@ -0,0 +1,36 @@
+// compile-flags: -Pinteger_encoding=bitvector
+
+use prusti_contracts::*;
+
+#[ensures(result <= x)]
+#[ensures(result <= 15)]
+fn low_nibble(x: u8) -> u8 {
+    x & 0x0F
+}
+
+#[ensures(result >= x)]
+fn set_lowest_bit(x: u32) -> u32 {
+    x | 1
+}
+
+fn swap_with_xor() {
+    let mut a = 12u16;
+    let mut b = 5u16;
+    a = a ^ b;
+    b = a ^ b;
+    a = a ^ b;
+    assert!(a == 5 && b == 12);
+}
+
+fn shifts() {
+    let a = 1u8;
+    let b = a << 3;
+    assert!(b == 8);
+    let c = b >> 2;
+    assert!(c == 2);
+}
+
+fn main() {
+    let n = low_nibble(0xAB);
+    assert!(n <= 15);
+}
@@ -0,0 +1,77 @@
+// compile-flags: -Punsafe_core_proof=true
+
+use prusti_contracts::*;
+use std::collections::HashMap;
+
+#[extern_spec]
+impl<K, V, S: std::hash::BuildHasher> std::collections::HashMap<K, V, S> {
+    #[pure]
+    fn contains_key<Q: ?Sized>(&self, k: &Q) -> bool
+    where
+        K: core::borrow::Borrow<Q> + std::cmp::Eq + std::hash::Hash,
+        Q: core::hash::Hash + Eq;
+
+    #[pure]
+    fn get<Q: ?Sized>(&self, k: &Q) -> Option<&V>
+    where
+        K: core::borrow::Borrow<Q> + std::cmp::Eq + std::hash::Hash,
+        Q: core::hash::Hash + Eq;
+
+    #[pure]
+    pub fn len(&self) -> usize;
+
+    #[ensures(self.contains_key(&k))]
+    fn insert(&mut self, k: K, v: V) -> Option<V>
+    where
+        K: std::cmp::Eq + std::hash::Hash;
+}
+
+#[pure]
+#[requires(m.contains_key(&key))]
+#[ensures(result)]
+fn contains(m: &HashMap<u32, u32>, key: u32) -> bool {
+    m.contains_key(&key)
+}
+
+#[requires(m.contains_key(&key))]
+#[ensures(result == m.len())]
+fn len_of_nonempty(m: &HashMap<u32, u32>, key: u32) -> usize {
+    m.len()
+}
+
+#[pure]
+fn value_or_zero(m: &HashMap<u32, u32>, key: u32) -> u32 {
+    match m.get(&key) {
+        Some(value) => *value,
+        None => 0,
+    }
+}
+
+#[requires(!m.contains_key(&key))]
+#[ensures(result == 0)]
+fn missing_is_zero(m: &HashMap<u32, u32>, key: u32) -> u32 {
+    value_or_zero(m, key)
+}
+
+#[pure]
+#[requires(m.contains_key(&key))]
+fn present_value(m: &HashMap<u32, u32>, key: u32) -> u32 {
+    match m.get(&key) {
+        Some(value) => *value,
+        None => unreachable!(),
+    }
+}
+
+#[requires(m.contains_key(&key) && present_value(m, key) < 10)]
+#[ensures(result < 10)]
+fn present_value_is_read(m: &HashMap<u32, u32>, key: u32) -> u32 {
+    value_or_zero(m, key)
+}
+
+fn insert_then_contains(mut m: HashMap<u32, u32>) {
+    m.insert(1, 2);
+    assert!(m.contains_key(&1));
+    assert!(contains(&m, 1));
+}
+
+fn main() {}
@@ -0,0 +1,35 @@
+// compile-flags: -Punsafe_core_proof=true
+
+#![allow(unused)]
+
+use prusti_contracts::*;
+
+type Seq = prusti_contracts::Seq<i64>;
+
+#[pure]
+#[terminates(trusted)]
+fn sum(s: Seq) -> Int {
+    if s.len() == Int::new(0) {
+        Int::new(0)
+    } else {
+        Int::new(s[0]) + sum(s.tail())
+    }
+}
+
+#[ensures(result == sum(Seq::empty()))]
+fn sum_empty() -> Int {
+    Int::new(0)
+}
+
+#[ensures(result == sum(seq![1, 2, 3]))]
+fn sum_three() -> Int {
+    Int::new(6)
+}
+
+#[requires(s.len() > Int::new(0))]
+#[ensures(result == sum(s))]
+fn sum_unfolded(s: Seq) -> Int {
+    Int::new(s[0]) + sum(s.tail())
+}
+
+fn main() {}
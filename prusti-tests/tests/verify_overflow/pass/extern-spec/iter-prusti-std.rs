@@ -0,0 +1,21 @@
+// The specifications of `once`, `repeat` and `take` are provided by `prusti_std`.
+extern crate prusti_std;
+
+use prusti_contracts::*;
+use prusti_std::{iter_get, iter_is_infinite, iter_len};
+
+fn main() {
+    let once = std::iter::once(5);
+    prusti_assert!(!iter_is_infinite(&once));
+    prusti_assert!(iter_len(&once) == 1);
+    prusti_assert!(iter_get(&once, 0) == 5);
+
+    let repeated = std::iter::repeat(7).take(3);
+    prusti_assert!(iter_len(&repeated) == 3);
+    prusti_assert!(iter_get(&repeated, 0) == 7);
+    prusti_assert!(iter_get(&repeated, 2) == 7);
+
+    let once_taken = std::iter::once(1).take(3);
+    prusti_assert!(iter_len(&once_taken) == 1);
+    prusti_assert!(iter_get(&once_taken, 0) == 1);
+}
@@ -0,0 +1,34 @@
+// The specifications of `Vec` are provided by `prusti_std`.
+extern crate prusti_std;
+
+use prusti_contracts::*;
+use prusti_std::vec_get;
+
+fn main() {
+    let mut v = Vec::new();
+    assert!(v.is_empty());
+    v.push(1);
+    v.push(2);
+    assert!(v.len() == 2);
+    let last = v.pop();
+    assert!(matches!(last, Some(_)));
+    assert!(v.len() == 1);
+    v.pop();
+    assert!(v.is_empty());
+    let none = v.pop();
+    assert!(matches!(none, None));
+    assert!(v.len() == 0);
+}
+
+fn push_then_pop() {
+    let mut v = Vec::new();
+    v.push(3);
+    v.push(5);
+    prusti_assert!(vec_get(&v, 0) == 3);
+    prusti_assert!(vec_get(&v, 1) == 5);
+    let last = v.pop();
+    assert!(matches!(last, Some(5)));
+    prusti_assert!(vec_get(&v, 0) == 3);
+    let first = v.pop();
+    assert!(matches!(first, Some(3)));
+}
@@ -0,0 +1,31 @@
+// compile-flags: -Penable_type_invariants=true
+use prusti_contracts::*;
+
+#[variant_invariant(Running(ticks) => ticks > 0)]
+#[variant_invariant(Stopped { code } => code <= 100)]
+enum Machine {
+    Running(u32),
+    Stopped { code: u8 },
+}
+
+fn start() -> Machine {
+    Machine::Running(1)
+}
+
+fn stop(code: u8) -> Machine {
+    if code > 100 {
+        Machine::Stopped { code: 100 }
+    } else {
+        Machine::Stopped { code }
+    }
+}
+
+#[ensures(result > 0)]
+fn ticks(machine: Machine) -> u32 {
+    match machine {
+        Machine::Running(ticks) => ticks,
+        Machine::Stopped { code } => code as u32 + 1,
+    }
+}
+
+fn main() {}
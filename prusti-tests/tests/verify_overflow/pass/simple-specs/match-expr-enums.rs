@@ -0,0 +1,52 @@
+//! Example: test match expressions on `Result` and user-defined enums
+
+use prusti_contracts::*;
+
+#[derive(Clone, Copy)]
+enum Shape {
+    Point,
+    Square(u8),
+    Rect(u8, u8),
+}
+
+#[pure]
+#[ensures(match shape {
+    Shape::Point => result == 0,
+    Shape::Square(side) => result == side as u32 * side as u32,
+    Shape::Rect(width, height) => result == width as u32 * height as u32,
+})]
+fn area(shape: Shape) -> u32 {
+    match shape {
+        Shape::Point => 0,
+        Shape::Square(side) => side as u32 * side as u32,
+        Shape::Rect(width, height) => width as u32 * height as u32,
+    }
+}
+
+#[requires(match shape { Shape::Square(side) => side > 0, _ => false })]
+#[ensures(match result {
+    Ok(side) if side > 0 => matches!(shape, Shape::Square(s) if s == side),
+    _ => false,
+})]
+fn side_of_square(shape: Shape) -> Result<u8, ()> {
+    match shape {
+        Shape::Square(side) => Ok(side),
+        _ => Err(()),
+    }
+}
+
+#[ensures(match x {
+    n @ 1..=9 => result == n + 1,
+    _ => result == x,
+})]
+fn bump_digit(x: u32) -> u32 {
+    if 1 <= x && x <= 9 { x + 1 } else { x }
+}
+
+fn main() {
+    let square = Shape::Square(3);
+    let result = side_of_square(square);
+    assert!(matches!(result, Ok(3)));
+    assert!(bump_digit(4) == 5);
+    assert!(area(Shape::Rect(2, 3)) == 6);
+}
@@ -0,0 +1,66 @@
+// The specification of a wrapper type is written against a sequence model.
+use prusti_contracts::*;
+
+struct Stack {
+    items: Vec<u32>,
+}
+
+#[model]
+struct Stack {
+    seq: GhostSeq,
+}
+
+impl Stack {
+    #[trusted]
+    #[ensures(result.model().seq.len() == 0)]
+    fn new() -> Self {
+        Stack { items: Vec::new() }
+    }
+
+    #[trusted]
+    #[ensures(self.model().seq.len() == old(self.model().seq.len()) + 1)]
+    #[ensures(forall(|i: usize| i < old(self.model().seq.len()) ==>
+        self.model().seq.lookup(i) == old(self.model().seq.lookup(i))))]
+    #[ensures(self.model().seq.lookup(old(self.model().seq.len())) == value)]
+    fn push(&mut self, value: u32) {
+        self.items.push(value);
+    }
+
+    #[trusted]
+    #[requires(self.model().seq.len() > 0)]
+    #[ensures(self.model().seq.len() == old(self.model().seq.len()) - 1)]
+    #[ensures(result == old(self.model().seq.lookup(self.model().seq.len() - 1)))]
+    fn pop(&mut self) -> u32 {
+        self.items.pop().unwrap()
+    }
+}
+
+#[derive(Copy, Clone)]
+struct GhostSeq {
+    phantom: std::marker::PhantomData<u32>,
+}
+
+impl GhostSeq {
+    #[pure]
+    #[trusted]
+    #[requires(i < self.len())]
+    fn lookup(&self, i: usize) -> u32 {
+        unimplemented!()
+    }
+
+    #[pure]
+    #[trusted]
+    fn len(&self) -> usize {
+        unimplemented!()
+    }
+}
+
+fn main() {
+    let mut stack = Stack::new();
+    stack.push(1);
+    stack.push(2);
+    let top = stack.pop();
+    assert!(top == 2);
+    let bottom = stack.pop();
+    assert!(bottom == 1);
+}
@@ -13,7 +13,7 @@ use ::config::{Config, Environment, File};
 use log::warn;
 use rustc_hash::FxHashSet;
 use serde::Deserialize;
-use std::{env, path::PathBuf, sync::RwLock};
+use std::{env, path::PathBuf, str::FromStr, sync::RwLock};
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Optimizations {
@@ -26,6 +26,8 @@ pub struct Optimizations {
     pub fix_unfoldings: bool,
     pub remove_unused_vars: bool,
     pub remove_trivial_assertions: bool,
+    pub remove_unused_labels: bool,
+    pub share_pure_calls: bool,
     pub clean_cfg: bool,
 }
 
@@ -41,6 +43,8 @@ impl Optimizations {
             fix_unfoldings: false,
             remove_unused_vars: false,
             remove_trivial_assertions: false,
+            remove_unused_labels: false,
+            share_pure_calls: false,
             clean_cfg: false,
         }
     }
@@ -57,6 +61,10 @@ impl Optimizations {
             fix_unfoldings: false,
             remove_unused_vars: true,
             remove_trivial_assertions: true,
+            remove_unused_labels: true,
+            // Opt-in until the `share-pure-calls` benchmarks show that it
+            // helps more than the extra `let` expressions cost the verifier.
+            share_pure_calls: false,
             clean_cfg: true,
         }
     }
@@ -78,10 +86,15 @@ lazy_static::lazy_static! {
 
         settings.set_default::<Option<u32>>("check_timeout", None).unwrap();
         settings.set_default("check_foldunfold_state", false).unwrap();
+        settings.set_default("check_vir_types", false).unwrap();
         settings.set_default("check_overflows", true).unwrap();
+        settings.set_default("spec_arithmetic_mode", "unbounded").unwrap();
         settings.set_default("check_panics", true).unwrap();
+        settings.set_default("check_assertions", false).unwrap();
+        settings.set_default("check_vacuous_preconditions", false).unwrap();
         settings.set_default("encode_unsigned_num_constraint", true).unwrap();
         settings.set_default("encode_bitvectors", false).unwrap();
+        settings.set_default("integer_encoding", "int").unwrap();
         settings.set_default("simplify_encoding", true).unwrap();
         settings.set_default("log", "").unwrap();
         settings.set_default("log_style", "auto").unwrap();
@@ -97,6 +110,9 @@ lazy_static::lazy_static! {
         settings.set_default("dump_reborrowing_dag_in_debug_info", false).unwrap();
         settings.set_default("dump_borrowck_info", false).unwrap();
         settings.set_default("dump_viper_program", false).unwrap();
+        settings.set_default("encode_only", false).unwrap();
+        settings.set_default("export_viper_prelude", false).unwrap();
+        settings.set_default::<Option<String>>("explain_encoding", None).unwrap();
         settings.set_default("foldunfold_state_filter", "").unwrap();
         settings.set_default::<Vec<String>>("extra_jvm_args", vec![]).unwrap();
         settings.set_default::<Vec<String>>("extra_verifier_args", vec![]).unwrap();
@@ -109,7 +125,11 @@ lazy_static::lazy_static! {
         settings.set_default("allow_unreachable_unsupported_code", false).unwrap();
         settings.set_default("no_verify", false).unwrap();
         settings.set_default("no_verify_deps", false).unwrap();
+        settings.set_default("export_only_public_specs", false).unwrap();
         settings.set_default("opt_in_verification", false).unwrap();
+        settings.set_default("skip_main", false).unwrap();
+        settings.set_default("skip_tests", false).unwrap();
+        settings.set_default("lazy_assertion_encoding", false).unwrap();
         settings.set_default("full_compilation", false).unwrap();
         settings.set_default("json_communication", false).unwrap();
         settings.set_default("optimizations", "all").unwrap();
@@ -126,14 +146,22 @@ lazy_static::lazy_static! {
         settings.set_default("inline_caller_for", false).unwrap();
         settings.set_default("check_no_drops", false).unwrap();
         settings.set_default("enable_type_invariants", false).unwrap();
+        settings.set_default("warn_uninstantiated_generic_specs", false).unwrap();
         settings.set_default("use_new_encoder", true).unwrap();
         settings.set_default::<Option<u8>>("number_of_parallel_verifiers", None).unwrap();
         settings.set_default::<Option<String>>("min_prusti_version", None).unwrap();
 
         settings.set_default("print_desugared_specs", false).unwrap();
         settings.set_default("print_typeckd_specs", false).unwrap();
+        settings.set_default("report_inferred_triggers", false).unwrap();
+        settings.set_default("max_quantifier_alternations", 3).unwrap();
         settings.set_default("print_collected_verification_items", false).unwrap();
+        settings.set_default("time_report", false).unwrap();
+        settings.set_default::<Option<String>>("results_json", None).unwrap();
         settings.set_default("hide_uuids", false).unwrap();
+        settings.set_default("list_trusted", false).unwrap();
+        settings.set_default("require_all_specs_verified", false).unwrap();
+        settings.set_default::<Option<usize>>("max_trusted", None).unwrap();
         settings.set_default("counterexample", false).unwrap();
         settings.set_default("print_counterexample_if_model_is_present", false).unwrap();
         settings.set_default::<Option<String>>("save_failing_trace_to_file", None).unwrap();
@@ -220,6 +248,10 @@ lazy_static::lazy_static! {
         ).unwrap();
         check_keys(&settings, &allowed_keys, "command line arguments");
 
+        // 5. Check the values of the flags that only accept some strings
+        check_value::<SpecArithmeticMode>(&settings, "spec_arithmetic_mode");
+        check_value::<IntegerEncoding>(&settings, "integer_encoding");
+
         settings
     });
 }
@@ -243,6 +275,13 @@ fn check_keys(settings: &Config, allowed_keys: &FxHashSet<String>, source: &str)
     }
 }
 
+fn check_value<T: FromStr<Err = String>>(settings: &Config, key: &str) {
+    let value: String = settings.get(key).unwrap();
+    if let Err(error) = value.parse::<T>() {
+        panic!("{error}");
+    }
+}
+
 /// Return vector of arguments filtered out by prefix
 pub fn get_filtered_args() -> Vec<String> {
     CommandLine::with_prefix("-P")
@@ -269,6 +308,12 @@ where
     SETTINGS.read().unwrap().get(name).ok()
 }
 
+fn read_parsed_setting<T: FromStr<Err = String>>(name: &'static str) -> T {
+    read_setting::<String>(name)
+        .parse()
+        .unwrap_or_else(|error| panic!("{error}"))
+}
+
 fn read_setting<T>(name: &'static str) -> T
 where
     T: Deserialize<'static>,
@@ -304,6 +349,12 @@ pub fn check_foldunfold_state() -> bool {
     read_setting("check_foldunfold_state")
 }
 
+/// When enabled, the encoded Viper programs are type checked before they are passed to
+/// the backend, and ill-typed expressions are reported as internal errors.
+pub fn check_vir_types() -> bool {
+    read_setting("check_vir_types")
+}
+
 /// Verification backend to use. Possible values:
 ///
 /// - `Carbon` - verification-condition-generation-based backend
@@ -366,6 +417,18 @@ pub fn check_panics() -> bool {
     read_setting("check_panics")
 }
 
+/// When enabled, Prusti will check that the `assert!`s and `debug_assert!`s of
+/// the code always hold, even if `check_panics` is disabled.
+pub fn check_assertions() -> bool {
+    read_setting("check_assertions")
+}
+
+/// When enabled, Prusti warns about procedures whose preconditions are
+/// contradictory, because such procedures are verified vacuously.
+pub fn check_vacuous_preconditions() -> bool {
+    read_setting("check_vacuous_preconditions")
+}
+
 /// When enabled, the encoded program is simplified before it is passed to
 /// the Viper backend.
 pub fn simplify_encoding() -> bool {
@@ -420,6 +483,25 @@ pub fn dump_viper_program() -> bool {
     read_setting("dump_viper_program")
 }
 
+/// When enabled, the crate is encoded but the backend is not invoked; the
+/// encoded programs are dumped instead.
+pub fn encode_only() -> bool {
+    read_setting("encode_only")
+}
+
+/// When enabled, the domains, fields, predicates and pure functions generated
+/// for the crate will be exported as a standalone Viper program.
+pub fn export_viper_prelude() -> bool {
+    read_setting("export_viper_prelude")
+}
+
+/// When set to the name of a function, Prusti will write the encoded Viper method
+/// of the function, with each statement annotated by a comment that points to
+/// the Rust source code it was generated from.
+pub fn explain_encoding() -> Option<String> {
+    read_optional_setting("explain_encoding")
+}
+
 /// Filter for `fold`/`unfold` nodes when debug info is dumped.
 pub fn foldunfold_state_filter() -> String {
     read_setting("foldunfold_state_filter")
@@ -459,6 +541,37 @@ pub fn check_overflows() -> bool {
     read_setting("check_overflows")
 }
 
+/// The possible values of `spec_arithmetic_mode`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpecArithmeticMode {
+    /// An overflowing operation makes the specification false.
+    Unbounded,
+    /// Overflows in specifications are reported as separate errors, provided
+    /// that `check_overflows` is enabled.
+    Bounded,
+}
+
+impl FromStr for SpecArithmeticMode {
+    type Err = String;
+    fn from_str(mode: &str) -> Result<Self, Self::Err> {
+        match mode.to_lowercase().trim() {
+            "unbounded" => Ok(SpecArithmeticMode::Unbounded),
+            "bounded" => Ok(SpecArithmeticMode::Bounded),
+            _ => Err(format!(
+                "unknown spec arithmetic mode '{mode}', expected \"unbounded\" or \"bounded\""
+            )),
+        }
+    }
+}
+
+/// How arithmetic operations inside specifications are checked for overflows:
+/// - `unbounded` - an overflowing operation makes the specification false.
+/// - `bounded` - overflows in specifications are reported as separate errors,
+///   provided that `check_overflows` is enabled.
+pub fn spec_arithmetic_mode() -> SpecArithmeticMode {
+    read_parsed_setting("spec_arithmetic_mode")
+}
+
 /// When enabled, non-negativity of unsigned integers will be encoded and
 /// checked.
 pub fn encode_unsigned_num_constraint() -> bool {
@@ -466,10 +579,43 @@ pub fn encode_unsigned_num_constraint() -> bool {
 }
 
 /// When enabled, bitwise integer operations are encoded using bitvectors.
+/// Always enabled when `INTEGER_ENCODING` is `"bitvector"`.
 ///
 /// **Note:** this option is highly experimental.
 pub fn encode_bitvectors() -> bool {
-    read_setting("encode_bitvectors")
+    read_setting::<bool>("encode_bitvectors") || integer_encoding() == IntegerEncoding::Bitvector
+}
+
+/// The possible values of `integer_encoding`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntegerEncoding {
+    /// Integers are encoded as mathematical integers.
+    Int,
+    /// Integers are encoded as bitvectors of the size of their type.
+    Bitvector,
+}
+
+impl FromStr for IntegerEncoding {
+    type Err = String;
+    fn from_str(encoding: &str) -> Result<Self, Self::Err> {
+        match encoding.to_lowercase().trim() {
+            "int" => Ok(IntegerEncoding::Int),
+            "bitvector" => Ok(IntegerEncoding::Bitvector),
+            _ => Err(format!(
+                "unknown integer encoding '{encoding}', expected \"int\" or \"bitvector\""
+            )),
+        }
+    }
+}
+
+/// Selects how the values of fixed-size integers are encoded: `"int"` encodes
+/// them as mathematical integers and `"bitvector"` as bitvectors of the size
+/// of the integer type. The bitvector encoding also enables bitwise operations,
+/// as `ENCODE_BITVECTORS` does.
+///
+/// **Note:** the bitvector encoding is highly experimental.
+pub fn integer_encoding() -> IntegerEncoding {
+    read_parsed_setting("integer_encoding")
 }
 
 /// Additional arguments to pass to the JVM when launching a verifier backend.
@@ -522,6 +668,18 @@ pub fn print_collected_verification_items() -> bool {
     read_setting("print_collected_verification_items")
 }
 
+/// When enabled, prints a report of the time spent on collecting specifications,
+/// encoding and verifying each item.
+pub fn time_report() -> bool {
+    read_setting("time_report")
+}
+
+/// When set, the verification outcome of each function is written as JSON to
+/// the given file (or to stdout if the path is `-`).
+pub fn results_json() -> Option<String> {
+    read_setting("results_json")
+}
+
 /// When enabled, prints the AST with desugared specifications.
 pub fn print_desugared_specs() -> bool {
     read_setting("print_desugared_specs")
@@ -532,12 +690,44 @@ pub fn print_typeckd_specs() -> bool {
     read_setting("print_typeckd_specs")
 }
 
+/// When enabled, reports the triggers that Prusti infers for quantifiers that
+/// have no user-provided triggers.
+pub fn report_inferred_triggers() -> bool {
+    read_setting("report_inferred_triggers")
+}
+
+/// The maximal number of alternating blocks of nested `forall` and `exists`
+/// quantifiers in a specification before a warning is reported. Disabled when
+/// set to 0.
+pub fn max_quantifier_alternations() -> usize {
+    read_setting("max_quantifier_alternations")
+}
+
 /// When enabled, UUIDs of expressions and specifications printed with
 /// `PRINT_TYPECKD_SPECS` are hidden.
 pub fn hide_uuids() -> bool {
     read_setting("hide_uuids")
 }
 
+/// When enabled, prints the items marked as `#[trusted]` in the crate.
+pub fn list_trusted() -> bool {
+    read_setting("list_trusted")
+}
+
+/// The maximum number of items marked as `#[trusted]` in the crate. When set,
+/// a warning is emitted if the crate contains more trusted items.
+pub fn max_trusted() -> Option<usize> {
+    read_setting("max_trusted")
+}
+
+/// When enabled, Prusti reports an error if a function with a precondition,
+/// postcondition, or pledge was not verified, e.g. because it was skipped by
+/// `opt_in_verification` or its encoding failed. Functions marked as
+/// `#[trusted]` are not reported.
+pub fn require_all_specs_verified() -> bool {
+    read_setting("require_all_specs_verified")
+}
+
 /// When enabled, Prusti will try to find and print a counterexample for any
 /// failed assertion or specification.
 pub fn counterexample() -> bool {
@@ -705,6 +895,9 @@ pub fn verify_only_basic_block_path() -> Vec<String> {
 /// - `"fix_unfoldings"`
 /// - `"remove_unused_vars"`
 /// - `"remove_trivial_assertions"`
+/// - `"remove_unused_labels"`
+/// - `"share_pure_calls"` (not included in `"all"`): evaluates repeated pure function calls
+///   once per assertion; calls are not hoisted out of loops
 /// - `"clean_cfg"`
 pub fn optimizations() -> Optimizations {
     let optimizations_string = read_setting::<String>("optimizations");
@@ -724,6 +917,8 @@ pub fn optimizations() -> Optimizations {
             "fix_unfoldings" => opt.fix_unfoldings = true,
             "remove_unused_vars" => opt.remove_unused_vars = true,
             "remove_trivial_assertions" => opt.remove_trivial_assertions = true,
+            "remove_unused_labels" => opt.remove_unused_labels = true,
+            "share_pure_calls" => opt.share_pure_calls = true,
             "clean_cfg" => opt.clean_cfg = true,
             _ => warn!("Ignoring Unkown optimization '{}'", trimmed),
         }
@@ -989,12 +1184,36 @@ pub fn no_verify_deps() -> bool {
     read_setting("no_verify_deps")
 }
 
+/// When enabled, only the specifications of items that other crates can reach
+/// are exported to the specification file of a library crate.
+pub fn export_only_public_specs() -> bool {
+    read_setting("export_only_public_specs")
+}
+
 /// When enabled, verification is skipped for functions
 /// that do not have the `#[verified]` attribute.
 pub fn opt_in_verification() -> bool {
     read_setting("opt_in_verification")
 }
 
+/// When enabled, the `main` function of the crate is collected but not verified.
+pub fn skip_main() -> bool {
+    read_setting("skip_main")
+}
+
+/// When enabled, the functions annotated with `#[test]` are collected but not verified.
+/// This only has an effect when the crate is compiled as a test harness (`--test`), because
+/// test functions are removed otherwise.
+pub fn skip_tests() -> bool {
+    read_setting("skip_tests")
+}
+
+/// When enabled, the specifications of procedures that are not verified are
+/// encoded only once a verified procedure calls them.
+pub fn lazy_assertion_encoding() -> bool {
+    read_setting("lazy_assertion_encoding")
+}
+
 /// When enabled, compilation will continue and a binary will be generated
 /// after Prusti terminates.
 pub fn full_compilation() -> bool {
@@ -1023,3 +1242,10 @@ pub fn cargo_command() -> String {
 pub fn enable_type_invariants() -> bool {
     read_setting("enable_type_invariants")
 }
+
+/// When enabled, Prusti warns about generic functions with specifications that
+/// are never referenced in the crate, whose specifications are therefore
+/// never exercised by a call.
+pub fn warn_uninstantiated_generic_specs() -> bool {
+    read_setting("warn_uninstantiated_generic_specs")
+}
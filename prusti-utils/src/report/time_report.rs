@@ -0,0 +1,109 @@
+// © 2023, ETH Zurich
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! This module collects the time spent in each phase of the verification,
+//! which is printed when `config::time_report()` is enabled.
+
+use crate::config;
+use lazy_static::lazy_static;
+use rustc_hash::FxHashMap;
+use std::{
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+#[derive(Clone, Copy, Debug)]
+pub enum Phase {
+    /// Encoding of an item, including the encoding of its specifications.
+    Encoding,
+    /// Encoding of the specification assertions of an item. This time is
+    /// already included in `Phase::Encoding`.
+    Assertions,
+    /// Verification of the Viper program of an item by the backend.
+    Verification,
+}
+
+#[derive(Default)]
+struct ItemTimes {
+    encoding: Duration,
+    assertions: Duration,
+    verification: Duration,
+}
+
+impl ItemTimes {
+    fn total(&self) -> Duration {
+        self.encoding + self.verification
+    }
+}
+
+#[derive(Default)]
+struct TimeReport {
+    spec_collection: Duration,
+    items: FxHashMap<String, ItemTimes>,
+}
+
+lazy_static! {
+    static ref TIME_REPORT: Mutex<TimeReport> = Mutex::new(TimeReport::default());
+}
+
+/// Records the time spent collecting the specifications of the crate.
+pub fn record_spec_collection(duration: Duration) {
+    if config::time_report() {
+        TIME_REPORT.lock().unwrap().spec_collection += duration;
+    }
+}
+
+/// Records the time spent in the given phase for the item with the given name.
+pub fn record<S: ToString>(item: S, phase: Phase, duration: Duration) {
+    if !config::time_report() {
+        return;
+    }
+    let mut report = TIME_REPORT.lock().unwrap();
+    let times = report.items.entry(item.to_string()).or_default();
+    match phase {
+        Phase::Encoding => times.encoding += duration,
+        Phase::Assertions => times.assertions += duration,
+        Phase::Verification => times.verification += duration,
+    }
+}
+
+/// Runs `f`, recording the time it takes in the given phase for the item with
+/// the given name.
+pub fn measure<S: ToString, T>(item: S, phase: Phase, f: impl FnOnce() -> T) -> T {
+    if !config::time_report() {
+        return f();
+    }
+    let start = Instant::now();
+    let result = f();
+    record(item, phase, start.elapsed());
+    result
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:.3} ms", duration.as_secs_f64() * 1000.0)
+}
+
+/// Prints the collected times to stdout, the slowest items first.
+pub fn print() {
+    if !config::time_report() {
+        return;
+    }
+    let report = TIME_REPORT.lock().unwrap();
+    let mut items: Vec<_> = report.items.iter().collect();
+    items.sort_by(|(name_a, a), (name_b, b)| b.total().cmp(&a.total()).then(name_a.cmp(name_b)));
+    println!("Time report:");
+    println!("  specification collection: {}", format_duration(report.spec_collection));
+    for (name, times) in items {
+        println!(
+            "  {}: encoding {} (assertions {}), verification {}, total {}",
+            name,
+            format_duration(times.encoding),
+            format_duration(times.assertions),
+            format_duration(times.verification),
+            format_duration(times.total()),
+        );
+    }
+}
@@ -49,6 +49,13 @@ pub enum BuiltinFunctionKind {
         slice_pred_type: vir::Type,
         elem_pred_type: vir::Type,
     },
+    /// abstract result of calling a closure or a generic `Fn` argument in
+    /// pure code, on the snapshots of the callee and of the tupled arguments
+    ClosureCall {
+        closure_type: vir::Type,
+        arguments_type: vir::Type,
+        return_type: vir::Type,
+    },
 }
 
 #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
@@ -221,6 +228,20 @@ impl<'p, 'v: 'p, 'tcx: 'v> BuiltinEncoder<'p, 'v, 'tcx> {
                     body: None,
                 }
             },
+            BuiltinFunctionKind::ClosureCall { closure_type, arguments_type, return_type } => {
+                vir::Function {
+                    name: fn_name,
+                    type_arguments,
+                    formal_args: vec![
+                        vir::LocalVar::new("closure", closure_type),
+                        vir::LocalVar::new("arguments", arguments_type),
+                    ],
+                    return_type,
+                    pres: vec![],
+                    posts: vec![],
+                    body: None,
+                }
+            },
         }
     }
 
@@ -16,6 +16,7 @@ use crate::error_unsupported;
 use prusti_common::{vir_expr, vir_local};
 use prusti_common::config;
 use prusti_common::report::log;
+use prusti_common::report::time_report::{self, Phase};
 use prusti_interface::data::ProcedureDefId;
 use prusti_interface::environment::Environment;
 use prusti_interface::specs::typed;
@@ -39,6 +40,7 @@ use crate::encoder::errors::SpannedEncodingResult;
 use crate::encoder::mirror_function_encoder::MirrorEncoder;
 use crate::encoder::snapshot::interface::{SnapshotEncoderInterface, SnapshotEncoderState};
 use crate::encoder::purifier;
+use crate::encoder::explain_encoding;
 use super::builtin_encoder::BuiltinDomainKind;
 use super::high::builtin_functions::HighBuiltinFunctionEncoderState;
 use super::middle::core_proof::{MidCoreProofEncoderState, MidCoreProofEncoderInterface};
@@ -76,6 +78,11 @@ pub struct Encoder<'v, 'tcx: 'v> {
     pub(super) high_builtin_function_encoder_state: HighBuiltinFunctionEncoderState,
     procedures: RefCell<FxHashMap<ProcedureDefId, vir::CfgMethod>>,
     programs: Vec<vir::Program>,
+    /// The procedures whose encoding succeeded, which are therefore verified.
+    verified_procedures: FxHashSet<ProcedureDefId>,
+    /// The errors of the encoding of each procedure. Only collected if
+    /// `config::results_json()` is set.
+    procedure_encoding_errors: FxHashMap<ProcedureDefId, Vec<PrustiError>>,
     pub(super) mir_sequences_encoder_state: MirSequencesEncoderState<'tcx>,
     pub(super) contracts_encoder_state: ContractsEncoderState<'tcx>,
     pub(super) mir_procedure_encoder_state: MirProcedureEncoderState,
@@ -158,6 +165,8 @@ impl<'v, 'tcx> Encoder<'v, 'tcx> {
             builtin_methods: RefCell::new(FxHashMap::default()),
             high_builtin_function_encoder_state: Default::default(),
             programs: Vec::new(),
+            verified_procedures: FxHashSet::default(),
+            procedure_encoding_errors: FxHashMap::default(),
             mir_sequences_encoder_state: Default::default(),
             mir_procedure_encoder_state: Default::default(),
             mid_core_proof_encoder_state: Default::default(),
@@ -262,10 +271,63 @@ impl<'v, 'tcx> Encoder<'v, 'tcx> {
         prusti_error.emit(&self.env.diagnostic);
     }
 
+    /// Registers an error of the encoding of the procedure `proc_def_id`.
+    fn register_procedure_encoding_error(
+        &mut self,
+        proc_def_id: ProcedureDefId,
+        encoding_error: SpannedEncodingError,
+    ) {
+        if config::results_json().is_some() {
+            self.procedure_encoding_errors
+                .entry(proc_def_id)
+                .or_default()
+                .push(encoding_error.clone().into());
+        }
+        self.register_encoding_error(encoding_error);
+    }
+
     pub fn count_encoding_errors(&self) -> usize {
         *self.encoding_errors_counter.borrow()
     }
 
+    /// Returns the errors of the encoding of the procedure `proc_def_id`.
+    /// Only available if `config::results_json()` is set.
+    pub fn get_procedure_encoding_errors(&self, proc_def_id: ProcedureDefId) -> &[PrustiError] {
+        self.procedure_encoding_errors
+            .get(&proc_def_id)
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Whether the encoding of the procedure `proc_def_id` succeeded, so that
+    /// it is verified.
+    pub fn is_procedure_verified(&self, proc_def_id: ProcedureDefId) -> bool {
+        self.verified_procedures.contains(&proc_def_id)
+    }
+
+    /// Returns the procedures among `procedures` that have a precondition,
+    /// postcondition, or pledge, but were not verified, because they are only
+    /// trusted due to selective verification or their encoding failed.
+    /// Procedures that are explicitly marked as `#[trusted]` are not reported.
+    pub fn get_unverified_specified_procedures(
+        &self,
+        procedures: &[ProcedureDefId],
+    ) -> Vec<ProcedureDefId> {
+        procedures
+            .iter()
+            .copied()
+            .filter(|&proc_def_id| !self.verified_procedures.contains(&proc_def_id))
+            .filter(|&proc_def_id| !self.env.query.has_prusti_attribute(proc_def_id, "trusted"))
+            .filter(|&proc_def_id| {
+                let substs = self.env.query.identity_substs(proc_def_id);
+                self.get_procedure_specs(proc_def_id, substs)
+                    .map(|spec| {
+                        !spec.pres.is_empty() || !spec.posts.is_empty() || !spec.pledges.is_empty()
+                    })
+                    .unwrap_or(false)
+            })
+            .collect()
+    }
+
     pub(super) fn get_mirror_domain(&self) -> Option<vir::Domain> {
         self.mirror_encoder.borrow().get_domain().cloned()
     }
@@ -302,7 +364,9 @@ impl<'v, 'tcx> Encoder<'v, 'tcx> {
         self.procedures.borrow_mut().drain().map(|(_, value)| value).collect()
     }
 
-    /// Invoke const evaluation to extract scalar value.
+    /// Invoke const evaluation to extract scalar value. This also evaluates
+    /// const expressions such as the `N + 1` of an array length `[T; N + 1]`,
+    /// which are anonymous constants.
     fn uneval_eval_intlike(
         &self,
         ct: mir::UnevaluatedConst<'tcx>,
@@ -571,6 +635,17 @@ impl<'v, 'tcx> Encoder<'v, 'tcx> {
                 purifier::purify_method(self, &mut method);
             }
 
+            if let Some(selected) = config::explain_encoding() {
+                let proc_name = self.env.name.get_item_name(def_id);
+                if explain_encoding::is_explained(&selected, &proc_name) {
+                    log::report(
+                        "explain_encoding",
+                        format!("{proc_name}.vpr"),
+                        explain_encoding::explain_method(self, &method),
+                    );
+                }
+            }
+
             self.procedures.borrow_mut().insert(def_id, method);
         }
 
@@ -601,6 +676,28 @@ impl<'v, 'tcx> Encoder<'v, 'tcx> {
         }
     }
 
+    /// Checks whether the given type is an enum with only unit variants whose
+    /// `PartialEq` implementation is derived. Two values of such a type are
+    /// equal iff they have the same discriminant.
+    pub fn is_fieldless_enum_with_derived_eq(
+        &self,
+        ty: ty::Ty<'tcx>,
+        param_env_def_id: DefId,
+    ) -> bool {
+        let ty = ty.peel_refs();
+        match ty.kind() {
+            ty::TyKind::Adt(adt_def, _) if adt_def.is_enum() && adt_def.is_payloadfree() => {
+                self.env.tcx()
+                    .lang_items()
+                    .structural_peq_trait()
+                    .map_or(false, |trait_def_id| {
+                        self.env.query.type_implements_trait(trait_def_id, ty, param_env_def_id)
+                    })
+            }
+            _ => false,
+        }
+    }
+
     #[tracing::instrument(level = "debug", skip(self), ret)]
     pub fn encode_const_expr(
         &self,
@@ -714,16 +811,19 @@ impl<'v, 'tcx> Encoder<'v, 'tcx> {
                     assert!(substs.is_empty());
 
                     if config::unsafe_core_proof() {
+                        let mut encoded = true;
                         if self.env.query.is_unsafe_function(proc_def_id) {
                             if let Err(error) = self.encode_lifetimes_core_proof(proc_def_id, CheckMode::Both) {
-                                self.register_encoding_error(error);
+                                self.register_procedure_encoding_error(proc_def_id, error);
                                 debug!("Error encoding function: {:?} {}", proc_def_id, CheckMode::Both);
+                                encoded = false;
                             }
                         } else {
                             if config::verify_core_proof() {
                                 if let Err(error) = self.encode_lifetimes_core_proof(proc_def_id, CheckMode::CoreProof) {
-                                    self.register_encoding_error(error);
+                                    self.register_procedure_encoding_error(proc_def_id, error);
                                     debug!("Error encoding function: {:?} {}", proc_def_id, CheckMode::CoreProof);
+                                    encoded = false;
                                 }
                             }
                             if config::verify_specifications() {
@@ -733,11 +833,27 @@ impl<'v, 'tcx> Encoder<'v, 'tcx> {
                                     CheckMode::Specifications
                                 };
                                 if let Err(error) = self.encode_lifetimes_core_proof(proc_def_id, check_mode) {
-                                    self.register_encoding_error(error);
+                                    self.register_procedure_encoding_error(proc_def_id, error);
                                     debug!("Error encoding function: {:?} {}", proc_def_id, check_mode);
+                                    encoded = false;
                                 }
+                            } else {
+                                encoded = false;
                             }
                         }
+                        if encoded {
+                            self.verified_procedures.insert(proc_def_id);
+                        }
+                        continue;
+                    }
+
+                    if config::lazy_assertion_encoding() && self.is_trusted(proc_def_id, None) {
+                        // The contract of a procedure that is not verified is
+                        // encoded on demand, when a verified procedure uses it.
+                        debug!(
+                            "Unverified procedure will be encoded only if used: {:?}",
+                            proc_def_id
+                        );
                         continue;
                     }
 
@@ -751,8 +867,11 @@ impl<'v, 'tcx> Encoder<'v, 'tcx> {
                         // TODO: Make sure that this encoded function does not end up in
                         // the Viper file because that would be unsound.
                         let identity_substs = self.env.query.identity_substs(proc_def_id);
-                        if let Err(error) = self.encode_pure_function_def(proc_def_id, proc_def_id, identity_substs) {
-                            self.register_encoding_error(error);
+                        let encoding_result = time_report::measure(&proc_name, Phase::Encoding, || {
+                            self.encode_pure_function_def(proc_def_id, proc_def_id, identity_substs)
+                        });
+                        if let Err(error) = encoding_result {
+                            self.register_procedure_encoding_error(proc_def_id, error);
                             debug!("Error encoding function: {:?}", proc_def_id);
                             // Skip encoding the function as a method.
                             continue;
@@ -774,16 +893,18 @@ impl<'v, 'tcx> Encoder<'v, 'tcx> {
                         },
                         ProcedureSpecificationKind::Pure |
                         ProcedureSpecificationKind::Impure => {
-                            if let Err(error) = self.encode_procedure(proc_def_id) {
-                                self.register_encoding_error(error);
-                                debug!("Error encoding function: {:?}", proc_def_id);
-                            } else {
-                                match self.finalize_viper_program(proc_name, proc_def_id) {
-                                    Ok(program) => self.programs.push(program),
-                                    Err(error) => {
-                                        self.register_encoding_error(error);
-                                        debug!("Error finalizing program: {:?}", proc_def_id);
-                                    }
+                            let encoding_result = time_report::measure(&proc_name, Phase::Encoding, || {
+                                self.encode_procedure(proc_def_id)?;
+                                self.finalize_viper_program(proc_name.clone(), proc_def_id)
+                            });
+                            match encoding_result {
+                                Ok(program) => {
+                                    self.programs.push(program);
+                                    self.verified_procedures.insert(proc_def_id);
+                                }
+                                Err(error) => {
+                                    self.register_procedure_encoding_error(proc_def_id, error);
+                                    debug!("Error encoding function: {:?}", proc_def_id);
                                 }
                             }
                         }
@@ -137,6 +137,9 @@ pub enum ErrorCtxt {
     /// `assert` Rust terminator in a Rust pure function.
     /// Arguments: the message of the Rust assertion
     PureFunctionAssertTerminator(String),
+    /// A Viper function with `false` precondition that encodes an overflow of an arithmetic
+    /// operation in a specification, when `spec_arithmetic_mode` is `bounded`
+    SpecificationOverflow,
     /// Package a magic wand for the postcondition, at the end of a method
     PackageMagicWandForPostcondition,
     /// Apply a magic wand as a borrow expires, relevant for pledge conditions
@@ -148,6 +151,9 @@ pub enum ErrorCtxt {
     /// A Viper `assert e1 ==> e2` that encodes a weakening of the precondition
     /// of a method implementation of a trait
     AssertMethodPreconditionWeakening,
+    /// A Viper `refute false` after inhaling the precondition of a method,
+    /// which checks that the precondition is satisfiable
+    VacuousPrecondition,
     /// A Viper `assert e1 ==> e2` that encodes a strengthening of the precondition
     /// of a method implementation of a trait.
     AssertMethodPostconditionStrengthening,
@@ -405,6 +411,7 @@ impl<'tcx> ErrorManager<'tcx> {
 
             ("assert.failed:assertion.false", ErrorCtxt::ExhaleMethodPrecondition) => {
                 PrustiError::verification("precondition might not hold.", error_span)
+                    .set_code("P0009")
                     .set_failing_assertion(opt_cause_span)
             }
 
@@ -546,6 +553,13 @@ impl<'tcx> ErrorManager<'tcx> {
                 ).set_failing_assertion(opt_cause_span)
             },
 
+            ("application.precondition:assertion.false", ErrorCtxt::SpecificationOverflow) => {
+                PrustiError::verification(
+                    "arithmetic in the specification might overflow",
+                    error_span
+                ).set_failing_assertion(opt_cause_span)
+            }
+
             ("application.precondition:assertion.false", ErrorCtxt::TypeCast) => {
                 PrustiError::verification(
                     "value might not fit into the target type.",
@@ -705,6 +719,13 @@ impl<'tcx> ErrorManager<'tcx> {
                 )
             }
 
+            ("refute.failed:refutation.true", ErrorCtxt::VacuousPrecondition) => {
+                PrustiError::warning(
+                    "the preconditions of this function are contradictory, so it is verified vacuously",
+                    error_span,
+                )
+            }
+
             (full_err_id, ErrorCtxt::Unexpected) => {
                 PrustiError::internal(
                     format!(
@@ -0,0 +1,61 @@
+//! Printing of the encoded Viper method of a procedure annotated with the
+//! source spans of its statements (the `explain_encoding` flag).
+
+use crate::encoder::Encoder;
+use std::fmt::Write;
+use vir_crate::polymorphic::{self as vir};
+
+/// Returns whether `explain_encoding` selects the procedure with the given
+/// name. Both the path relative to the crate root and the name of the
+/// procedure itself are accepted.
+pub fn is_explained(selected: &str, proc_name: &str) -> bool {
+    proc_name == selected || proc_name.rsplit("::").next() == Some(selected)
+}
+
+/// Renders `method` with each statement preceded by a comment pointing to the
+/// Rust source code it was generated from.
+pub fn explain_method(encoder: &Encoder, method: &vir::CfgMethod) -> String {
+    let error_manager = encoder.error_manager();
+    let position_manager = error_manager.position_manager();
+    let codemap = encoder.env().query.codemap();
+    let mut result = String::new();
+    writeln!(result, "method {}", method.name()).unwrap();
+    writeln!(result, "{{").unwrap();
+    for (index, block) in method.basic_blocks.iter().enumerate() {
+        writeln!(result, "  label {}", method.basic_blocks_labels()[index]).unwrap();
+        for stmt in &block.stmts {
+            let span = statement_position(stmt)
+                .filter(|position| !position.is_default())
+                .and_then(|position| position_manager.get_span(position))
+                .and_then(|span| span.primary_span());
+            if let Some(span) = span {
+                let location = codemap.span_to_diagnostic_string(span);
+                let snippet = codemap.span_to_snippet(span).unwrap_or_default();
+                let first_line = snippet.lines().next().unwrap_or_default().trim();
+                writeln!(result, "    // {location}: {first_line}").unwrap();
+            }
+            for line in stmt.to_string().lines() {
+                writeln!(result, "    {line}").unwrap();
+            }
+        }
+        // The successor is printed with a trailing newline.
+        write!(result, "    {}", block.successor).unwrap();
+    }
+    writeln!(result, "}}").unwrap();
+    result
+}
+
+/// The position of the statements that are associated with a source span.
+fn statement_position(stmt: &vir::Stmt) -> Option<vir::Position> {
+    match stmt {
+        vir::Stmt::Inhale(vir::Inhale { expr }) => Some(expr.pos()),
+        vir::Stmt::Exhale(vir::Exhale { position, .. })
+        | vir::Stmt::Assert(vir::Assert { position, .. })
+        | vir::Stmt::Refute(vir::Refute { position, .. })
+        | vir::Stmt::Fold(vir::Fold { position, .. })
+        | vir::Stmt::Obtain(vir::Obtain { position, .. })
+        | vir::Stmt::PackageMagicWand(vir::PackageMagicWand { position, .. })
+        | vir::Stmt::ApplyMagicWand(vir::ApplyMagicWand { position, .. }) => Some(*position),
+        _ => None,
+    }
+}
@@ -235,6 +235,18 @@ impl<'v, 'tcx: 'v> HighBuiltinFunctionEncoderInterface<'tcx>
             BuiltinFunctionKind::SliceLen { elem_pred_type, .. } => {
                 ("Slice$len".to_string(), vec![elem_pred_type.clone()])
             }
+            BuiltinFunctionKind::ClosureCall {
+                closure_type,
+                arguments_type,
+                return_type,
+            } => (
+                "builtin$closure_call".to_string(),
+                vec![
+                    closure_type.clone(),
+                    arguments_type.clone(),
+                    return_type.clone(),
+                ],
+            ),
         }
     }
 }
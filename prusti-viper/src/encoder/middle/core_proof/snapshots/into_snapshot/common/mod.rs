@@ -75,7 +75,9 @@ pub(super) trait IntoSnapshotLowerer<'p, 'v: 'p, 'tcx: 'v> {
             vir_mid::Expression::Conditional(expression) => {
                 self.conditional_to_snapshot(lowerer, expression, expect_math_bool)
             }
-            // vir_mid::Expression::Quantifier(expression) => self.quantifier_to_snapshot(lowerer, expression, expect_math_bool),
+            vir_mid::Expression::Quantifier(expression) => {
+                self.quantifier_to_snapshot(lowerer, expression, expect_math_bool)
+            }
             // vir_mid::Expression::LetExpr(expression) => self.letexpr_to_snapshot(lowerer, expression, expect_math_bool),
             vir_mid::Expression::FuncApp(expression) => {
                 self.func_app_to_snapshot(lowerer, expression, expect_math_bool)
@@ -434,6 +436,56 @@ pub(super) trait IntoSnapshotLowerer<'p, 'v: 'p, 'tcx: 'v> {
         ))
     }
 
+    fn quantifier_to_snapshot(
+        &mut self,
+        lowerer: &mut Lowerer<'p, 'v, 'tcx>,
+        quantifier: &vir_mid::Quantifier,
+        expect_math_bool: bool,
+    ) -> SpannedEncodingResult<vir_low::Expression> {
+        let mut variables = Vec::new();
+        for variable in &quantifier.variables {
+            variables.push(vir_low::VariableDecl::new(
+                variable.name.clone(),
+                self.type_to_snapshot(lowerer, &variable.ty)?,
+            ));
+        }
+        self.push_bound_variables(&quantifier.variables);
+        let mut triggers = Vec::new();
+        for trigger in &quantifier.triggers {
+            let terms = self.expression_vec_to_snapshot(lowerer, &trigger.terms, false)?;
+            triggers.push(vir_low::Trigger::new(terms));
+        }
+        let body = self.expression_to_snapshot(lowerer, &quantifier.body, true)?;
+        self.pop_bound_variables(&quantifier.variables);
+        let kind = match quantifier.kind {
+            vir_mid::expression::QuantifierKind::ForAll => {
+                vir_low::expression::QuantifierKind::ForAll
+            }
+            vir_mid::expression::QuantifierKind::Exists => {
+                vir_low::expression::QuantifierKind::Exists
+            }
+        };
+        let value = vir_low::Expression::quantifier(
+            kind,
+            variables,
+            triggers,
+            body,
+            quantifier.position,
+        );
+        if expect_math_bool {
+            Ok(value)
+        } else {
+            lowerer.construct_constant_snapshot(&vir_mid::Type::Bool, value, quantifier.position)
+        }
+    }
+
+    /// Marks `variables` as bound by a quantifier while its body is being
+    /// lowered. Lowerers that rename variables (for example, to SSA versions)
+    /// must keep the names of bound variables unchanged.
+    fn push_bound_variables(&mut self, _variables: &[vir_mid::VariableDecl]) {}
+
+    fn pop_bound_variables(&mut self, _variables: &[vir_mid::VariableDecl]) {}
+
     fn func_app_to_snapshot(
         &mut self,
         lowerer: &mut Lowerer<'p, 'v, 'tcx>,
@@ -581,6 +633,18 @@ pub(super) trait IntoSnapshotLowerer<'p, 'v: 'p, 'tcx: 'v> {
                 }
             }
             BuiltinFunc::ConcatSeq => seq(ContainerOpKind::SeqConcat),
+            BuiltinFunc::TailSeq => {
+                assert_eq!(args.len(), 1);
+                Ok(vir_low::Expression::container_op(
+                    ContainerOpKind::SeqDrop,
+                    vir_low::Type::seq(ty_args[0].clone()),
+                    vec![
+                        args[0].clone(),
+                        vir_low::Expression::constant_no_pos(1.into(), vir_low::ty::Type::Int),
+                    ],
+                    app.position,
+                ))
+            }
             BuiltinFunc::SeqLen => {
                 let value = seq(ContainerOpKind::SeqLen)?;
                 lowerer.construct_constant_snapshot(app.get_type(), value, app.position)
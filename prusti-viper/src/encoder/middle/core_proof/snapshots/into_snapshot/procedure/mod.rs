@@ -27,6 +27,9 @@ pub(in super::super::super) use self::traits::{
 struct ProcedureSnapshot {
     old_label: Option<String>,
     deref_to_final: bool,
+    /// Variables bound by the enclosing quantifiers. They are not SSA
+    /// variables and are therefore not versioned.
+    bound_variables: Vec<vir_mid::VariableDecl>,
 }
 
 impl<'p, 'v: 'p, 'tcx: 'v> IntoSnapshotLowerer<'p, 'v, 'tcx> for ProcedureSnapshot {
@@ -35,13 +38,27 @@ impl<'p, 'v: 'p, 'tcx: 'v> IntoSnapshotLowerer<'p, 'v, 'tcx> for ProcedureSnapsh
         lowerer: &mut Lowerer<'p, 'v, 'tcx>,
         variable: &vir_mid::VariableDecl,
     ) -> SpannedEncodingResult<vir_low::VariableDecl> {
-        if let Some(label) = &self.old_label {
+        if self.bound_variables.contains(variable) {
+            Ok(vir_low::VariableDecl::new(
+                variable.name.clone(),
+                self.type_to_snapshot(lowerer, &variable.ty)?,
+            ))
+        } else if let Some(label) = &self.old_label {
             lowerer.snapshot_variable_version_at_label(variable, label)
         } else {
             lowerer.current_snapshot_variable_version(variable)
         }
     }
 
+    fn push_bound_variables(&mut self, variables: &[vir_mid::VariableDecl]) {
+        self.bound_variables.extend(variables.iter().cloned());
+    }
+
+    fn pop_bound_variables(&mut self, variables: &[vir_mid::VariableDecl]) {
+        let len = self.bound_variables.len() - variables.len();
+        self.bound_variables.truncate(len);
+    }
+
     fn func_app_to_snapshot(
         &mut self,
         lowerer: &mut Lowerer<'p, 'v, 'tcx>,
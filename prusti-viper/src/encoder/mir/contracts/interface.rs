@@ -76,7 +76,7 @@ impl<'v, 'tcx: 'v> ContractsEncoderInterface<'tcx> for super::super::super::Enco
                 let specification = self
                     .get_procedure_specs(proc_def_id, substs)
                     .unwrap_or_else(|| typed::ProcedureSpecification::empty(proc_def_id));
-                get_procedure_contract(self, specification, proc_def_id, substs)
+                get_procedure_contract(self, specification, proc_def_id, substs, proc_def_id)
             })
             .clone()
     }
@@ -94,7 +94,13 @@ impl<'v, 'tcx: 'v> ContractsEncoderInterface<'tcx> for super::super::super::Enco
         let specification = self
             .get_procedure_specs_for_call(called_def_id, caller_def_id, call_substs)
             .unwrap_or_else(|| typed::ProcedureSpecification::empty(called_def_id));
-        let contract = get_procedure_contract(self, specification, called_def_id, call_substs)?;
+        let contract = get_procedure_contract(
+            self,
+            specification,
+            called_def_id,
+            call_substs,
+            caller_def_id,
+        )?;
         Ok(contract)
     }
 
@@ -126,17 +132,28 @@ impl<'v, 'tcx: 'v> ContractsEncoderInterface<'tcx> for super::super::super::Enco
         let specification = self
             .get_procedure_specs_for_call(called_def_id, caller_def_id, call_substs)
             .unwrap_or_else(|| typed::ProcedureSpecification::empty(called_def_id));
-        let contract = get_procedure_contract(self, specification, called_def_id, call_substs)?;
+        let contract = get_procedure_contract(
+            self,
+            specification,
+            called_def_id,
+            call_substs,
+            caller_def_id,
+        )?;
         Ok(contract.to_call_site_contract(args, target))
     }
 }
 
+/// Computes the contract of `proc_def_id`. Associated types in its signature
+/// are normalized in the environment of `param_env_def_id`, which is the
+/// procedure itself for definition-site contracts and the caller for call-site
+/// contracts.
 #[tracing::instrument(level = "debug", skip(encoder, specification))]
 fn get_procedure_contract<'p, 'v: 'p, 'tcx: 'v>(
     encoder: &'p Encoder<'v, 'tcx>,
     specification: typed::ProcedureSpecification,
     proc_def_id: DefId,
     substs: SubstsRef<'tcx>,
+    param_env_def_id: DefId,
 ) -> EncodingResult<ProcedureContractMirDef<'tcx>> {
     let env = encoder.env();
     let args_ty: Vec<(mir::Local, ty::Ty<'tcx>)>;
@@ -145,7 +162,10 @@ fn get_procedure_contract<'p, 'v: 'p, 'tcx: 'v>(
     if !env.query.is_closure(proc_def_id) {
         // FIXME: "skip_binder" is most likely wrong
         // FIXME: Replace with FakeMirEncoder.
-        let fn_sig: FnSig = env.query.get_fn_sig(proc_def_id, substs).skip_binder();
+        let fn_sig: FnSig = env
+            .query
+            .get_fn_sig_resolved(proc_def_id, substs, param_env_def_id)
+            .skip_binder();
         if fn_sig.c_variadic {
             error_unsupported!("variadic functions are not supported");
         }
@@ -197,6 +197,13 @@ impl<'v, 'tcx: 'v> PlacesEncoderInterface<'tcx> for super::super::super::Encoder
                             vir_high::Expression::variant_no_pos(expr, variant_index, variant_type);
                         let encoded_field = variant.fields[0].clone();
                         variant_expression.field_no_pos(encoded_field)
+                    } else if mir_type.ty.is_phantom_data() {
+                        // Like in the legacy encoding, which does not encode
+                        // `PhantomData` fields at all.
+                        return Err(SpannedEncodingError::unsupported(
+                            "access to `PhantomData` fields is not supported",
+                            use_span.unwrap_or(declaration_span),
+                        ));
                     } else {
                         let encoded_field =
                             self.encode_field(&parent_type, field, use_span, declaration_span)?;
@@ -218,10 +218,11 @@ impl<'p, 'v, 'tcx> BuiltinFuncAppEncoder<'p, 'v, 'tcx> for super::ProcedureEncod
             "prusti_contracts::Map::<K, V>::len" => {
                 make_builtin_call(self, block_builder, vir_high::BuiltinFunc::MapLen)?
             }
-            "prusti_contracts::Map::<K, V>::contains" => {
+            "prusti_contracts::Map::<K, V>::contains"
+            | "prusti_contracts::Map::<K, V>::contains_key" => {
                 make_builtin_call(self, block_builder, vir_high::BuiltinFunc::MapContains)?
             }
-            "prusti_contracts::Map::<K, V>::lookup" => {
+            "prusti_contracts::Map::<K, V>::lookup" | "prusti_contracts::Map::<K, V>::get" => {
                 make_builtin_call(self, block_builder, vir_high::BuiltinFunc::LookupMap)?
             }
             "prusti_contracts::Seq::<T>::empty" => {
@@ -233,6 +234,9 @@ impl<'p, 'v, 'tcx> BuiltinFuncAppEncoder<'p, 'v, 'tcx> for super::ProcedureEncod
             "prusti_contracts::Seq::<T>::concat" => {
                 make_builtin_call(self, block_builder, vir_high::BuiltinFunc::ConcatSeq)?
             }
+            "prusti_contracts::Seq::<T>::tail" => {
+                make_builtin_call(self, block_builder, vir_high::BuiltinFunc::TailSeq)?
+            }
             "prusti_contracts::Seq::<T>::lookup" => {
                 make_builtin_call(self, block_builder, vir_high::BuiltinFunc::LookupSeq)?
             }
@@ -108,7 +108,8 @@ pub(super) fn encode_procedure<'v, 'tcx: 'v>(
         specification_blocks,
         specification_block_encoding: Default::default(),
         loop_invariant_encoding: Default::default(),
-        check_panics: config::check_panics() && check_mode != CheckMode::CoreProof,
+        check_panics: (config::check_panics() || encoder.is_no_panic(def_id, None))
+            && check_mode != CheckMode::CoreProof,
         locals_without_explicit_allocation,
         used_locals: Default::default(),
         fresh_id_generator: 0,
@@ -1950,6 +1951,7 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
         if false
             || self.try_encode_assert(bb, block, encoded_statements)?
             || self.try_encode_assume(bb, block, encoded_statements)?
+            || self.try_encode_assume_invariant(block)?
             || self.try_encode_ghost_markers(bb, block, encoded_statements)?
             || self.try_encode_specification_function_call(bb, block, encoded_statements)?
         {
@@ -2055,6 +2057,32 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
         Ok(false)
     }
 
+    fn try_encode_assume_invariant(
+        &mut self,
+        block: &mir::BasicBlockData<'tcx>,
+    ) -> SpannedEncodingResult<bool> {
+        for stmt in &block.statements {
+            if let mir::StatementKind::Assign(box (
+                _,
+                mir::Rvalue::Aggregate(box mir::AggregateKind::Closure(cl_def_id, _), _),
+            )) = stmt.kind
+            {
+                let Some(assumption) = self.encoder.get_prusti_invariant_assumption(cl_def_id)
+                else {
+                    return Ok(false);
+                };
+                let span = self
+                    .encoder
+                    .get_definition_span(assumption.assumption.to_def_id());
+                return Err(SpannedEncodingError::unsupported(
+                    "prusti_assume_invariant! is not supported in the core proof encoding",
+                    span,
+                ));
+            }
+        }
+        Ok(false)
+    }
+
     fn try_encode_ghost_markers(
         &mut self,
         _bb: mir::BasicBlock,
@@ -551,6 +551,23 @@ impl<'p, 'v: 'p, 'tcx: 'v> ExpressionBackwardInterpreter<'p, 'v, 'tcx> {
             ))
         };
 
+        // `Ghost<T>` is encoded as `T`, so dereferencing a ghost variable, for
+        // example in a `prusti_assert!` inside a ghost block, is the identity.
+        if matches!(
+            proc_name,
+            "std::ops::Deref::deref"
+                | "core::ops::Deref::deref"
+                | "std::ops::DerefMut::deref_mut"
+                | "core::ops::DerefMut::deref_mut"
+        ) {
+            if let ty::TyKind::Adt(adt_def, _) = substs.type_at(0).kind()
+                && self.encoder.env().name.get_absolute_item_name(adt_def.did())
+                    == "prusti_contracts::Ghost"
+            {
+                return subst_with(encoded_args[0].clone());
+            }
+        }
+
         if let Some(proc_name) = proc_name.strip_prefix("prusti_contracts::Map::<K, V>::") {
             assert_eq!(type_arguments.len(), 2);
 
@@ -562,11 +579,100 @@ impl<'p, 'v: 'p, 'tcx: 'v> ExpressionBackwardInterpreter<'p, 'v, 'tcx> {
                 "empty" => (EmptyMap, map_type),
                 "insert" => (UpdateMap, map_type),
                 "len" => (MapLen, Type::int(Int::Unbounded)),
-                "lookup" => (LookupMap, val_type),
+                "lookup" | "get" => (LookupMap, val_type),
                 "delete" => unimplemented!(),
-                "contains" => (MapContains, Type::bool()),
+                "contains" | "contains_key" => (MapContains, Type::bool()),
                 _ => unreachable!("no further Map functions"),
             });
+        } else if let Some(proc_name) =
+            proc_name.strip_prefix("std::collections::HashMap::<K, V, S>::")
+        {
+            // `HashMap` is encoded as a map when `is_hash_map` holds, in which
+            // case its pure accessors become map operations. The map itself is
+            // dereferenced when lowering the operations, the key is not.
+            if let Some(Type::Reference(Reference {
+                target_type: box Type::Map(_),
+                ..
+            })) = encoded_args.first().map(vir_high::Expression::get_type)
+            {
+                let key_type = type_arguments[0].clone();
+                let map_type_arguments = type_arguments[..2].to_vec();
+                let map_op = |function, arguments, return_type| {
+                    vir_high::Expression::builtin_func_app_no_pos(
+                        function,
+                        map_type_arguments.clone(),
+                        arguments,
+                        return_type,
+                    )
+                };
+                let key_args = || -> SpannedEncodingResult<Vec<vir_high::Expression>> {
+                    // Only lookups with the key type itself are supported, not
+                    // with other types that the key can be borrowed as.
+                    if type_arguments.get(3) != Some(&key_type) {
+                        return Err(SpannedEncodingError::unsupported(
+                            format!("`HashMap::{proc_name}` with a key of a different type"),
+                            span,
+                        ));
+                    }
+                    Ok(vec![
+                        encoded_args[0].clone(),
+                        vir_high::Expression::deref_no_pos(
+                            encoded_args[1].clone(),
+                            key_type.clone(),
+                        ),
+                    ])
+                };
+                match proc_name {
+                    "contains_key" => {
+                        return subst_with(map_op(MapContains, key_args()?, Type::bool()));
+                    }
+                    "get" => {
+                        // `get` returns `Some(&value)` if the key is in the map
+                        // and `None` otherwise.
+                        let option_type = encoded_lhs.get_type().clone();
+                        let Type::Enum(Enum { arguments, .. }) = &option_type else {
+                            unreachable!("`HashMap::get` returns an `Option`");
+                        };
+                        let reference_type = arguments[0].clone();
+                        let Type::Reference(Reference {
+                            target_type: box value_type,
+                            ..
+                        }) = &reference_type else {
+                            unreachable!("`HashMap::get` returns an `Option<&V>`");
+                        };
+                        let value = map_op(LookupMap, key_args()?, value_type.clone());
+                        let some = vir_high::Expression::constructor_no_pos(
+                            option_type.clone().variant("Some".into()),
+                            vec![vir_high::Expression::addr_of_no_pos(value, reference_type)],
+                        );
+                        let none = vir_high::Expression::constructor_no_pos(
+                            option_type.clone().variant("None".into()),
+                            vec![],
+                        );
+                        return subst_with(vir_high::Expression::conditional_no_pos(
+                            map_op(MapContains, key_args()?, Type::bool()),
+                            some,
+                            none,
+                        ));
+                    }
+                    "len" => {
+                        // The length of a map is an unbounded integer, which
+                        // `NewInt` converts to the `usize` returned by `len`.
+                        let len = map_op(
+                            MapLen,
+                            encoded_args.into(),
+                            Type::int(Int::Unbounded),
+                        );
+                        return subst_with(vir_high::Expression::builtin_func_app_no_pos(
+                            NewInt,
+                            vec![],
+                            vec![len],
+                            encoded_lhs.get_type().clone(),
+                        ));
+                    }
+                    _ => {}
+                }
+            }
         } else if let Some(proc_name) = proc_name.strip_prefix("prusti_contracts::Seq::<T>::") {
             assert_eq!(type_arguments.len(), 1);
 
@@ -579,6 +685,7 @@ impl<'p, 'v: 'p, 'tcx: 'v> ExpressionBackwardInterpreter<'p, 'v, 'tcx> {
                 "len" => (SeqLen, Type::Int(vir_high::ty::Int::Unbounded)),
                 "lookup" => (LookupSeq, elem_type),
                 "concat" => (ConcatSeq, seq_type),
+                "tail" => (TailSeq, seq_type),
                 _ => unreachable!("no further Seq functions"),
             });
         } else if let Some(proc_name) = proc_name.strip_prefix("prusti_contracts::Int::") {
@@ -20,7 +20,8 @@ use crate::{
             types::MirTypeEncoderInterface,
         },
         mir_encoder::{
-            MirEncoder, PlaceEncoder, PlaceEncoding, PRECONDITION_LABEL, WAND_LHS_LABEL,
+            MirEncoder, PlaceEncoder, PlaceEncoding, LOOP_ITERATIONS_PLACEHOLDER,
+            snapshot_label, PRECONDITION_LABEL, PREV_ITERATION_LABEL, WAND_LHS_LABEL,
         },
         snapshot::interface::SnapshotEncoderInterface,
         Encoder,
@@ -28,7 +29,7 @@ use crate::{
     error_unsupported,
 };
 use log::{debug, trace};
-use prusti_common::vir_local;
+use prusti_common::{config, vir_local};
 use prusti_interface::environment::mir_utils::SliceOrArrayRef;
 use prusti_rustc_interface::{
     hir::def_id::DefId,
@@ -143,6 +144,126 @@ impl<'p, 'v: 'p, 'tcx: 'v> PureFunctionBackwardInterpreter<'p, 'v, 'tcx> {
         }
     }
 
+    /// Encodes an argument of a function call. A raw pointer is encoded as the
+    /// address it points to, which can only be compared (e.g. by `std::ptr::eq`).
+    fn encode_call_argument(&self, operand: &mir::Operand<'tcx>) -> EncodingResult<vir::Expr> {
+        if let &mir::Operand::Move(place) | &mir::Operand::Copy(place) = operand {
+            let (encoded_place, ty, _) = self.encode_place(place)?;
+            if let ty::TyKind::RawPtr(ty::TypeAndMut { ty: target_ty, .. }) = ty.kind() {
+                let ref_field = self.encoder.encode_dereference_field(*target_ty)?;
+                return Ok(encoded_place.field(ref_field));
+            }
+        }
+        self.mir_encoder.encode_operand_expr(operand)
+    }
+
+    /// Returns true iff `ty` is the mathematical integer type `prusti_contracts::Int`.
+    fn is_unbounded_int(&self, ty: ty::Ty<'tcx>) -> bool {
+        ty.ty_adt_def().map_or(false, |adt_def| {
+            self.encoder.env().name.get_absolute_item_name(adt_def.did())
+                == "prusti_contracts::Int"
+        })
+    }
+
+    /// If `arg_ty` is a (reference to a) newtype marked with
+    /// `#[prusti::transparent]` and `result_ty` is a reference to its only
+    /// field, returns the name and the type of that field. This allows the
+    /// accessor generated by `#[transparent]`, as well as `Deref`, `AsRef` and
+    /// `Borrow` of such newtypes, to be used in pure code; the collector checks
+    /// that their implementations only return a reference to the field.
+    fn transparent_newtype_field(
+        &self,
+        arg_ty: ty::Ty<'tcx>,
+        result_ty: ty::Ty<'tcx>,
+    ) -> Option<(String, ty::Ty<'tcx>)> {
+        if let ty::TyKind::Adt(adt_def, substs) = arg_ty.peel_refs().kind() {
+            let is_transparent = self
+                .encoder
+                .get_type_specs(adt_def.did())
+                .map_or(false, |specs| specs.transparent);
+            if is_transparent && adt_def.is_struct() {
+                let tcx = self.encoder.env().tcx();
+                let field = adt_def.all_fields().next()?;
+                let field_ty = field.ty(tcx, substs);
+                if matches!(
+                    result_ty.kind(),
+                    ty::TyKind::Ref(_, target_ty, _) if *target_ty == field_ty
+                ) {
+                    return Some((field.ident(tcx).to_string(), field_ty));
+                }
+            }
+        }
+        None
+    }
+
+    /// Encodes the bit counting methods of the primitive integer types (e.g.
+    /// `count_ones` or `leading_zeros`) by inspecting the bits of `value` one
+    /// at a time. This relies on the bitvector encoding of integers.
+    fn encode_bit_count(
+        &self,
+        method: &str,
+        value: vir::Expr,
+        ty: ty::Ty<'tcx>,
+    ) -> EncodingResult<vir::Expr> {
+        if !config::encode_bitvectors() {
+            error_unsupported!(
+                "support for '{}' is experimental and disabled by default; use \
+                `encode_bitvectors` to enable it",
+                method
+            );
+        }
+        let width = match ty.kind() {
+            ty::TyKind::Int(int_ty) => int_ty.bit_width(),
+            ty::TyKind::Uint(uint_ty) => uint_ty.bit_width(),
+            _ => None,
+        };
+        let Some(width) = width else {
+            error_unsupported!("'{}' is not supported for values of type '{}'", method, ty);
+        };
+        let width = width as u32;
+        let is_set = |index: u32| {
+            let shifted = vir::Expr::bin_op(vir::BinaryOpKind::LShr, value.clone(), index.into());
+            let bit = vir::Expr::bin_op(vir::BinaryOpKind::BitAnd, shifted, 1u32.into());
+            vir::Expr::eq_cmp(bit, 1u32.into())
+        };
+        let count = |ones: bool| {
+            (0..width)
+                .map(|index| {
+                    let (if_set, if_unset) = if ones { (1u32, 0u32) } else { (0u32, 1u32) };
+                    vir::Expr::ite(is_set(index), if_set.into(), if_unset.into())
+                })
+                .reduce(vir::Expr::add)
+                .unwrap()
+        };
+        // The number of consecutive bits equal to `ones`, starting from the
+        // most significant bit if `leading` holds and from the least
+        // significant one otherwise.
+        let run = |ones: bool, leading: bool| {
+            let mut indices: Vec<u32> = (0..width).collect();
+            if !leading {
+                indices.reverse();
+            }
+            indices.into_iter().fold(vir::Expr::from(width), |rest, index| {
+                let ends_run = if ones {
+                    vir::Expr::not(is_set(index))
+                } else {
+                    is_set(index)
+                };
+                let length = if leading { width - 1 - index } else { index };
+                vir::Expr::ite(ends_run, length.into(), rest)
+            })
+        };
+        Ok(match method {
+            "count_ones" => count(true),
+            "count_zeros" => count(false),
+            "leading_zeros" => run(false, true),
+            "leading_ones" => run(true, true),
+            "trailing_zeros" => run(false, false),
+            "trailing_ones" => run(true, false),
+            _ => unreachable!("{}", method),
+        })
+    }
+
     fn postprocess_place_encoding(
         &self,
         place_encoding: PlaceEncoding<'tcx>,
@@ -423,7 +544,7 @@ impl<'p, 'v: 'p, 'tcx: 'v> BackwardMirInterpreter<'tcx>
                             self.encode_place(destination).with_span(span)?;
                         let encoded_args: Vec<vir::Expr> = args
                             .iter()
-                            .map(|arg| self.mir_encoder.encode_operand_expr(arg))
+                            .map(|arg| self.encode_call_argument(arg))
                             .collect::<Result<_, _>>()
                             .with_span(span)?;
 
@@ -442,6 +563,40 @@ impl<'p, 'v: 'p, 'tcx: 'v> BackwardMirInterpreter<'tcx>
                                 state
                             }
 
+                            "prusti_contracts::old_prev_iteration" => {
+                                assert_eq!(args.len(), 1);
+                                let encoded_rhs = self.mir_encoder.encode_old_expr(
+                                    vir::Expr::snap_app(encoded_args[0].clone()),
+                                    PREV_ITERATION_LABEL,
+                                );
+                                let mut state = states[&target_block].clone();
+                                state.substitute_value(&encoded_lhs, encoded_rhs);
+                                state
+                            }
+
+                            "prusti_contracts::old_at" => {
+                                assert_eq!(args.len(), 1);
+                                let label = snapshot_label(tcx, call_substs.type_at(0));
+                                let encoded_rhs = self.mir_encoder.encode_old_expr(
+                                    vir::Expr::snap_app(encoded_args[0].clone()),
+                                    &label,
+                                );
+                                let mut state = states[&target_block].clone();
+                                state.substitute_value(&encoded_lhs, encoded_rhs);
+                                state
+                            }
+
+                            "prusti_contracts::iterations" => {
+                                assert!(args.is_empty());
+                                let encoded_rhs = vir::Expr::local(vir::LocalVar::new(
+                                    LOOP_ITERATIONS_PLACEHOLDER,
+                                    vir::Type::Int,
+                                ));
+                                let mut state = states[&target_block].clone();
+                                state.substitute_value(&encoded_lhs, encoded_rhs);
+                                state
+                            }
+
                             "prusti_contracts::before_expiry" => {
                                 trace!("Encoding before_expiry expression {:?}", args[0]);
                                 assert_eq!(args.len(), 1);
@@ -484,6 +639,47 @@ impl<'p, 'v: 'p, 'tcx: 'v> BackwardMirInterpreter<'tcx>
                                 state
                             }
 
+                            "std::cmp::PartialEq::eq"
+                            | "core::cmp::PartialEq::eq"
+                            | "std::cmp::PartialEq::ne"
+                            | "core::cmp::PartialEq::ne"
+                                if self.encoder.is_fieldless_enum_with_derived_eq(
+                                    self.mir_encoder.get_operand_ty(&args[0]),
+                                    self.caller_def_id,
+                                ) =>
+                            {
+                                assert_eq!(args.len(), 2);
+                                // Comparisons such as `result == MyEnum::Variant` are
+                                // encoded as comparisons of the discriminants.
+                                let enum_ty = self.mir_encoder.get_operand_ty(&args[0]).peel_refs();
+                                let ty::TyKind::Adt(adt_def, _) = enum_ty.kind() else {
+                                    unreachable!()
+                                };
+                                let deref_field = self
+                                    .encoder
+                                    .encode_dereference_field(enum_ty)
+                                    .with_span(span)?;
+                                let discriminant = |arg: &vir::Expr| -> vir::Expr {
+                                    if adt_def.variants().len() > 1 {
+                                        arg.clone()
+                                            .field(deref_field.clone())
+                                            .field(self.encoder.encode_discriminant_field())
+                                    } else {
+                                        0u32.into()
+                                    }
+                                };
+                                let lhs_discriminant = discriminant(&encoded_args[0]);
+                                let rhs_discriminant = discriminant(&encoded_args[1]);
+                                let encoded_rhs = if full_func_proc_name.ends_with("::eq") {
+                                    vir::Expr::eq_cmp(lhs_discriminant, rhs_discriminant)
+                                } else {
+                                    vir::Expr::ne_cmp(lhs_discriminant, rhs_discriminant)
+                                };
+                                let mut state = states[&target_block].clone();
+                                state.substitute_value(&encoded_lhs, encoded_rhs);
+                                state
+                            }
+
                             "core::slice::<impl [T]>::len" => {
                                 assert_eq!(args.len(), 1);
                                 let slice_ty = self.mir_encoder.get_operand_ty(&args[0]);
@@ -497,6 +693,288 @@ impl<'p, 'v: 'p, 'tcx: 'v> BackwardMirInterpreter<'tcx>
                                 state
                             }
 
+                            "std::ptr::eq" | "core::ptr::eq" => {
+                                assert_eq!(args.len(), 2);
+                                // Reference identity: the arguments are encoded as the
+                                // addresses they point to, which are compared instead of the
+                                // values stored at them.
+                                let encoded_rhs = vir::Expr::eq_cmp(
+                                    encoded_args[0].clone(),
+                                    encoded_args[1].clone(),
+                                );
+                                let mut state = states[&target_block].clone();
+                                state.substitute_value(&encoded_lhs, encoded_rhs);
+                                state
+                            }
+
+                            "std::mem::size_of" | "core::mem::size_of"
+                            | "std::mem::align_of" | "core::mem::align_of" => {
+                                assert!(args.is_empty());
+                                // The layout of a type is fixed for the compilation target,
+                                // so its size and alignment are encoded as constants.
+                                let layout_ty = call_substs.type_at(0);
+                                let param_env = tcx.param_env(self.caller_def_id);
+                                let layout = match tcx.layout_of(param_env.and(layout_ty)) {
+                                    Ok(layout_of) => layout_of.layout,
+                                    Err(_) => {
+                                        return Err(SpannedEncodingError::unsupported(
+                                            format!(
+                                                "the layout of type `{layout_ty}` is not known"
+                                            ),
+                                            span,
+                                        ));
+                                    }
+                                };
+                                let bytes = if full_func_proc_name.ends_with("size_of") {
+                                    layout.size().bytes()
+                                } else {
+                                    layout.align().abi.bytes()
+                                };
+                                let mut state = states[&target_block].clone();
+                                state.substitute_value(&encoded_lhs, bytes.into());
+                                state
+                            }
+
+                            "prusti_contracts::Int::new" | "prusti_contracts::Int::new_usize" => {
+                                assert_eq!(args.len(), 1);
+                                let mut state = states[&target_block].clone();
+                                state.substitute_value(&encoded_lhs, encoded_args[0].clone());
+                                state
+                            }
+
+                            "std::ops::Add::add" | "core::ops::Add::add"
+                            | "std::ops::Sub::sub" | "core::ops::Sub::sub"
+                            | "std::ops::Mul::mul" | "core::ops::Mul::mul"
+                            | "std::ops::Div::div" | "core::ops::Div::div"
+                            | "std::ops::Rem::rem" | "core::ops::Rem::rem"
+                            | "std::ops::Neg::neg" | "core::ops::Neg::neg"
+                                if self.is_unbounded_int(
+                                    self.mir_encoder.get_operand_ty(&args[0]),
+                                ) =>
+                            {
+                                // Operations on `Int` are mathematical operations.
+                                let operand = |index: usize| encoded_args[index].clone();
+                                let encoded_rhs = match full_func_proc_name.rsplit("::").next() {
+                                    Some("add") => vir::Expr::add(operand(0), operand(1)),
+                                    Some("sub") => vir::Expr::sub(operand(0), operand(1)),
+                                    Some("mul") => vir::Expr::mul(operand(0), operand(1)),
+                                    Some("div") => vir::Expr::div(operand(0), operand(1)),
+                                    Some("rem") => vir::Expr::modulo(operand(0), operand(1)),
+                                    Some("neg") => vir::Expr::minus(operand(0)),
+                                    _ => unreachable!("{}", full_func_proc_name),
+                                };
+                                let mut state = states[&target_block].clone();
+                                state.substitute_value(&encoded_lhs, encoded_rhs);
+                                state
+                            }
+
+                            "std::cmp::min" | "core::cmp::min"
+                            | "std::cmp::max" | "core::cmp::max"
+                            | "std::cmp::Ord::min" | "core::cmp::Ord::min"
+                            | "std::cmp::Ord::max" | "core::cmp::Ord::max"
+                                if self.mir_encoder.get_operand_ty(&args[0]).is_integral() =>
+                            {
+                                assert_eq!(args.len(), 2);
+                                // When both are equal, `min` returns the first argument and
+                                // `max` the second one, like in the standard library.
+                                let lhs = encoded_args[0].clone();
+                                let rhs = encoded_args[1].clone();
+                                let lhs_le_rhs = vir::Expr::le_cmp(lhs.clone(), rhs.clone());
+                                let encoded_rhs = if full_func_proc_name.ends_with("max") {
+                                    vir::Expr::ite(lhs_le_rhs, rhs, lhs)
+                                } else {
+                                    vir::Expr::ite(lhs_le_rhs, lhs, rhs)
+                                };
+                                let mut state = states[&target_block].clone();
+                                state.substitute_value(&encoded_lhs, encoded_rhs);
+                                state
+                            }
+
+                            // `Box<T>` is transparent in pure code: a box is encoded
+                            // like a reference to its content.
+                            "std::boxed::Box::<T>::new" | "alloc::boxed::Box::<T>::new" => {
+                                assert_eq!(args.len(), 1);
+                                let (encoded_content, is_value) =
+                                    self.encode_operand(&args[0]).with_span(span)?;
+                                let mut state = states[&target_block].clone();
+                                if is_value {
+                                    let boxed_ty = ty.boxed_ty();
+                                    let content_field = self
+                                        .encoder
+                                        .encode_dereference_field(boxed_ty)
+                                        .with_span(span)?;
+                                    let content_value = self
+                                        .encoder
+                                        .encode_value_expr(
+                                            encoded_lhs.clone().field(content_field),
+                                            boxed_ty,
+                                        )
+                                        .with_span(span)?;
+                                    state.substitute_value(&content_value, encoded_content);
+                                } else {
+                                    state.substitute_value(&encoded_lhs, encoded_content.addr_of());
+                                }
+                                state
+                            }
+
+                            "std::ops::Deref::deref"
+                            | "core::ops::Deref::deref"
+                            | "std::convert::AsRef::as_ref"
+                            | "core::convert::AsRef::as_ref"
+                            | "std::borrow::Borrow::borrow"
+                            | "core::borrow::Borrow::borrow"
+                                if is_box_deref(self.mir_encoder.get_operand_ty(&args[0]), ty) =>
+                            {
+                                assert_eq!(args.len(), 1);
+                                let box_ty = self.mir_encoder.get_operand_ty(&args[0]).peel_refs();
+                                let content_field = self
+                                    .encoder
+                                    .encode_dereference_field(box_ty.boxed_ty())
+                                    .with_span(span)?;
+                                let encoded_rhs = encoded_args[0].clone().field(content_field);
+                                let mut state = states[&target_block].clone();
+                                state.substitute_value(&encoded_lhs, encoded_rhs.addr_of());
+                                state
+                            }
+
+                            "std::ops::Deref::deref"
+                            | "core::ops::Deref::deref"
+                            | "std::convert::AsRef::as_ref"
+                            | "core::convert::AsRef::as_ref"
+                            | "std::borrow::Borrow::borrow"
+                            | "core::borrow::Borrow::borrow"
+                            | "prusti_contracts::Transparent::field"
+                                if self
+                                    .transparent_newtype_field(
+                                        self.mir_encoder.get_operand_ty(&args[0]),
+                                        ty,
+                                    )
+                                    .is_some() =>
+                            {
+                                assert_eq!(args.len(), 1);
+                                let (field_name, field_ty) = self
+                                    .transparent_newtype_field(
+                                        self.mir_encoder.get_operand_ty(&args[0]),
+                                        ty,
+                                    )
+                                    .unwrap();
+                                let field = self
+                                    .encoder
+                                    .encode_struct_field(&field_name, field_ty)
+                                    .with_span(span)?;
+                                let encoded_rhs = encoded_args[0].clone().field(field);
+                                let mut state = states[&target_block].clone();
+                                state.substitute_value(&encoded_lhs, encoded_rhs.addr_of());
+                                state
+                            }
+
+                            _ if is_bit_count_method(full_func_proc_name) => {
+                                assert_eq!(args.len(), 1);
+                                let method = full_func_proc_name.rsplit("::").next().unwrap();
+                                let encoded_rhs = self
+                                    .encode_bit_count(
+                                        method,
+                                        encoded_args[0].clone(),
+                                        self.mir_encoder.get_operand_ty(&args[0]),
+                                    )
+                                    .with_span(span)?;
+                                let mut state = states[&target_block].clone();
+                                state.substitute_value(&encoded_lhs, encoded_rhs);
+                                state
+                            }
+
+                            "std::ops::RangeInclusive::<Idx>::new"
+                            | "core::ops::RangeInclusive::<Idx>::new"
+                                if call_substs.type_at(0).is_integral() =>
+                            {
+                                assert_eq!(args.len(), 2);
+                                let idx_ty = call_substs.type_at(0);
+                                let bool_ty = tcx.types.bool;
+                                let mut state = states[&target_block].clone();
+                                for (field_name, field_ty, value) in [
+                                    ("start", idx_ty, encoded_args[0].clone()),
+                                    ("end", idx_ty, encoded_args[1].clone()),
+                                    ("exhausted", bool_ty, false.into()),
+                                ] {
+                                    let encoded_field = self
+                                        .encoder
+                                        .encode_struct_field_value(
+                                            encoded_lhs.clone(),
+                                            field_name,
+                                            field_ty,
+                                        )
+                                        .with_span(span)?;
+                                    state.substitute_value(&encoded_field, value);
+                                }
+                                state
+                            }
+
+                            "std::ops::RangeInclusive::<Idx>::start"
+                            | "core::ops::RangeInclusive::<Idx>::start"
+                            | "std::ops::RangeInclusive::<Idx>::end"
+                            | "core::ops::RangeInclusive::<Idx>::end" => {
+                                assert_eq!(args.len(), 1);
+                                let field_name = if full_func_proc_name.ends_with("start") {
+                                    "start"
+                                } else {
+                                    "end"
+                                };
+                                let encoded_field = self
+                                    .encoder
+                                    .encode_struct_field(field_name, call_substs.type_at(0))
+                                    .with_span(span)?;
+                                let encoded_rhs = encoded_args[0].clone().field(encoded_field);
+                                let mut state = states[&target_block].clone();
+                                state.substitute_value(&encoded_lhs, encoded_rhs.addr_of());
+                                state
+                            }
+
+                            "std::ops::Range::<Idx>::contains"
+                            | "core::ops::Range::<Idx>::contains"
+                            | "std::ops::RangeInclusive::<Idx>::contains"
+                            | "core::ops::RangeInclusive::<Idx>::contains"
+                                if call_substs.type_at(0).is_integral() =>
+                            {
+                                assert_eq!(args.len(), 2);
+                                let idx_ty = call_substs.type_at(0);
+                                let range = &encoded_args[0];
+                                let field = |name| {
+                                    self.encoder
+                                        .encode_struct_field_value(range.clone(), name, idx_ty)
+                                        .with_span(span)
+                                };
+                                let start = field("start")?;
+                                let end = field("end")?;
+                                let item_ty = call_substs.type_at(1);
+                                let item = self
+                                    .encoder
+                                    .encode_value_expr(encoded_args[1].clone(), item_ty)
+                                    .with_span(span)?;
+                                let below_end = if full_func_proc_name.contains("RangeInclusive") {
+                                    // An exhausted inclusive range no longer contains its end.
+                                    let exhausted = self
+                                        .encoder
+                                        .encode_struct_field_value(
+                                            range.clone(),
+                                            "exhausted",
+                                            tcx.types.bool,
+                                        )
+                                        .with_span(span)?;
+                                    vir::Expr::ite(
+                                        exhausted,
+                                        vir::Expr::lt_cmp(item.clone(), end.clone()),
+                                        vir::Expr::le_cmp(item.clone(), end),
+                                    )
+                                } else {
+                                    vir::Expr::lt_cmp(item.clone(), end)
+                                };
+                                let encoded_rhs =
+                                    vir::Expr::and(vir::Expr::le_cmp(start, item), below_end);
+                                let mut state = states[&target_block].clone();
+                                state.substitute_value(&encoded_lhs, encoded_rhs);
+                                state
+                            }
+
                             "std::ops::Index::index" | "core::ops::Index::index" => {
                                 assert_eq!(args.len(), 2);
                                 trace!("slice::index(args={:?}, encoded_args={:?}, ty={:?}, encoded_lhs={:?})", args, encoded_args, ty, encoded_lhs);
@@ -523,12 +1001,11 @@ impl<'p, 'v: 'p, 'tcx: 'v> BackwardMirInterpreter<'tcx>
                                     tcx.mk_ty_from_kind(ty::TyKind::Uint(ty::UintTy::Usize));
                                 let start = match &*idx_ident {
                                     "std::ops::Range" | "core::ops::Range" |
-                                    "std::ops::RangeFrom" | "core::ops::RangeFrom" =>
+                                    "std::ops::RangeFrom" | "core::ops::RangeFrom" |
+                                    // Inclusive ranges are built with `RangeInclusive::new`,
+                                    // whose fields are modelled above.
+                                    "std::ops::RangeInclusive" | "core::ops::RangeInclusive" =>
                                         self.encoder.encode_struct_field_value(encoded_idx.clone(), "start", usize_ty).with_span(span)?,
-                                    // See procedure_encoder.rs
-                                    "std::ops::RangeInclusive" | "core::ops::RangeInclusive" => return Err(
-                                        SpannedEncodingError::unsupported("slicing with RangeInclusive (e.g. [x..=y]) currently not supported".to_string(), span)
-                                    ),
                                     "std::ops::RangeTo" | "core::ops::RangeTo" |
                                     "std::ops::RangeFull" | "core::ops::RangeFull" |
                                     "std::ops::RangeToInclusive" | "core::ops::RangeToInclusive" => vir::Expr::from(0u32),
@@ -538,9 +1015,7 @@ impl<'p, 'v: 'p, 'tcx: 'v> BackwardMirInterpreter<'tcx>
                                     "std::ops::Range" | "core::ops::Range" |
                                     "std::ops::RangeTo" | "core::ops::RangeTo" =>
                                         self.encoder.encode_struct_field_value(encoded_idx.clone(), "end", usize_ty).with_span(span)?,
-                                    "std::ops::RangeInclusive" | "core::ops::RangeInclusive" => return Err(
-                                        SpannedEncodingError::unsupported("slicing with RangeInclusive (e.g. [x..=y]) currently not supported".to_string(), span)
-                                    ),
+                                    "std::ops::RangeInclusive" | "core::ops::RangeInclusive" |
                                     "std::ops::RangeToInclusive" | "core::ops::RangeToInclusive" => {
                                         let end_expr = self.encoder.encode_struct_field_value(encoded_idx.clone(), "end", usize_ty).with_span(span)?;
                                         vir::Expr::add(end_expr, vir::Expr::from(1u32))
@@ -560,6 +1035,15 @@ impl<'p, 'v: 'p, 'tcx: 'v> BackwardMirInterpreter<'tcx>
                                     _ => unreachable!("{}", idx_ident)
                                 };
 
+                                // The preconditions of the slicing function check that
+                                // the range is within the bounds of the sequence.
+                                let pos = self.encoder.error_manager().register_error(
+                                    span,
+                                    ErrorCtxt::SliceRangeBoundsCheckAssert(
+                                        "the range may be out of bounds when slicing".to_string(),
+                                    ),
+                                    self.caller_def_id,
+                                );
                                 let slice_expr = self
                                     .encoder
                                     .encode_snapshot_slicing(
@@ -569,7 +1053,8 @@ impl<'p, 'v: 'p, 'tcx: 'v> BackwardMirInterpreter<'tcx>
                                         start,
                                         end,
                                     )
-                                    .with_span(span)?;
+                                    .with_span(span)?
+                                    .set_default_pos(pos);
 
                                 let mut state = states[&target_block].clone();
                                 state.substitute_value(&encoded_lhs, slice_expr);
@@ -583,7 +1068,10 @@ impl<'p, 'v: 'p, 'tcx: 'v> BackwardMirInterpreter<'tcx>
                             | "prusti_contracts::specification_entailment"
                             | "prusti_contracts::call_description"
                             | "prusti_contracts::snap"
-                            | "prusti_contracts::snapshot_equality" => {
+                            | "prusti_contracts::snapshot_equality"
+                            | "prusti_contracts::disjoint"
+                            | "prusti_contracts::result_borrows_from"
+                            | "prusti_contracts::lazy_iterator" => {
                                 let expr = self.encoder.encode_prusti_operation(
                                     full_func_proc_name,
                                     span,
@@ -596,6 +1084,55 @@ impl<'p, 'v: 'p, 'tcx: 'v> BackwardMirInterpreter<'tcx>
                                 state
                             }
 
+                            // Calls of closures and of generic `Fn` arguments. Closure
+                            // bodies are not encoded as pure functions, so the result
+                            // of such a call is an uninterpreted function of the
+                            // snapshots of the callee and of the tupled arguments.
+                            "std::ops::Fn::call" | "core::ops::Fn::call"
+                                if matches!(
+                                    call_substs.type_at(0).kind(),
+                                    ty::TyKind::Closure(..) | ty::TyKind::Param(_)
+                                ) =>
+                            {
+                                let closure_type = self
+                                    .encoder
+                                    .encode_snapshot_type(call_substs.type_at(0))
+                                    .with_span(span)?;
+                                let arguments_type = self
+                                    .encoder
+                                    .encode_snapshot_type(call_substs.type_at(1))
+                                    .with_span(span)?;
+                                let return_type =
+                                    self.encoder.encode_snapshot_type(ty).with_span(span)?;
+                                let (function_name, type_arguments) =
+                                    self.encoder.encode_builtin_function_use(
+                                        BuiltinFunctionKind::ClosureCall {
+                                            closure_type: closure_type.clone(),
+                                            arguments_type: arguments_type.clone(),
+                                            return_type: return_type.clone(),
+                                        },
+                                    );
+                                let pos = self.encoder.error_manager().register_error(
+                                    span,
+                                    ErrorCtxt::PureFunctionCall,
+                                    self.caller_def_id,
+                                );
+                                let encoded_rhs = vir::Expr::func_app(
+                                    function_name,
+                                    type_arguments,
+                                    encoded_args.into_iter().map(vir::Expr::snap_app).collect(),
+                                    vec![
+                                        vir::LocalVar::new("closure", closure_type),
+                                        vir::LocalVar::new("arguments", arguments_type),
+                                    ],
+                                    return_type,
+                                    pos,
+                                );
+                                let mut state = states[&target_block].clone();
+                                state.substitute_value(&encoded_lhs, encoded_rhs);
+                                state
+                            }
+
                             // simple function call
                             _ => {
                                 let (called_def_id, call_substs) = self
@@ -616,12 +1153,38 @@ impl<'p, 'v: 'p, 'tcx: 'v> BackwardMirInterpreter<'tcx>
                                         )
                                         .with_span(span)?
                                 } else {
-                                    return Err(SpannedEncodingError::incorrect(
+                                    let mut error = SpannedEncodingError::incorrect(
                                         format!(
                                             "use of impure function {func_proc_name:?} in pure code is not allowed"
                                         ),
                                         span,
-                                    ));
+                                    );
+                                    // Overloaded operators are calls to the methods of the
+                                    // `std::ops` traits, which can be used in specifications
+                                    // when the implementation is pure.
+                                    if called_def_id != def_id
+                                        && (full_func_proc_name.starts_with("core::ops::")
+                                            || full_func_proc_name.starts_with("std::ops::"))
+                                    {
+                                        let impl_method_name = self
+                                            .encoder
+                                            .env()
+                                            .name
+                                            .get_item_name(called_def_id);
+                                        error.set_help(format!(
+                                            "mark `{impl_method_name}` as `#[pure]` to use the operator in specifications"
+                                        ));
+                                    } else if called_def_id.is_local() {
+                                        let function_name = self
+                                            .encoder
+                                            .env()
+                                            .name
+                                            .get_item_name(called_def_id);
+                                        error.set_help(format!(
+                                            "mark `{function_name}` as `#[pure]` to call it from specifications"
+                                        ));
+                                    }
+                                    return Err(error);
                                 };
                                 trace!("Encoding pure function call '{}'", function_name);
 
@@ -734,8 +1297,18 @@ impl<'p, 'v: 'p, 'tcx: 'v> BackwardMirInterpreter<'tcx>
                     vir::Expr::not(cond_val)
                 };
 
+                let is_bounded_spec_overflow = matches!(
+                    self.pure_encoding_context,
+                    PureEncodingContext::Assertion
+                ) && matches!(
+                    msg,
+                    mir::AssertKind::Overflow(..) | mir::AssertKind::OverflowNeg(..)
+                ) && config::spec_arithmetic_mode() == config::SpecArithmeticMode::Bounded;
+
                 let error_ctxt = if let mir::AssertKind::BoundsCheck { .. } = msg {
                     ErrorCtxt::BoundsCheckAssert
+                } else if is_bounded_spec_overflow {
+                    ErrorCtxt::SpecificationOverflow
                 } else {
                     let assert_msg = msg.description().to_string();
                     ErrorCtxt::PureFunctionAssertTerminator(assert_msg)
@@ -753,7 +1326,8 @@ impl<'p, 'v: 'p, 'tcx: 'v> BackwardMirInterpreter<'tcx>
                         states[target].clone()
                     }
                     PureEncodingContext::Assertion
-                        if matches!(self.mir.return_ty().kind(), ty::TyKind::Bool) =>
+                        if matches!(self.mir.return_ty().kind(), ty::TyKind::Bool)
+                            && !is_bounded_spec_overflow =>
                     {
                         // We are encoding an assertion, so all failures should be equivalent to false.
                         // Predicates are also encoded as assertions, but non-Boolean predicates should
@@ -769,7 +1343,8 @@ impl<'p, 'v: 'p, 'tcx: 'v> BackwardMirInterpreter<'tcx>
                         ))
                     }
                     PureEncodingContext::Assertion | PureEncodingContext::Code => {
-                        // We are encoding a pure function, so all failures should be unreachable.
+                        // We are encoding a pure function or a specification with bounded
+                        // arithmetic, so all failures should be unreachable.
                         let failure_encoding =
                             unreachable_expr(pos).with_span(term.source_info.span)?;
                         ExprBackwardInterpreterState::new(states[target].expr().map(
@@ -859,7 +1434,6 @@ impl<'p, 'v: 'p, 'tcx: 'v> BackwardMirInterpreter<'tcx>
                     | ty::TyKind::Int(..)
                     | ty::TyKind::Uint(..)
                     | ty::TyKind::Float(..)
-                    | ty::TyKind::RawPtr(..)
                     | ty::TyKind::Ref(..) => true,
                     ty::TyKind::Tuple(substs) if substs.is_empty() => true,
                     _ => false
@@ -948,6 +1522,10 @@ impl<'p, 'v: 'p, 'tcx: 'v> BackwardMirInterpreter<'tcx>
                                     let operand = &operands[field_index];
                                     let field_name = field.ident(tcx).to_string();
                                     let field_ty = field.ty(tcx, subst);
+                                    // `PhantomData` fields are not part of the encoding.
+                                    if field_ty.is_phantom_data() {
+                                        continue;
+                                    }
                                     let encoded_field = self.encoder
                                         .encode_struct_field(&field_name, field_ty)
                                         .with_span(span)?;
@@ -1184,6 +1762,22 @@ impl<'p, 'v: 'p, 'tcx: 'v> BackwardMirInterpreter<'tcx>
                         state.substitute_value(&encoded_lhs, encoded_ref);
                     }
 
+                    // References are cast to raw pointers with `&raw const *r`, for example
+                    // when they are passed to `std::ptr::eq`. The pointer is encoded like the
+                    // reference, so that its target is the target of the reference.
+                    &mir::Rvalue::AddressOf(_, place) => {
+                        let (encoded_place, _, _) = self.encode_place(place).with_span(span)?;
+                        let encoded_ptr = match encoded_place {
+                            vir::Expr::Field( vir::FieldExpr {
+                                box ref base,
+                                field: vir::Field { ref name, .. },
+                                ..
+                            }) if name == "val_ref" => base.clone(),
+                            other_place => other_place.addr_of(),
+                        };
+                        state.substitute_value(&encoded_lhs, encoded_ptr);
+                    }
+
                     mir::Rvalue::Cast(mir::CastKind::IntToInt, ref operand, dst_ty) => {
                         let encoded_val = self.mir_encoder
                             .encode_cast_expr(operand, *dst_ty, span)?;
@@ -1302,3 +1896,32 @@ impl<'p, 'v: 'p, 'tcx: 'v> BackwardMirInterpreter<'tcx>
         Ok(())
     }
 }
+
+/// Whether a call taking a reference to a value of type `arg_ty` and
+/// returning a value of type `result_ty` gives access to the content of a
+/// `Box<T>`, e.g. `<Box<T> as Deref>::deref`.
+fn is_box_deref<'tcx>(arg_ty: ty::Ty<'tcx>, result_ty: ty::Ty<'tcx>) -> bool {
+    let arg_ty = arg_ty.peel_refs();
+    arg_ty.is_box()
+        && matches!(
+            result_ty.kind(),
+            ty::TyKind::Ref(_, target_ty, _) if *target_ty == arg_ty.boxed_ty()
+        )
+}
+
+/// Whether `proc_name` is one of the bit counting methods of the primitive
+/// integer types, which are modelled by `encode_bit_count`.
+fn is_bit_count_method(proc_name: &str) -> bool {
+    proc_name.starts_with("core::num::<impl ")
+        && matches!(
+            proc_name.rsplit("::").next(),
+            Some(
+                "count_ones"
+                    | "count_zeros"
+                    | "leading_zeros"
+                    | "leading_ones"
+                    | "trailing_zeros"
+                    | "trailing_ones"
+            )
+        )
+}
@@ -250,7 +250,7 @@ impl<'p, 'v: 'p, 'tcx: 'v> PureFunctionEncoder<'p, 'v, 'tcx> {
     #[tracing::instrument(level = "debug", skip(self))]
     pub fn encode_predicate_function(
         &self,
-        predicate_body: &DefId,
+        predicate_bodies: &[DefId],
     ) -> SpannedEncodingResult<vir::Function> {
         let function_name = self.encode_function_name();
         debug!("Encode predicate function {}", function_name);
@@ -265,24 +265,32 @@ impl<'p, 'v: 'p, 'tcx: 'v> PureFunctionEncoder<'p, 'v, 'tcx> {
             .map(|local| self.encode_local((*local).into()).map(|l| l.into()))
             .collect::<Result<Vec<_>, _>>()?;
 
-        let predicate_body_encoded = self
-            .encoder
-            .encode_assertion(
-                predicate_body,
-                None,
-                &encoded_args,
-                None,
-                true,
-                self.parent_def_id,
-                self.substs,
-            )?
-            .set_default_pos(self.encoder.error_manager().register_error(
-                self.span,
-                ErrorCtxt::PureFunctionDefinition,
-                *predicate_body,
-            ));
+        // The bodies of all predicate fragments are conjoined.
+        let mut predicate_body_encoded: Option<vir::Expr> = None;
+        for predicate_body in predicate_bodies {
+            let fragment_encoded = self
+                .encoder
+                .encode_assertion(
+                    predicate_body,
+                    None,
+                    &encoded_args,
+                    None,
+                    true,
+                    self.parent_def_id,
+                    self.substs,
+                )?
+                .set_default_pos(self.encoder.error_manager().register_error(
+                    self.span,
+                    ErrorCtxt::PureFunctionDefinition,
+                    *predicate_body,
+                ));
+            predicate_body_encoded = Some(match predicate_body_encoded {
+                Some(encoded) => vir::Expr::and(encoded, fragment_encoded),
+                None => fragment_encoded,
+            });
+        }
 
-        self.encode_function_given_body(Some(predicate_body_encoded))
+        self.encode_function_given_body(predicate_body_encoded)
     }
 
     // Private
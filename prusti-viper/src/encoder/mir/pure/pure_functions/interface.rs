@@ -112,6 +112,9 @@ pub(crate) struct PureFunctionEncoderState<'v, 'tcx: 'v> {
     function_constructors: RefCell<FxHashMap<String, FunctionConstructor<'v, 'tcx>>>,
     /// Stores the procedure id such that it can be used to create a counterexample
     function_proc_ids: RefCell<FxHashMap<String, ProcedureDefId>>,
+    /// Names of the encoded functions whose calls are used as quantifier
+    /// triggers (`#[trigger_on_call]`).
+    trigger_on_call_functions: RefCell<FxHashSet<String>>,
 }
 
 /// The information necessary to encode a function definition.
@@ -196,6 +199,10 @@ pub(crate) trait PureFunctionEncoderInterface<'v, 'tcx> {
     ) -> SpannedEncodingResult<()>;
 
     fn get_proc_def_id(&self, identifier: String) -> Option<ProcedureDefId>;
+
+    /// Whether calls of the encoded pure function with the given name should
+    /// be used as quantifier triggers.
+    fn is_trigger_on_call_function(&self, function_name: &str) -> bool;
 }
 
 impl<'v, 'tcx: 'v> PureFunctionEncoderInterface<'v, 'tcx>
@@ -209,6 +216,13 @@ impl<'v, 'tcx: 'v> PureFunctionEncoderInterface<'v, 'tcx>
             .cloned()
     }
 
+    fn is_trigger_on_call_function(&self, function_name: &str) -> bool {
+        self.pure_function_encoder_state
+            .trigger_on_call_functions
+            .borrow()
+            .contains(function_name)
+    }
+
     fn encode_pure_expression_high(
         &self,
         proc_def_id: ProcedureDefId,
@@ -339,10 +353,12 @@ impl<'v, 'tcx: 'v> PureFunctionEncoderInterface<'v, 'tcx>
                     pure_function_encoder.encode_bodyless_function()?
                 } else {
                     match proc_kind {
-                        ProcedureSpecificationKind::Predicate(Some(predicate_body)) => {
-                            pure_function_encoder.encode_predicate_function(&predicate_body)?
+                        ProcedureSpecificationKind::Predicate(ref predicate_bodies)
+                            if !predicate_bodies.is_empty() =>
+                        {
+                            pure_function_encoder.encode_predicate_function(predicate_bodies)?
                         }
-                        ProcedureSpecificationKind::Predicate(None) => {
+                        ProcedureSpecificationKind::Predicate(_) => {
                             pure_function_encoder.encode_bodyless_function()?
                         }
                         ProcedureSpecificationKind::Pure => {
@@ -364,11 +380,8 @@ impl<'v, 'tcx: 'v> PureFunctionEncoderInterface<'v, 'tcx>
                     }
                 };
 
-                let needs_patching = matches!(
-                    proc_kind,
-                    ProcedureSpecificationKind::Pure
-                        | ProcedureSpecificationKind::Predicate(Some(_)),
-                );
+                let needs_patching = proc_kind.is_concrete_predicate()
+                    || matches!(proc_kind, ProcedureSpecificationKind::Pure);
                 if needs_patching {
                     self.mirror_encoder
                         .borrow_mut()
@@ -465,6 +478,13 @@ impl<'v, 'tcx: 'v> PureFunctionEncoderInterface<'v, 'tcx>
             );
             let function_call_info = pure_function_encoder.encode_function_call_info()?;
 
+            if self.is_trigger_on_call(proc_def_id) {
+                self.pure_function_encoder_state
+                    .trigger_on_call_functions
+                    .borrow_mut()
+                    .insert(function_call_info.name.clone());
+            }
+
             // Save the information necessary to encode the function definition.
             let function_identifier: vir_poly::FunctionIdentifier =
                 WithIdentifier::get_identifier(&function_call_info).into();
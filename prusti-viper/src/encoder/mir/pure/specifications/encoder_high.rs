@@ -201,6 +201,12 @@ pub(super) fn encode_quantifier_high<'tcx>(
         body_substs,
     )?;
 
+    if encoded_trigger_sets.is_empty() {
+        if let Some(trigger) = map_domain_trigger(&encoded_body, &encoded_qvars) {
+            encoded_trigger_sets.push(trigger);
+        }
+    }
+
     // TODO: implement cache-friendly qvar renaming
 
     let final_body = if bounds.is_empty() {
@@ -224,3 +230,52 @@ pub(super) fn encode_quantifier_high<'tcx>(
         ))
     }
 }
+
+/// Quantifiers over the keys of a map are written as
+/// `forall(|k| map.contains(k) ==> ...)`. If the guard of such a quantifier
+/// restricts all quantified variables to the domain of a map, the membership
+/// checks of the guard are used as its trigger.
+fn map_domain_trigger(
+    body: &vir_high::Expression,
+    qvars: &[vir_high::VariableDecl],
+) -> Option<vir_high::Trigger> {
+    let vir_high::Expression::BinaryOp(vir_high::BinaryOp {
+        op_kind: vir_high::BinaryOpKind::Implies,
+        box left,
+        ..
+    }) = body else {
+        return None;
+    };
+    let mut guards = Vec::new();
+    collect_map_domain_guards(left, &mut guards);
+    let covers_qvars = qvars.iter().all(|qvar| {
+        let qvar = vir_high::Expression::local_no_pos(qvar.clone());
+        guards.iter().any(|guard| guard.find(&qvar))
+    });
+    if guards.is_empty() || !covers_qvars {
+        return None;
+    }
+    Some(vir_high::Trigger::new(guards))
+}
+
+fn collect_map_domain_guards(
+    guard: &vir_high::Expression,
+    guards: &mut Vec<vir_high::Expression>,
+) {
+    match guard {
+        vir_high::Expression::BinaryOp(vir_high::BinaryOp {
+            op_kind: vir_high::BinaryOpKind::And,
+            box left,
+            box right,
+            ..
+        }) => {
+            collect_map_domain_guards(left, guards);
+            collect_map_domain_guards(right, guards);
+        }
+        vir_high::Expression::BuiltinFuncApp(vir_high::BuiltinFuncApp {
+            function: vir_high::BuiltinFunc::MapContains,
+            ..
+        }) => guards.push(guard.clone()),
+        _ => {}
+    }
+}
@@ -19,10 +19,15 @@ use crate::{
     error_incorrect,
 };
 use prusti_common::config;
+use prusti_interface::PrustiError;
 use prusti_rustc_interface::{
     errors::MultiSpan,
     hir::def_id::DefId,
-    middle::{ty, ty::subst::SubstsRef},
+    middle::{
+        mir::{self, visit::Visitor},
+        ty,
+        ty::subst::SubstsRef,
+    },
     span::Span,
 };
 use rustc_hash::FxHashSet;
@@ -101,6 +106,8 @@ pub(super) fn inline_spec_item<'tcx>(
     );
     let mir_encoder = MirEncoder::new(encoder, &mir, def_id);
     let mut body_replacements = vec![];
+    // For each replacement, the index of the argument it substitutes.
+    let mut replaced_args = vec![];
     for (arg_idx, arg_local) in mir.args_iter().enumerate() {
         let local_span = mir_encoder.get_local_span(arg_local);
         let local = mir_encoder.encode_local(arg_local).unwrap();
@@ -120,22 +127,62 @@ pub(super) fn inline_spec_item<'tcx>(
                 target_args[arg_idx].clone()
             },
         ));
+        replaced_args.push(arg_idx);
 
         if !is_return_arg {
             body_replacements.push((
                 vir_crate::polymorphic::Expr::local(local),
                 target_args[arg_idx].clone(),
             ));
+            replaced_args.push(arg_idx);
         }
     }
-    Ok(encoder
+    let (inlined, counts) = encoder
         .encode_pure_expression(def_id, parent_def_id, substs)?
-        .replace_multiple_places(&body_replacements))
+        .replace_multiple_places_counted(&body_replacements);
+    // Specifications do not need to mention all arguments, but every argument
+    // that the body of the specification uses must have been substituted.
+    for (arg_idx, arg_local) in mir.args_iter().enumerate() {
+        let substituted: usize = replaced_args
+            .iter()
+            .zip(&counts)
+            .filter(|(replaced_arg, _)| **replaced_arg == arg_idx)
+            .map(|(_, count)| count)
+            .sum();
+        debug_assert!(
+            substituted > 0 || !is_local_used(&mir, arg_local),
+            "argument {arg_idx} of spec item {def_id:?} is used, but was not substituted"
+        );
+    }
+    Ok(inlined)
+}
+
+/// Whether a statement or terminator of `body` uses `local`.
+fn is_local_used(body: &mir::Body, local: mir::Local) -> bool {
+    struct LocalUseDetector {
+        local: mir::Local,
+        used: bool,
+    }
+    impl<'tcx> Visitor<'tcx> for LocalUseDetector {
+        fn visit_local(
+            &mut self,
+            local: mir::Local,
+            context: mir::visit::PlaceContext,
+            _location: mir::Location,
+        ) {
+            self.used |= local == self.local && context.is_use();
+        }
+    }
+    let mut detector = LocalUseDetector { local, used: false };
+    for (block, data) in body.basic_blocks.iter_enumerated() {
+        detector.visit_basic_block_data(block, data);
+    }
+    detector.used
 }
 
 pub(super) fn encode_quantifier<'tcx>(
     encoder: &Encoder<'_, 'tcx>,
-    _span: Span,
+    span: Span,
     encoded_args: Vec<vir_crate::polymorphic::Expr>,
     is_exists: bool,
     parent_def_id: DefId,
@@ -208,19 +255,13 @@ pub(super) fn encode_quantifier<'tcx>(
                 trigger_substs,
             );
             encoder.is_encoding_trigger.set(false);
-            let mut encoded_trigger = encoded_trigger_result?;
+            let encoded_trigger = encoded_trigger_result?;
 
             // slice accesses and other pure calls can get encoded as
             // `foo(...).val_X` but for triggers we need to strip the field
             // access away
             // TODO(tymap): this also strip out user-written field accesses...
-            while let vir_crate::polymorphic::Expr::Field(vir_crate::polymorphic::FieldExpr {
-                base,
-                ..
-            }) = encoded_trigger
-            {
-                encoded_trigger = *base;
-            }
+            let encoded_trigger = strip_trigger_fields(encoded_trigger);
 
             check_trigger(&encoded_trigger).with_span(trigger_span)?;
             encoded_triggers.push(encoded_trigger);
@@ -241,6 +282,10 @@ pub(super) fn encode_quantifier<'tcx>(
         body_substs,
     )?;
 
+    if encoded_trigger_sets.is_empty() {
+        encoded_trigger_sets = find_trigger_on_call_sets(encoder, &encoded_qvars, &encoded_body);
+    }
+
     // replace qvars with a nicer name based on quantifier depth to ensure that
     // quantifiers remain stable for caching
     let quantifier_depth = find_quantifier_depth(&encoded_body);
@@ -267,6 +312,27 @@ pub(super) fn encode_quantifier<'tcx>(
         .map(|set| set.replace_multiple_places(&qvar_replacements))
         .collect::<Vec<_>>();
 
+    if encoded_trigger_sets.is_empty() && config::report_inferred_triggers() {
+        report_inferred_triggers(encoder, span, &fixed_qvars, &encoded_body);
+    }
+
+    // Nested quantifiers are encoded first, so only the innermost quantifier
+    // that exceeds the limit is reported.
+    let max_alternations = config::max_quantifier_alternations();
+    if max_alternations > 0
+        && find_quantifier_alternations(is_exists, &encoded_body) == max_alternations + 1
+    {
+        PrustiError::warning(
+            format!(
+                "this quantifier nests more than {max_alternations} alternating blocks of \
+                `forall` and `exists`, which may cause the verifier to time out"
+            ),
+            span.into(),
+        )
+        .set_help("consider splitting the specification or introducing helper predicates")
+        .emit(&encoder.env().diagnostic);
+    }
+
     let final_body = if bounds.is_empty() {
         encoded_body
     } else if is_exists {
@@ -289,6 +355,114 @@ pub(super) fn encode_quantifier<'tcx>(
     }
 }
 
+/// Returns a trigger set for each call of a `#[trigger_on_call]` function in
+/// `body` that is a valid trigger of a quantifier over `qvars`.
+fn find_trigger_on_call_sets(
+    encoder: &Encoder<'_, '_>,
+    qvars: &[vir_crate::polymorphic::LocalVar],
+    body: &vir_crate::polymorphic::Expr,
+) -> Vec<vir_crate::polymorphic::Trigger> {
+    use vir_crate::polymorphic::ExprWalker;
+    struct CallCollector<'a, 'v, 'tcx> {
+        encoder: &'a Encoder<'v, 'tcx>,
+        calls: Vec<vir_crate::polymorphic::Expr>,
+    }
+    impl<'a, 'v, 'tcx> ExprWalker for CallCollector<'a, 'v, 'tcx> {
+        fn walk_func_app(&mut self, expr: &vir_crate::polymorphic::FuncApp) {
+            if self.encoder.is_trigger_on_call_function(&expr.function_name) {
+                let call = vir_crate::polymorphic::Expr::FuncApp(expr.clone());
+                if !self.calls.contains(&call) {
+                    self.calls.push(call);
+                }
+            }
+            for arg in &expr.arguments {
+                self.walk(arg);
+            }
+        }
+        // Calls in nested quantifiers may depend on their bound variables.
+        fn walk_forall(&mut self, _statement: &vir_crate::polymorphic::ForAll) {}
+        fn walk_exists(&mut self, _statement: &vir_crate::polymorphic::Exists) {}
+    }
+    let mut collector = CallCollector {
+        encoder,
+        calls: vec![],
+    };
+    collector.walk(body);
+    collector
+        .calls
+        .into_iter()
+        .filter(|call| check_trigger(call).is_ok())
+        .map(|call| vir_crate::polymorphic::Trigger::new(vec![call]))
+        .filter(|trigger_set| check_trigger_set(qvars, trigger_set).is_ok())
+        .collect()
+}
+
+/// Reports the triggers that `Expr::collect_triggers` infers for a quantifier
+/// without user-provided triggers.
+fn report_inferred_triggers(
+    encoder: &Encoder<'_, '_>,
+    span: Span,
+    qvars: &[vir_crate::polymorphic::LocalVar],
+    body: &vir_crate::polymorphic::Expr,
+) {
+    let trigger_sets = body.collect_triggers(qvars);
+    let warning = if trigger_sets.is_empty() {
+        PrustiError::warning("no triggers could be inferred for this quantifier", span.into())
+    } else {
+        let trigger_sets = trigger_sets
+            .iter()
+            .map(|trigger_set| trigger_set.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        PrustiError::warning(
+            format!("inferred triggers for this quantifier: {trigger_sets}"),
+            span.into(),
+        )
+        .set_help("the triggers can be given explicitly with `triggers=[...]`")
+    };
+    warning.emit(&encoder.env().diagnostic);
+}
+
+/// Returns the number of alternating blocks of `forall` and `exists` of a
+/// quantifier (`exists` if `is_exists`) with the given body. For example,
+/// `forall x. forall y. exists z. ...` has two blocks.
+fn find_quantifier_alternations(is_exists: bool, body: &vir_crate::polymorphic::Expr) -> usize {
+    use vir_crate::polymorphic::ExprWalker;
+    struct AlternationChecker {
+        is_exists: bool,
+        current_blocks: usize,
+        max_blocks: usize,
+    }
+    impl AlternationChecker {
+        fn walk_quantifier(&mut self, is_exists: bool, body: &vir_crate::polymorphic::Expr) {
+            let (old_is_exists, old_blocks) = (self.is_exists, self.current_blocks);
+            if is_exists != self.is_exists {
+                self.current_blocks += 1;
+                self.max_blocks = self.max_blocks.max(self.current_blocks);
+            }
+            self.is_exists = is_exists;
+            self.walk(body);
+            self.is_exists = old_is_exists;
+            self.current_blocks = old_blocks;
+        }
+    }
+    impl ExprWalker for AlternationChecker {
+        fn walk_forall(&mut self, statement: &vir_crate::polymorphic::ForAll) {
+            self.walk_quantifier(false, &statement.body);
+        }
+        fn walk_exists(&mut self, statement: &vir_crate::polymorphic::Exists) {
+            self.walk_quantifier(true, &statement.body);
+        }
+    }
+    let mut checker = AlternationChecker {
+        is_exists,
+        current_blocks: 1,
+        max_blocks: 1,
+    };
+    checker.walk(body);
+    checker.max_blocks
+}
+
 fn find_quantifier_depth(expr: &vir_crate::polymorphic::Expr) -> usize {
     use vir_crate::polymorphic::ExprWalker;
     struct DepthChecker {
@@ -321,6 +495,26 @@ fn find_quantifier_depth(expr: &vir_crate::polymorphic::Expr) -> usize {
     checker.max_depth
 }
 
+/// Strips the field accesses around a trigger term, including the ones
+/// inside of an `old(..)`, e.g. `old[pre](foo(...).val_X)`.
+fn strip_trigger_fields(trigger: vir_crate::polymorphic::Expr) -> vir_crate::polymorphic::Expr {
+    match trigger {
+        vir_crate::polymorphic::Expr::Field(vir_crate::polymorphic::FieldExpr { base, .. }) => {
+            strip_trigger_fields(*base)
+        }
+        vir_crate::polymorphic::Expr::LabelledOld(vir_crate::polymorphic::LabelledOld {
+            label,
+            base,
+            position,
+        }) => vir_crate::polymorphic::Expr::LabelledOld(vir_crate::polymorphic::LabelledOld {
+            label,
+            base: Box::new(strip_trigger_fields(*base)),
+            position,
+        }),
+        _ => trigger,
+    }
+}
+
 fn check_trigger(trigger: &vir_crate::polymorphic::Expr) -> EncodingResult<()> {
     use vir_crate::polymorphic::FallibleExprFolder;
     struct TriggerChecker {}
@@ -336,6 +530,17 @@ fn check_trigger(trigger: &vir_crate::polymorphic::Expr) -> EncodingResult<()> {
                 | vir_crate::polymorphic::Expr::Const(..)
                 | vir_crate::polymorphic::Expr::FuncApp(..)
                 | vir_crate::polymorphic::Expr::DomainFuncApp(..) => Ok(e),
+                // a function call evaluated in an old state, e.g. `old(foo(x))`
+                vir_crate::polymorphic::Expr::LabelledOld(vir_crate::polymorphic::LabelledOld {
+                    ref base,
+                    ..
+                }) => match **base {
+                    vir_crate::polymorphic::Expr::FuncApp(..)
+                    | vir_crate::polymorphic::Expr::DomainFuncApp(..) => Ok(e),
+                    _ => Err(EncodingError::incorrect(
+                        "only function calls are allowed inside of `old(..)` in triggers",
+                    )),
+                },
                 // everything else is illegal in triggers
                 _ => Err(EncodingError::incorrect(
                     "only function calls are allowed in triggers",
@@ -27,11 +27,16 @@ use crate::encoder::{
     mir_encoder::{MirEncoder, PlaceEncoder, PRECONDITION_LABEL},
     snapshot::interface::SnapshotEncoderInterface,
 };
+use prusti_common::{
+    config,
+    report::time_report::{self, Phase},
+};
 use prusti_rustc_interface::{
     hir::def_id::DefId,
     middle::{mir, ty::subst::SubstsRef},
     span::Span,
 };
+use std::time::Instant;
 use vir_crate::{
     high::{self as vir_high, operations::ty::Typed},
     polymorphic as vir_poly,
@@ -255,6 +260,30 @@ impl<'v, 'tcx: 'v> SpecificationEncoderInterface<'tcx> for crate::encoder::Encod
                 vir_poly::Expr::snap_app(encoded_args[0].clone()),
                 vir_poly::Expr::snap_app(encoded_args[1].clone()),
             )),
+            "prusti_contracts::disjoint" => {
+                // References are encoded by the place they are stored in; the
+                // addresses they point to are the values of their `val_ref` fields.
+                let deref_field = self
+                    .encode_dereference_field(substs.type_at(0))
+                    .with_span(span)?;
+                Ok(vir_poly::Expr::ne_cmp(
+                    encoded_args[0].clone().field(deref_field.clone()),
+                    encoded_args[1].clone().field(deref_field),
+                ))
+            }
+            // Established by `ResultBorrowsFromChecker` from the lifetimes in
+            // the signature of the function, which the borrow checker enforces
+            // at the call sites, so there is nothing left to verify.
+            "prusti_contracts::result_borrows_from" => Ok(true.into()),
+            // The borrowing part is checked like `result_borrows_from`; what
+            // remains is that the source was not modified.
+            "prusti_contracts::lazy_iterator" => {
+                let source = vir_poly::Expr::snap_app(encoded_args[1].clone());
+                Ok(vir_poly::Expr::eq_cmp(
+                    source.clone(),
+                    vir_poly::Expr::labelled_old(PRECONDITION_LABEL, source),
+                ))
+            }
             _ => unimplemented!(),
         }
     }
@@ -269,6 +298,7 @@ impl<'v, 'tcx: 'v> SpecificationEncoderInterface<'tcx> for crate::encoder::Encod
         parent_def_id: DefId,
         substs: SubstsRef<'tcx>,
     ) -> SpannedEncodingResult<vir_poly::Expr> {
+        let start = Instant::now();
         let mut encoded_assertion = inline_spec_item(
             self,
             *assertion,
@@ -293,6 +323,14 @@ impl<'v, 'tcx: 'v> SpecificationEncoderInterface<'tcx> for crate::encoder::Encod
         let span = self.env().query.get_def_span(assertion);
         encoded_assertion = self.patch_snapshots(encoded_assertion).with_span(span)?;
 
+        if config::time_report() {
+            time_report::record(
+                self.env().name.get_unique_item_name(parent_def_id),
+                Phase::Assertions,
+                start.elapsed(),
+            );
+        }
+
         Ok(encoded_assertion
             .set_default_pos(self.error_manager().register_span(parent_def_id, span)))
     }
@@ -4,7 +4,7 @@ use prusti_interface::{
         typed,
         typed::{DefSpecificationMap, ProcedureSpecification, ProcedureSpecificationKind},
     },
-    utils::has_spec_only_attr,
+    utils::{has_prusti_attr, has_spec_only_attr},
 };
 use prusti_rustc_interface::{hir::def_id::DefId, middle::ty::subst::SubstsRef, span::Span};
 use std::{cell::RefCell, hash::Hash};
@@ -80,7 +80,18 @@ pub(crate) trait SpecificationsInterface<'tcx> {
 
     fn is_trusted(&self, def_id: DefId, substs: Option<SubstsRef<'tcx>>) -> bool;
 
-    fn get_predicate_body(&self, def_id: DefId, substs: SubstsRef<'tcx>) -> Option<DefId>;
+    /// Is the function annotated with `#[no_panic]`?
+    fn is_no_panic(&self, def_id: DefId, substs: Option<SubstsRef<'tcx>>) -> bool;
+
+    /// The fields of `self` that the method may modify, if it is annotated
+    /// with `#[modifies(...)]`.
+    fn get_modified_fields(
+        &self,
+        def_id: DefId,
+        substs: Option<SubstsRef<'tcx>>,
+    ) -> Option<Vec<String>>;
+
+    fn get_predicate_bodies(&self, def_id: DefId, substs: SubstsRef<'tcx>) -> Vec<DefId>;
 
     fn terminates(&self, def_id: DefId, substs: Option<SubstsRef<'tcx>>) -> bool;
 
@@ -97,6 +108,12 @@ pub(crate) trait SpecificationsInterface<'tcx> {
     /// Get the prusti assumption
     fn get_prusti_assumption(&self, def_id: DefId) -> Option<typed::PrustiAssumption>;
 
+    /// Get the marker of a `prusti_assume_invariant!`
+    fn get_prusti_invariant_assumption(
+        &self,
+        def_id: DefId,
+    ) -> Option<typed::PrustiInvariantAssumption>;
+
     /// Get the prusti refutation
     fn get_prusti_refutation(&self, def_id: DefId) -> Option<typed::PrustiRefutation>;
 
@@ -124,6 +141,10 @@ pub(crate) trait SpecificationsInterface<'tcx> {
     /// Is the closure specified with the `def_id` spec only?
     fn is_spec_closure(&self, def_id: DefId) -> bool;
 
+    /// Is the function marked with `#[trigger_on_call]`, i.e. should its calls
+    /// be used as triggers of the quantifiers that mention them?
+    fn is_trigger_on_call(&self, def_id: DefId) -> bool;
+
     /// Get the span of the declared specification, if any, or else the span of
     /// the method declaration.
     fn get_spec_span(&self, def_id: DefId) -> Span;
@@ -132,11 +153,7 @@ pub(crate) trait SpecificationsInterface<'tcx> {
 impl<'v, 'tcx: 'v> SpecificationsInterface<'tcx> for super::super::super::Encoder<'v, 'tcx> {
     #[tracing::instrument(level = "trace", skip(self), ret)]
     fn is_pure(&self, def_id: DefId, substs: Option<SubstsRef<'tcx>>) -> bool {
-        let kind = self.get_proc_kind(def_id, substs);
-        let mut pure = matches!(
-            kind,
-            ProcedureSpecificationKind::Pure | ProcedureSpecificationKind::Predicate(_)
-        );
+        let mut pure = self.get_proc_kind(def_id, substs).is_pure();
 
         let func_name = self.env().name.get_unique_item_name(def_id);
         if func_name.starts_with("prusti_contracts::prusti_contracts::Map")
@@ -161,7 +178,7 @@ impl<'v, 'tcx: 'v> SpecificationsInterface<'tcx> for super::super::super::Encode
             .borrow_mut()
             .get_and_refine_proc_spec(self.env(), query)
             .map(|spec| spec.kind)
-            .and_then(|kind| kind.extract_with_selective_replacement().copied())
+            .and_then(|kind| kind.extract_with_selective_replacement().cloned())
             .unwrap_or(ProcedureSpecificationKind::Impure)
     }
 
@@ -178,14 +195,42 @@ impl<'v, 'tcx: 'v> SpecificationsInterface<'tcx> for super::super::super::Encode
     }
 
     #[tracing::instrument(level = "trace", skip(self), ret)]
-    fn get_predicate_body(&self, def_id: DefId, substs: SubstsRef<'tcx>) -> Option<DefId> {
+    fn is_no_panic(&self, def_id: DefId, substs: Option<SubstsRef<'tcx>>) -> bool {
+        let substs = substs.unwrap_or_else(|| self.env().query.identity_substs(def_id));
+        let query = SpecQuery::GetProcKind(def_id, substs);
+        self.specifications_state
+            .specs
+            .borrow_mut()
+            .get_and_refine_proc_spec(self.env(), query)
+            .and_then(|spec| spec.no_panic.extract_with_selective_replacement().copied())
+            .unwrap_or(false)
+    }
+
+    #[tracing::instrument(level = "trace", skip(self), ret)]
+    fn get_modified_fields(
+        &self,
+        def_id: DefId,
+        substs: Option<SubstsRef<'tcx>>,
+    ) -> Option<Vec<String>> {
+        let substs = substs.unwrap_or_else(|| self.env().query.identity_substs(def_id));
+        let query = SpecQuery::GetProcKind(def_id, substs);
+        self.specifications_state
+            .specs
+            .borrow_mut()
+            .get_and_refine_proc_spec(self.env(), query)
+            .and_then(|spec| spec.modifies.extract_with_selective_replacement().cloned().flatten())
+    }
+
+    #[tracing::instrument(level = "trace", skip(self), ret)]
+    fn get_predicate_bodies(&self, def_id: DefId, substs: SubstsRef<'tcx>) -> Vec<DefId> {
         let query = SpecQuery::FunctionDefEncoding(def_id, substs);
         let mut specs = self.specifications_state.specs.borrow_mut();
         specs
             .get_and_refine_proc_spec(self.env(), query)
             // In case of error -> It is emitted in get_and_refine_proc_spec
-            .and_then(|spec| spec.kind.get_predicate_body().unwrap_or(None))
-            .cloned()
+            .and_then(|spec| spec.kind.get_predicate_bodies().ok())
+            .map(|bodies| bodies.to_vec())
+            .unwrap_or_default()
     }
 
     #[tracing::instrument(level = "trace", skip(self), ret)]
@@ -237,6 +282,17 @@ impl<'v, 'tcx: 'v> SpecificationsInterface<'tcx> for super::super::super::Encode
             .cloned()
     }
 
+    fn get_prusti_invariant_assumption(
+        &self,
+        def_id: DefId,
+    ) -> Option<typed::PrustiInvariantAssumption> {
+        self.specifications_state
+            .specs
+            .borrow()
+            .get_invariant_assumption(&def_id)
+            .cloned()
+    }
+
     fn get_prusti_refutation(&self, def_id: DefId) -> Option<typed::PrustiRefutation> {
         self.specifications_state
             .specs
@@ -292,6 +348,10 @@ impl<'v, 'tcx: 'v> SpecificationsInterface<'tcx> for super::super::super::Encode
         has_spec_only_attr(self.env().query.get_attributes(def_id))
     }
 
+    fn is_trigger_on_call(&self, def_id: DefId) -> bool {
+        has_prusti_attr(self.env().query.get_attributes(def_id), "trigger_on_call")
+    }
+
     fn get_spec_span(&self, def_id: DefId) -> Span {
         let query = SpecQuery::FetchSpan(def_id);
         self.specifications_state
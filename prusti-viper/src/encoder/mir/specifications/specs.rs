@@ -11,7 +11,8 @@ use prusti_interface::{
     specs::typed::{
         DefSpecificationMap, GhostBegin, GhostEnd, LoopSpecification, ProcedureSpecification,
         ProcedureSpecificationKind, ProcedureSpecificationKindError, PrustiAssertion,
-        PrustiAssumption, PrustiRefutation, Refinable, SpecificationItem, TypeSpecification,
+        PrustiAssumption, PrustiInvariantAssumption, PrustiRefutation, Refinable,
+        SpecificationItem, TypeSpecification,
     },
     PrustiError,
 };
@@ -91,6 +92,14 @@ impl<'tcx> Specifications<'tcx> {
         self.user_typed_specs.get_assumption(def_id)
     }
 
+    #[tracing::instrument(level = "trace", skip(self))]
+    pub(super) fn get_invariant_assumption(
+        &self,
+        def_id: &DefId,
+    ) -> Option<&PrustiInvariantAssumption> {
+        self.user_typed_specs.get_invariant_assumption(def_id)
+    }
+
     #[tracing::instrument(level = "trace", skip(self))]
     pub(super) fn get_refutation(&self, def_id: &DefId) -> Option<&PrustiRefutation> {
         self.user_typed_specs.get_refutation(def_id)
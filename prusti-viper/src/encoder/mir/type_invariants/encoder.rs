@@ -1,6 +1,6 @@
 use super::interface::TypeInvariantEncoderInterface;
 use crate::encoder::{
-    errors::EncodingResult,
+    errors::{EncodingError, EncodingResult},
     high::types::HighTypeEncoderInterface,
     mir::{
         pure::SpecificationEncoderInterface, specifications::SpecificationsInterface,
@@ -11,7 +11,10 @@ use crate::encoder::{
 };
 use prusti_common::{vir_expr, vir_local};
 use prusti_interface::specs::typed;
-use prusti_rustc_interface::{middle::ty, target::abi::Integer};
+use prusti_rustc_interface::{
+    middle::ty,
+    target::abi::{Integer, VariantIdx},
+};
 use vir_crate::polymorphic::{self as vir, ExprIterator};
 
 pub(super) fn needs_invariant_func(ty: ty::Ty<'_>) -> bool {
@@ -103,9 +106,23 @@ pub(super) fn encode_invariant_def<'p, 'v: 'p, 'tcx: 'v>(
         }
 
         ty::TyKind::Adt(adt_def, substs) if adt_def.is_struct() || adt_def.is_enum() => {
+            // Whether the value `self` is of the given variant of the enum.
+            let variant_guard = |variant_idx: VariantIdx| {
+                let discriminant_raw = adt_def.discriminant_for_variant(tcx, variant_idx).val;
+                let size = ty::tls::with(|tcx| {
+                    Integer::from_attr(&tcx, adt_def.repr().discr_type()).size()
+                });
+                let arg_discriminant = arg_expr.clone().field(encoder.encode_discriminant_field());
+                let variant_discriminant = size.sign_extend(discriminant_raw) as i128;
+                vir_expr! { [arg_discriminant] == [vir::Expr::from(variant_discriminant)] }
+            };
+
             if adt_def.is_struct() {
                 for field in adt_def.all_fields() {
                     let field_ty = field.ty(tcx, substs);
+                    if field_ty.is_phantom_data() {
+                        continue;
+                    }
                     conjuncts.push(encoder.encode_invariant_func_app(
                         field_ty,
                         vir::Expr::snap_app(vir::Expr::field(
@@ -133,6 +150,9 @@ pub(super) fn encode_invariant_def<'p, 'v: 'p, 'tcx: 'v>(
                     };
                     for field in &variant.fields {
                         let field_ty = field.ty(tcx, substs);
+                        if field_ty.is_phantom_data() {
+                            continue;
+                        }
                         let field = vir::Expr::snap_app(vir::Expr::field(
                             field_base.clone(),
                             encoder.encode_struct_field(&field.ident(tcx).to_string(), field_ty)?,
@@ -140,23 +160,8 @@ pub(super) fn encode_invariant_def<'p, 'v: 'p, 'tcx: 'v>(
                         fields.push(encoder.encode_invariant_func_app(field_ty, field)?);
                     }
 
-                    let discriminant_raw = adt_def
-                        .discriminant_for_variant(
-                            tcx,
-                            prusti_rustc_interface::target::abi::VariantIdx::from_usize(
-                                variant_idx,
-                            ),
-                        )
-                        .val;
-                    let size = ty::tls::with(|tcx| {
-                        Integer::from_attr(&tcx, adt_def.repr().discr_type()).size()
-                    });
-                    let arg_discriminant =
-                        arg_expr.clone().field(encoder.encode_discriminant_field());
-                    let variant_discriminant = size.sign_extend(discriminant_raw) as i128;
-
                     variants.push((
-                        vir_expr! { [arg_discriminant] == [vir::Expr::from(variant_discriminant)] },
+                        variant_guard(VariantIdx::from_usize(variant_idx)),
                         fields.into_iter().conjoin(),
                     ));
                 }
@@ -191,6 +196,40 @@ pub(super) fn encode_invariant_def<'p, 'v: 'p, 'tcx: 'v>(
                     _ => todo!(),
                     // TODO(inv): handle invariant inheritance
                 }
+                for (variant_name, variant_inv_def_id) in &specs.variant_invariants {
+                    let Some((variant_idx, _)) = adt_def
+                        .variants()
+                        .iter_enumerated()
+                        .find(|(_, variant)| variant.name.as_str() == variant_name)
+                    else {
+                        return Err(EncodingError::internal(format!(
+                            "enum {ty:?} has no variant named '{variant_name}'"
+                        )));
+                    };
+                    let variant_inv = encoder.encode_assertion(
+                        variant_inv_def_id,
+                        None,
+                        &[arg_expr.clone()],
+                        None,
+                        true,
+                        *variant_inv_def_id,
+                        substs,
+                    )?;
+                    conjuncts.push(vir::Expr::implies(variant_guard(variant_idx), variant_inv));
+                }
+                for trait_inv_def_id in &specs.trait_invariants {
+                    // The invariant is a provided method of a trait without
+                    // generic parameters, so `Self` is its only type argument.
+                    conjuncts.push(encoder.encode_assertion(
+                        trait_inv_def_id,
+                        None,
+                        &[arg_expr.clone()],
+                        None,
+                        true,
+                        *trait_inv_def_id,
+                        tcx.mk_substs_trait(ty, []),
+                    )?);
+                }
             }
         }
 
@@ -22,6 +22,7 @@ use prusti_rustc_interface::{
         mir,
         ty::{self, TypeVisitableExt},
     },
+    span::symbol::Symbol,
 };
 use vir_crate::high::{self as vir, operations::ty::Typed};
 
@@ -50,7 +51,7 @@ impl<'p, 'v, 'r: 'v, 'tcx: 'v> TypeEncoder<'p, 'v, 'tcx> {
                 | "prusti_contracts::Map"
                 | "prusti_contracts::Int"
                 | "prusti_contracts::Ghost"
-        )
+        ) || is_hash_map(self.encoder.env().tcx(), did)
     }
 
     fn is_trusted_type(&self, did: DefId) -> bool {
@@ -131,7 +132,9 @@ impl<'p, 'v, 'r: 'v, 'tcx: 'v> TypeEncoder<'p, 'v, 'tcx> {
                         element_type: enc_substs[0].clone(),
                         lifetimes,
                     })
-                } else if type_name == "prusti_contracts::Map" {
+                } else if type_name == "prusti_contracts::Map"
+                    || is_hash_map(self.encoder.env().tcx(), adt_def.did())
+                {
                     vir::Type::Map(vir::ty::Map {
                         key_type: enc_substs[0].clone(),
                         val_type: enc_substs[1].clone(),
@@ -441,6 +444,12 @@ impl<'p, 'v, 'r: 'v, 'tcx: 'v> TypeEncoder<'p, 'v, 'tcx> {
                         enc_substs[0].clone(),
                         enc_substs[1].clone(),
                     ),
+                    _ if is_hash_map(self.encoder.env().tcx(), adt_def.did()) => vir::TypeDecl::map(
+                        lifetimes,
+                        const_parameters,
+                        enc_substs[0].clone(),
+                        enc_substs[1].clone(),
+                    ),
                     "prusti_contracts::Int" => vir::TypeDecl::Int(vir::type_decl::Int {
                         lower_bound: None,
                         upper_bound: None,
@@ -684,6 +693,14 @@ fn encode_function_def_name<'v, 'tcx: 'v>(encoder: &Encoder<'v, 'tcx>, did: DefI
     format!("fndef${}", encoder.encode_item_name(did))
 }
 
+/// `HashMap` is modelled as a native Viper map. This is supported only by the
+/// core proof encoding because the legacy encoder has no map operations. The
+/// type is identified by its diagnostic item, so that user types that happen to
+/// be called `HashMap` are not affected.
+pub(crate) fn is_hash_map(tcx: ty::TyCtxt<'_>, did: DefId) -> bool {
+    config::unsafe_core_proof() && tcx.is_diagnostic_item(Symbol::intern("HashMap"), did)
+}
+
 fn encode_box_name() -> String {
     "box$".to_string()
 }
@@ -707,6 +724,13 @@ fn encode_variant<'v, 'tcx: 'v>(
     for (field_index, field) in variant.fields.iter().enumerate() {
         let field_name = crate::encoder::encoder::encode_field_name(field.ident(tcx).as_str());
         let field_ty = field.ty(tcx, substs);
+        // `PhantomData` fields are zero-sized markers, which do not get any
+        // permission in the legacy encoding. The core proof encoding looks up
+        // fields by their index, so it keeps them. Both encodings reject
+        // accesses to such fields.
+        if field_ty.is_phantom_data() && !config::unsafe_core_proof() {
+            continue;
+        }
         let field_ty = if config::unsafe_core_proof() && field_ty.has_erasable_regions() {
             field_ty
         } else {
@@ -25,6 +25,7 @@ use log::{trace, debug};
 use prusti_interface::environment::mir_utils::MirPlace;
 use crate::encoder::mir::{
     sequences::MirSequencesEncoderInterface,
+    specifications::SpecificationsInterface,
     types::MirTypeEncoderInterface,
 };
 use super::high::types::HighTypeEncoderInterface;
@@ -37,6 +38,22 @@ pub use place_encoding::{PlaceEncoding, ExprOrArrayBase};
 
 pub static PRECONDITION_LABEL: &str = "pre";
 pub static WAND_LHS_LABEL: &str = "lhs";
+/// The variable that `iterations()` is encoded as, which the procedure encoder
+/// replaces by the iteration counter of the loop whose invariant uses it.
+pub static LOOP_ITERATIONS_PLACEHOLDER: &str = "_loop_iterations";
+/// The label of `old[prev](..)`, which the procedure encoder replaces by the
+/// label of the previous iteration of the loop whose invariant uses it.
+pub static PREV_ITERATION_LABEL: &str = "prev_iteration";
+
+/// The label of the snapshot taken by `snapshot!(label)` and referenced by
+/// `old[label](..)`. The label is passed to `prusti_contracts::snapshot` and
+/// `prusti_contracts::old_at` as a unit struct type argument.
+pub fn snapshot_label(tcx: ty::TyCtxt, label_ty: ty::Ty) -> String {
+    match label_ty.kind() {
+        ty::TyKind::Adt(adt_def, _) => tcx.item_name(adt_def.did()).to_string(),
+        _ => unreachable!("snapshot labels are unit structs, found {:?}", label_ty),
+    }
+}
 
 pub trait PlaceEncoder<'v, 'tcx: 'v> {
 
@@ -147,6 +164,11 @@ pub trait PlaceEncoder<'v, 'tcx: 'v> {
                         if utils::is_reference(field_ty) {
                             error_unsupported!("access to reference-typed fields is not supported");
                         }
+                        // `PhantomData` fields are skipped by the encoding of
+                        // their struct, so there is nothing to project to.
+                        if field_ty.is_phantom_data() {
+                            error_unsupported!("access to `PhantomData` fields is not supported");
+                        }
                         let encoded_field = self
                             .encoder()
                             .encode_struct_field(
@@ -404,6 +426,12 @@ impl<'p, 'v: 'p, 'tcx: 'v> MirEncoder<'p, 'v, 'tcx> {
         }
     }
 
+    /// Overflows are checked if enabled globally or if the procedure is marked
+    /// with `#[no_panic]`.
+    fn check_overflows(&self) -> bool {
+        config::check_overflows() || self.encoder.is_no_panic(self.def_id, None)
+    }
+
     /// Returns an `vir::Expr` that corresponds to the value of the operand
     #[tracing::instrument(level = "trace", skip(self))]
     pub fn encode_operand_expr(
@@ -552,7 +580,7 @@ impl<'p, 'v: 'p, 'tcx: 'v> MirEncoder<'p, 'v, 'tcx> {
         right: vir::Expr,
         ty: ty::Ty<'tcx>,
     ) -> EncodingResult<vir::Expr> {
-        if !op.is_checkable() || !config::check_overflows() {
+        if !op.is_checkable() || !self.check_overflows() {
             Ok(false.into())
         } else {
             let result = self.encode_bin_op_expr(op, left, right.clone(), ty)?;
@@ -725,7 +753,7 @@ impl<'p, 'v: 'p, 'tcx: 'v> MirEncoder<'p, 'v, 'tcx> {
             | (ty::TyKind::Uint(_), ty::TyKind::Uint(_))
             => {
                 let encoded_operand = self.encode_operand_expr(operand).with_span(span)?;
-                if config::check_overflows() {
+                if self.check_overflows() {
                     // Check the cast
                     let function_name = self.encoder.encode_cast_function_use(src_ty, dst_ty)
                         .with_span(span)?;
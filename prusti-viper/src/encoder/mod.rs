@@ -28,6 +28,7 @@ mod high;
 mod typed;
 mod middle;
 mod purifier;
+mod explain_encoding;
 pub mod counterexamples;
 mod definition_collector;
 mod versioning;
@@ -16,7 +16,9 @@ use crate::encoder::high::types::HighTypeEncoderInterface;
 use crate::encoder::initialisation::InitInfo;
 use crate::encoder::loop_encoder::{LoopEncoder, LoopEncoderError};
 use crate::encoder::mir_encoder::{MirEncoder, FakeMirEncoder, PlaceEncoder, PlaceEncoding, ExprOrArrayBase};
-use crate::encoder::mir_encoder::PRECONDITION_LABEL;
+use crate::encoder::mir_encoder::{
+    snapshot_label, LOOP_ITERATIONS_PLACEHOLDER, PRECONDITION_LABEL, PREV_ITERATION_LABEL,
+};
 use crate::encoder::mir_successor::MirSuccessor;
 use crate::encoder::places::{Local, LocalVariableManager, Place};
 use crate::encoder::Encoder;
@@ -65,7 +67,7 @@ use ::log::{trace, debug};
 use prusti_interface::environment::borrowck::regions::PlaceRegionsError;
 use crate::encoder::errors::EncodingErrorKind;
 use std::convert::TryInto;
-use prusti_interface::specs::typed::{Pledge, SpecificationItem};
+use prusti_interface::specs::typed::{LoopSpecification, Pledge, SpecificationItem};
 use vir_crate::polymorphic::Float;
 use crate::utils::is_reference;
 use crate::encoder::mir::{
@@ -130,6 +132,9 @@ pub struct ProcedureEncoder<'p, 'v: 'p, 'tcx: 'v> {
     old_ghost_vars: FxHashMap<String, vir::Type>,
     /// For each loop head, the block at whose end the loop invariant holds
     cached_loop_invariant_block: FxHashMap<BasicBlockIndex, BasicBlockIndex>,
+    /// For each loop head, the encoded body invariants of the loop, in which
+    /// `old[prev](..)` still refers to `PREV_ITERATION_LABEL`
+    cached_loop_invariant_specs: FxHashMap<BasicBlockIndex, (Vec<vir::Expr>, MultiSpan)>,
     /// Type substitutions inside this procedure. Most likely identity for the
     /// given proc_def_id.
     substs: SubstsRef<'tcx>,
@@ -174,7 +179,7 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
             loop_encoder: LoopEncoder::new(procedure, tcx),
             auxiliary_local_vars: FxHashMap::default(),
             mir_encoder,
-            check_panics: config::check_panics(),
+            check_panics: config::check_panics() || encoder.is_no_panic(proc_def_id, None),
             check_foldunfold_state: config::check_foldunfold_state(),
             polonius_info: None,
             procedure_contract: None,
@@ -191,6 +196,7 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
             old_to_ghost_var: FxHashMap::default(),
             old_ghost_vars: FxHashMap::default(),
             cached_loop_invariant_block: FxHashMap::default(),
+            cached_loop_invariant_specs: FxHashMap::default(),
             substs,
         })
     }
@@ -223,10 +229,67 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
         let block = &self.mir[bb];
         let _ = self.try_encode_assert(bb, block, encoded_statements)?
         || self.try_encode_assume(bb, block, encoded_statements)?
+        || self.try_encode_assume_invariant(bb, block, encoded_statements)?
         || self.try_encode_refute(bb, block, encoded_statements)?;
         Ok(())
     }
 
+    /// Encode `prusti_assume_invariant!(x)` by inhaling the type invariant of
+    /// the place borrowed by the marker closure.
+    fn try_encode_assume_invariant(
+        &mut self,
+        _bb: mir::BasicBlock,
+        block: &mir::BasicBlockData<'tcx>,
+        encoded_statements: &mut Vec<vir::Stmt>,
+    ) -> SpannedEncodingResult<bool> {
+        for stmt in &block.statements {
+            if let mir::StatementKind::Assign(box (
+                _,
+                mir::Rvalue::Aggregate(box mir::AggregateKind::Closure(cl_def_id, _), ref operands),
+            )) = stmt.kind
+            {
+                let assumption = match self.encoder.get_prusti_invariant_assumption(cl_def_id) {
+                    Some(spec) => spec,
+                    None => return Ok(false),
+                };
+
+                let span = self
+                    .encoder
+                    .get_definition_span(assumption.assumption.to_def_id());
+
+                // The closure captures the value by a shared reference, which
+                // is created in the same specification block.
+                let borrowed_place = operands.iter().find_map(|operand| {
+                    let local = operand.place()?.as_local()?;
+                    block.statements.iter().find_map(|stmt| match stmt.kind {
+                        mir::StatementKind::Assign(box (
+                            lhs,
+                            mir::Rvalue::Ref(_, _, place),
+                        )) if lhs.as_local() == Some(local) => Some(place),
+                        _ => None,
+                    })
+                });
+                let Some(place) = borrowed_place else {
+                    error_unsupported!(span =>
+                        "the argument of prusti_assume_invariant! must be a place"
+                    );
+                };
+
+                let (encoded_place, place_ty, _) = self.mir_encoder.encode_place(place).with_span(span)?;
+                let encoded_place = encoded_place.try_into_expr().with_span(span)?;
+                let invariant = self.encoder.encode_invariant_func_app(
+                    place_ty,
+                    vir::Expr::snap_app(encoded_place),
+                ).with_span(span)?;
+
+                encoded_statements.push(vir::Stmt::Inhale(vir::Inhale { expr: invariant }));
+
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
     fn try_encode_assume(
         &mut self,
         bb: mir::BasicBlock,
@@ -402,6 +465,18 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
     pub fn encode(mut self) -> SpannedEncodingResult<vir::CfgMethod> {
         let mir_span = self.mir.span;
 
+        // The body of an `async fn` only constructs the future; the code that
+        // computes the output (which the postconditions talk about) is in the
+        // body of the generator, which we cannot encode yet.
+        // TODO: encode the generator with the arguments of the `async fn` as
+        // its upvars and check the postconditions against its return value.
+        if self.encoder.env().query.is_async_fn(self.proc_def_id) {
+            return Err(SpannedEncodingError::unsupported(
+                "verifying the body of an `async fn` is not supported",
+                mir_span,
+            ));
+        }
+
         // Retrieve the contract
         let procedure_contract = self.encoder
             .get_procedure_contract_for_def(self.proc_def_id, self.substs)
@@ -893,6 +968,38 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
             ));
         }
 
+        // The iterations are only counted if an invariant uses `iterations()`.
+        let iterations_var = self.encode_loop_iterations_var(loop_head);
+        let (invariant_specs, _) = self.encode_loop_invariant_specs(
+            loop_head,
+            before_invariant_block,
+            Some(PREV_ITERATION_LABEL),
+        )?;
+        let uses_iterations = invariant_specs
+            .iter()
+            .any(|spec| spec.find(&vir::Expr::local(iterations_var.clone())));
+        // The state of the previous iteration is only labelled if an invariant
+        // uses `old[prev](..)`. The first label is the state of an arbitrary
+        // previous iteration, the second one is the state at the invariant of
+        // the current iteration, which is the previous one of the next check.
+        let prev_iteration_labels = invariant_specs
+            .iter()
+            .any(|spec| {
+                spec.clone().map_labels(|label| (label != PREV_ITERATION_LABEL).then_some(label))
+                    != *spec
+            })
+            .then(|| {
+                (
+                    self.cfg_method.get_fresh_label_name(),
+                    self.cfg_method.get_fresh_label_name(),
+                )
+            });
+        if uses_iterations && !self.auxiliary_local_vars.contains_key(&iterations_var.name) {
+            self.cfg_method.add_local_var(&iterations_var.name, vir::Type::Int);
+            self.auxiliary_local_vars
+                .insert(iterations_var.name.clone(), vir::Type::Int);
+        }
+
         // Split the blocks such that:
         // * G is loop_guard_evaluation, starting (if nonempty) with loop_head
         // * B1 is loop_body_before_inv, starting with after_guard_block (which could be loop_head)
@@ -912,6 +1019,13 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
                 "========== {loop_label_prefix}_start =========="
             ))],
         );
+        if uses_iterations {
+            self.cfg_method.add_stmt(start_block, vir::Stmt::Assign( vir::Assign {
+                target: vir::Expr::local(iterations_var.clone()),
+                source: 0.into(),
+                kind: vir::AssignKind::Copy,
+            }));
+        }
         heads.push(Some(start_block));
 
         // Encode the first G group (start - *G* - B1 - invariant - B2 - G - B1 - end)
@@ -960,18 +1074,71 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
         heads.push(Some(inv_pre_block));
         self.cfg_method
                 .set_successor(inv_pre_block, vir::Successor::Goto(inv_post_block_perms));
+        if uses_iterations {
+            // The counter is havocked together with the other local variables.
+            self.cfg_method.add_stmt(inv_post_block_fnspc, vir::Stmt::Inhale( vir::Inhale {
+                expr: vir::Expr::ge_cmp(vir::Expr::local(iterations_var.clone()), 0.into()),
+            }));
+        }
+        // The loop variant has to be positive when entering the loop body, and
+        // it is compared with its value at the beginning of an arbitrary
+        // iteration at the end of that iteration.
+        let loop_variant = self.encode_loop_variant_spec(loop_head)?.map(|(variant, span)| {
+            (variant, span, self.cfg_method.get_fresh_label_name())
+        });
+        if let Some((variant, variant_span, _)) = &loop_variant {
+            let position = self.register_error(*variant_span, ErrorCtxt::LoopVariantOnEntry);
+            self.cfg_method.add_stmt(inv_pre_block, vir::Stmt::Assert( vir::Assert {
+                expr: vir::Expr::gt_cmp(variant.clone(), 0.into()),
+                position,
+            }));
+        }
         {
-            let stmts =
-                self.encode_loop_invariant_exhale_stmts(loop_head, before_invariant_block, false)?;
+            // On entry, the previous iteration is the current state.
+            let stmts = self.encode_loop_invariant_exhale_stmts(
+                loop_head,
+                before_invariant_block,
+                false,
+                None,
+            )?;
             self.cfg_method.add_stmts(inv_pre_block, stmts);
         }
         // We'll add later more statements at the end of inv_pre_block, to havoc local variables
         let fnspec_span = {
-            let (stmts, fnspec_span) =
-                self.encode_loop_invariant_inhale_fnspec_stmts(loop_head, before_invariant_block, false)?;
+            let (stmts, fnspec_span) = self.encode_loop_invariant_inhale_fnspec_stmts(
+                loop_head,
+                before_invariant_block,
+                false,
+                prev_iteration_labels.as_ref().map(|(arbitrary_label, _)| arbitrary_label.as_str()),
+            )?;
             self.cfg_method.add_stmts(inv_post_block_fnspc, stmts); fnspec_span
         };
+        if let Some((_, _, variant_label)) = &loop_variant {
+            self.cfg_method.add_stmt(inv_post_block_fnspc, vir::Stmt::label(variant_label.clone()));
+        }
+        if let Some((_, iteration_label)) = &prev_iteration_labels {
+            self.cfg_method
+                .add_stmt(inv_post_block_fnspc, vir::Stmt::label(iteration_label.clone()));
+        }
         {
+            let stmts =
+                self.encode_loop_invariant_inhale_perm_stmts(loop_head, before_invariant_block, false).with_span(fnspec_span.clone())?;
+            self.cfg_method.add_stmts(inv_post_block_perms, stmts);
+        }
+        if let Some((arbitrary_label, _)) = &prev_iteration_labels {
+            // Havoc the permissions of the loop a second time, so that the
+            // labelled state of the previous iteration is unrelated to the
+            // current one.
+            self.cfg_method
+                .add_stmt(inv_post_block_perms, vir::Stmt::label(arbitrary_label.clone()));
+            let (permissions, _, _) = self
+                .encode_loop_invariant_permissions(loop_head, before_invariant_block, true)
+                .with_span(fnspec_span.clone())?;
+            let position = self.register_error(fnspec_span.clone(), ErrorCtxt::Unexpected);
+            self.cfg_method.add_stmt(inv_post_block_perms, vir::Stmt::Exhale( vir::Exhale {
+                expr: permissions.into_iter().conjoin(),
+                position,
+            }));
             let stmts =
                 self.encode_loop_invariant_inhale_perm_stmts(loop_head, before_invariant_block, false).with_span(fnspec_span)?;
             self.cfg_method.add_stmts(inv_post_block_perms, stmts);
@@ -1003,9 +1170,9 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
             Some((mid_g, mid_b1))
         } else {
             // Cannot add loop guard to loop invariant
-            let fn_names: Vec<_> = preconds.iter().filter_map(|(name, _)| name.as_ref()).map(|name| {
-                name.strip_prefix("m_").unwrap_or(name)
-            }).collect();
+            let fn_names: Vec<_> = preconds.iter().filter_map(|(name, _)| name.as_ref())
+                .map(|name| vir_crate::legacy::typaram::demangle(name))
+                .collect();
             let warning_msg = if fn_names.is_empty() {
                 "the loop guard was not automatically added as a `body_invariant!(...)`, consider doing this manually".to_string()
             } else {
@@ -1062,11 +1229,34 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
                 "========== {loop_label_prefix}_end_body =========="
             ))],
         );
+        if uses_iterations {
+            self.cfg_method.add_stmt(end_body_block, vir::Stmt::Assign( vir::Assign {
+                target: vir::Expr::local(iterations_var.clone()),
+                source: vir::Expr::add(vir::Expr::local(iterations_var), 1.into()),
+                kind: vir::AssignKind::Copy,
+            }));
+        }
+        if let Some((variant, variant_span, variant_label)) = loop_variant {
+            let old_variant = variant.clone().old(variant_label);
+            let decreased_pos =
+                self.register_error(variant_span, ErrorCtxt::LoopVariantNonDecreased);
+            self.cfg_method.add_stmt(end_body_block, vir::Stmt::Assert( vir::Assert {
+                expr: vir::Expr::lt_cmp(variant.clone(), old_variant),
+                position: decreased_pos,
+            }));
+            let non_negative_pos =
+                self.register_error(variant_span, ErrorCtxt::LoopVariantAfterIteration);
+            self.cfg_method.add_stmt(end_body_block, vir::Stmt::Assert( vir::Assert {
+                expr: vir::Expr::ge_cmp(variant, 0.into()),
+                position: non_negative_pos,
+            }));
+        }
         {
             let stmts = self.encode_loop_invariant_exhale_stmts(
                 loop_head,
                 before_invariant_block,
-                true
+                true,
+                prev_iteration_labels.as_ref().map(|(_, iteration_label)| iteration_label.as_str()),
             )?;
             self.cfg_method.add_stmts(end_body_block, stmts);
         }
@@ -2506,8 +2696,12 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
                                     term.source_info.span,
                                     ErrorCtxt::Panic(panic_cause),
                                 );
+                            // The panics of failing assertions can be checked
+                            // independently of the other panics.
+                            let is_checked_assertion = config::check_assertions()
+                                && matches!(panic_cause, PanicCause::Assert | PanicCause::DebugAssert);
 
-                            if self.check_panics {
+                            if self.check_panics || is_checked_assertion {
                                 stmts.push(vir::Stmt::comment(format!(
                                     "Rust panic - {panic_message}"
                                 )));
@@ -2520,6 +2714,17 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
                             }
                         }
 
+                        "prusti_contracts::snapshot" => {
+                            // `snapshot!(label)` names the current state, so
+                            // that specifications can refer to it with
+                            // `old[label](..)`.
+                            let label = snapshot_label(
+                                self.encoder.env().tcx(),
+                                call_substs.type_at(0),
+                            );
+                            stmts.push(vir::Stmt::label(label));
+                        }
+
                         "std::boxed::Box::<T>::new"
                         | "alloc::boxed::Box::<T>::new" => {
                             // This is the initialization of a box
@@ -2602,6 +2807,26 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
                             );
                         }
 
+                        "std::cmp::min" | "core::cmp::min" |
+                        "std::cmp::max" | "core::cmp::max" |
+                        "std::cmp::Ord::min" | "core::cmp::Ord::min" |
+                        "std::cmp::Ord::max" | "core::cmp::Ord::max"
+                            if args.len() == 2 &&
+                                self.mir_encoder.get_operand_ty(&args[0]).is_integral()
+                        => {
+                            debug!("Encoding call of {}", full_func_proc_name);
+                            stmts.extend(
+                                self.encode_min_max_function_call(
+                                    location,
+                                    term.source_info.span,
+                                    args,
+                                    destination,
+                                    target,
+                                    full_func_proc_name.ends_with("max"),
+                                )?
+                            );
+                        }
+
                         "std::ops::Fn::call"
                         | "core::ops::Fn::call" => {
                             let cl_type: ty::Ty = call_substs[0].expect_ty();
@@ -3133,6 +3358,46 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
         }
     }
 
+    /// Encode a call of `min` or `max` on integers as a conditional expression,
+    /// picking the same argument as the implementation in the standard library
+    /// when both are equal.
+    fn encode_min_max_function_call(
+        &mut self,
+        location: mir::Location,
+        call_site_span: Span,
+        args: &[mir::Operand<'tcx>],
+        destination: mir::Place<'tcx>,
+        target: Option<BasicBlockIndex>,
+        is_max: bool,
+    ) -> SpannedEncodingResult<Vec<vir::Stmt>> {
+        let lhs = self.mir_encoder.encode_operand_expr(&args[0])
+            .with_span(call_site_span)?;
+        let rhs = self.mir_encoder.encode_operand_expr(&args[1])
+            .with_span(call_site_span)?;
+        let lhs_le_rhs = vir::Expr::le_cmp(lhs.clone(), rhs.clone());
+        let expr = if is_max {
+            vir::Expr::ite(lhs_le_rhs, rhs, lhs)
+        } else {
+            vir::Expr::ite(lhs_le_rhs, lhs, rhs)
+        };
+
+        let (target_value, mut stmts) = self.encode_pure_function_call_lhs_value(destination, target, location)
+            .with_span(call_site_span)?;
+        let inhaled_expr = vir::Expr::eq_cmp(target_value, expr);
+
+        let (call_stmts, label) = self.encode_pure_function_call_site(
+            location,
+            destination,
+            target,
+            inhaled_expr,
+        )?;
+        stmts.extend(call_stmts);
+
+        self.encode_transfer_args_permissions(location, args,  &mut stmts, &label, false)?;
+
+        Ok(stmts)
+    }
+
     /// Encode an edge of the MIR graph
     fn encode_edge_block(
         &mut self,
@@ -4143,6 +4408,27 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
                 expr: func_spec
             }),
         );
+        if config::check_vacuous_preconditions() {
+            let precondition_spans: Vec<_> = self.procedure_contract()
+                .functional_precondition(self.encoder.env(), self.substs)
+                .iter()
+                .map(|(assertion, _)| self.encoder.env().query.get_def_span(assertion))
+                .collect();
+            if !precondition_spans.is_empty() {
+                // The refutation fails iff the preconditions are contradictory.
+                let pos = self.register_error(
+                    MultiSpan::from_spans(precondition_spans),
+                    ErrorCtxt::VacuousPrecondition,
+                );
+                self.cfg_method.add_stmt(
+                    start_cfg_block,
+                    vir::Stmt::Refute( vir::Refute {
+                        expr: false.into(),
+                        position: pos,
+                    }),
+                );
+            }
+        }
         self.cfg_method.add_stmt(
             start_cfg_block,
             vir::Stmt::label(PRECONDITION_LABEL),
@@ -4225,10 +4511,22 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
                     "pledge reference={:?} lhs={:?} rhs={:?}",
                     reference, body_lhs, body_rhs
                 );
-                assert!(
-                    reference.is_none(),
-                    "The reference should be none in postcondition."
-                );
+                // There is only one magic wand, so a pledge on an argument can only be
+                // attached to it if the result reborrows from that argument.
+                if let Some(index) = reference {
+                    let reference_arg = contract.args[*index];
+                    let is_reborrowed = borrow_info
+                        .blocked_paths
+                        .iter()
+                        .any(|(place, _)| place.is_root(reference_arg));
+                    if !is_reborrowed {
+                        return Err(SpannedEncodingError::incorrect(
+                            "the reference of the pledge is not reborrowed by the result",
+                            span,
+                        )
+                        .into());
+                    }
+                }
                 let mut assertion_lhs = if let Some(body_lhs) = body_lhs {
                     self.encoder.encode_assertion(
                         body_lhs,
@@ -4298,6 +4596,9 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
     ///
     /// +   For references wrap the base ``_1.var_ref``.
     /// +   For non-references wrap the entire place into old.
+    ///
+    /// Every formal argument of the contract is wrapped, not only ``self``,
+    /// so that pledges can refer to the pre-state of any argument.
     fn wrap_arguments_into_old(
         &self,
         mut assertion: vir::Expr,
@@ -4493,6 +4794,24 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
             )?;
             func_spec.push(assertion.set_default_pos(assertion_pos));
         }
+        let modified_fields = self.encoder.get_modified_fields(contract.def_id, Some(substs));
+        if let Some(modified_fields) = modified_fields {
+            let modifies_span = self.encoder.env().query.get_def_span(contract.def_id);
+            func_spec_spans.push(modifies_span);
+            let modifies_pos = self.mir_encoder.register_span(modifies_span);
+            let unmodified_fields = self.encode_unmodified_fields(
+                contract,
+                &encoded_args,
+                &modified_fields,
+                pre_label,
+                modifies_span,
+            )?;
+            for assertion in unmodified_fields {
+                let assertion =
+                    self.wrap_arguments_into_old(assertion, pre_label, contract, &encoded_args)?;
+                func_spec.push(assertion.set_default_pos(modifies_pos));
+            }
+        }
         let postcondition_span = MultiSpan::from_spans(func_spec_spans);
         let func_spec_pos = self.mir_encoder.register_span(postcondition_span.clone());
 
@@ -4519,6 +4838,51 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
         ))
     }
 
+    /// Encode the frame condition of a method annotated with `#[modifies(...)]`:
+    /// the fields of `self` that are not in `modified_fields` keep the value
+    /// they had in the pre-state.
+    fn encode_unmodified_fields(
+        &self,
+        contract: &ProcedureContract<'tcx>,
+        encoded_args: &[vir::Expr],
+        modified_fields: &[String],
+        pre_label: &str,
+        span: Span,
+    ) -> SpannedEncodingResult<Vec<vir::Expr>> {
+        // The annotation was checked by the specification collector of the
+        // crate that defines the method.
+        let tcx = self.encoder.env().tcx();
+        let receiver_ty = self.locals.get_type(contract.args[0]);
+        let (encoded_self, self_ty, _) = self
+            .mir_encoder
+            .encode_deref(encoded_args[0].clone(), receiver_ty)
+            .with_span(span)?;
+        let ty::TyKind::Adt(adt_def, adt_substs) = self_ty.kind() else {
+            unreachable!("`#[modifies]` on a method of `{self_ty}`");
+        };
+        let fields = &adt_def.non_enum_variant().fields;
+        let mut unmodified_fields = vec![];
+        for field in fields {
+            let field_name = field.ident(tcx).to_string();
+            let field_ty = field.ty(tcx, adt_substs);
+            // `PhantomData` fields are not part of the encoding.
+            if modified_fields.contains(&field_name) || field_ty.is_phantom_data() {
+                continue;
+            }
+            let encoded_field = self
+                .encoder
+                .encode_struct_field(&field_name, field_ty)
+                .with_span(span)?;
+            let field_place = encoded_self.clone().field(encoded_field);
+            unmodified_fields.push(vir::Expr::eq_cmp(
+                vir::Expr::snap_app(field_place.clone()),
+                self.mir_encoder
+                    .encode_old_expr(vir::Expr::snap_app(field_place), pre_label),
+            ));
+        }
+        Ok(unmodified_fields)
+    }
+
     /// Modelling move as simple assignment on Viper level has a consequence
     /// that the assigned place changes. Therefore, if some value is
     /// moved into a borrow, the borrow starts pointing to a different
@@ -5301,12 +5665,45 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
         res
     }
 
-    /// Encode the functional specification of a loop
+    /// Encode the functional specification of a loop.
+    /// `old[prev](..)` is encoded as an old expression with `prev_iteration_label`,
+    /// or as the current state if it is `None`.
     #[tracing::instrument(level = "trace", skip(self), ret)]
     fn encode_loop_invariant_specs(
-        &self,
+        &mut self,
         loop_head: BasicBlockIndex,
         _loop_inv_block: BasicBlockIndex,
+        prev_iteration_label: Option<&str>,
+    ) -> SpannedEncodingResult<(Vec<vir::Expr>, MultiSpan)> {
+        // The invariants are encoded only once per loop, since they are used at
+        // several points of the encoding of the loop.
+        let (encoded_specs, encoded_spec_span) =
+            if let Some(cached) = self.cached_loop_invariant_specs.get(&loop_head) {
+                cached.clone()
+            } else {
+                let encoded = self.encode_loop_invariant_specs_uncached(loop_head)?;
+                self.cached_loop_invariant_specs
+                    .insert(loop_head, encoded.clone());
+                encoded
+            };
+        let encoded_specs = encoded_specs
+            .into_iter()
+            .map(|spec| {
+                spec.map_labels(|label| {
+                    if label == PREV_ITERATION_LABEL {
+                        prev_iteration_label.map(ToString::to_string)
+                    } else {
+                        Some(label)
+                    }
+                })
+            })
+            .collect();
+        Ok((encoded_specs, encoded_spec_span))
+    }
+
+    fn encode_loop_invariant_specs_uncached(
+        &self,
+        loop_head: BasicBlockIndex,
     ) -> SpannedEncodingResult<(Vec<vir::Expr>, MultiSpan)> {
         let spec_blocks = self.get_loop_spec_blocks(loop_head);
         trace!(
@@ -5325,17 +5722,23 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
                     _,
                     mir::Rvalue::Aggregate(box mir::AggregateKind::Closure(cl_def_id, cl_substs), _),
                 )) = stmt.kind {
-                    if let Some(spec) = self.encoder.get_loop_specs(cl_def_id) {
-                        encoded_specs.push(self.encoder.encode_invariant(
+                    if let Some(LoopSpecification::Invariant(invariant)) =
+                        self.encoder.get_loop_specs(cl_def_id)
+                    {
+                        let encoded_spec = self.encoder.encode_invariant(
                             self.mir,
                             bbi,
                             self.proc_def_id,
                             cl_substs,
-                        )?);
-                        let invariant = match spec {
-                            prusti_interface::specs::typed::LoopSpecification::Invariant(inv) => inv,
-                            _ => continue,
-                        };
+                        )?;
+                        let encoded_spec = encoded_spec.replace_place(
+                            &vir::Expr::local(vir::LocalVar::new(
+                                LOOP_ITERATIONS_PLACEHOLDER,
+                                vir::Type::Int,
+                            )),
+                            &vir::Expr::local(self.encode_loop_iterations_var(loop_head)),
+                        );
+                        encoded_specs.push(encoded_spec);
                         encoded_spec_spans.push(self.encoder.env().tcx().def_span(invariant));
                     }
                 }
@@ -5344,19 +5747,62 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
         Ok((encoded_specs, MultiSpan::from_spans(encoded_spec_spans)))
     }
 
+    /// The ghost counter of the completed iterations of a loop, which is what
+    /// `iterations()` refers to in the invariants of the loop.
+    fn encode_loop_iterations_var(&self, loop_head: BasicBlockIndex) -> vir::LocalVar {
+        vir::LocalVar::new(
+            format!("_loop{}_iterations", loop_head.index()),
+            vir::Type::Int,
+        )
+    }
+
+    /// Encode the variant of a loop, i.e. the termination measure given by
+    /// `body_variant!(..)`, together with its span.
+    #[tracing::instrument(level = "trace", skip(self), ret)]
+    fn encode_loop_variant_spec(
+        &self,
+        loop_head: BasicBlockIndex,
+    ) -> SpannedEncodingResult<Option<(vir::Expr, Span)>> {
+        for bbi in self.get_loop_spec_blocks(loop_head) {
+            for stmt in &self.mir.basic_blocks[bbi].statements {
+                if let mir::StatementKind::Assign(box (
+                    _,
+                    mir::Rvalue::Aggregate(box mir::AggregateKind::Closure(cl_def_id, cl_substs), _),
+                )) = stmt.kind {
+                    if let Some(LoopSpecification::Variant(variant)) =
+                        self.encoder.get_loop_specs(cl_def_id)
+                    {
+                        // The variant is a closure like the invariants, so it
+                        // is inlined in the same way.
+                        let encoded_variant = self.encoder.encode_invariant(
+                            self.mir,
+                            bbi,
+                            self.proc_def_id,
+                            cl_substs,
+                        )?;
+                        let span = self.encoder.env().tcx().def_span(variant);
+                        return Ok(Some((encoded_variant, span)));
+                    }
+                }
+            }
+        }
+        Ok(None)
+    }
+
     #[tracing::instrument(level = "trace", skip(self))]
     fn encode_loop_invariant_exhale_stmts(
         &mut self,
         loop_head: BasicBlockIndex,
         loop_inv_block: BasicBlockIndex,
         after_loop_iteration: bool,
+        prev_iteration_label: Option<&str>,
     ) -> SpannedEncodingResult<Vec<vir::Stmt>> {
         if !after_loop_iteration {
             self.pure_var_for_preserving_value_map
                 .insert(loop_head, FxHashMap::default());
         }
         let (func_spec, func_spec_span) =
-            self.encode_loop_invariant_specs(loop_head, loop_inv_block)?;
+            self.encode_loop_invariant_specs(loop_head, loop_inv_block, prev_iteration_label)?;
         let (permissions, equalities, invs_spec) =
             self.encode_loop_invariant_permissions(loop_head, loop_inv_block, true)
                 .with_span(func_spec_span.clone())?;
@@ -5463,9 +5909,10 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
         loop_head: BasicBlockIndex,
         loop_inv_block: BasicBlockIndex,
         after_loop: bool,
+        prev_iteration_label: Option<&str>,
     ) -> SpannedEncodingResult<(Vec<vir::Stmt>, MultiSpan)> {
         let (func_spec, func_spec_span) =
-            self.encode_loop_invariant_specs(loop_head, loop_inv_block)?;
+            self.encode_loop_invariant_specs(loop_head, loop_inv_block, prev_iteration_label)?;
 
         let mut stmts = vec![vir::Stmt::comment(format!(
             "Inhale the loop fnspec invariant of block {loop_head:?}"
@@ -6681,6 +7128,10 @@ impl<'p, 'v: 'p, 'tcx: 'v> ProcedureEncoder<'p, 'v, 'tcx> {
                     let operand = &operands[field_index];
                     let field_name = field.ident(tcx).to_string();
                     let field_ty = field.ty(tcx, subst);
+                    // `PhantomData` fields are not part of the encoding.
+                    if field_ty.is_phantom_data() {
+                        continue;
+                    }
                     let encoded_field = self.encoder
                         .encode_struct_field(&field_name, field_ty)
                         .with_span(span)?;
@@ -716,6 +716,9 @@ impl SnapshotEncoder {
                 for field in adt_def.all_fields() {
                     // or adt_def.variants[0].fields ?
                     let field_ty = field.ty(tcx, substs);
+                    if field_ty.is_phantom_data() {
+                        continue;
+                    }
                     let field_ty = tcx
                         .try_normalize_erasing_regions(ty::ParamEnv::reveal_all(), field_ty)
                         .unwrap_or(field_ty);
@@ -764,6 +767,9 @@ impl SnapshotEncoder {
                     };
                     for field in &variant.fields {
                         let field_ty = field.ty(tcx, substs);
+                        if field_ty.is_phantom_data() {
+                            continue;
+                        }
                         let field_ty = tcx
                             .try_normalize_erasing_regions(ty::ParamEnv::reveal_all(), field_ty)
                             .unwrap_or(field_ty);
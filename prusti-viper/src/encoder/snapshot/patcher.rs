@@ -130,9 +130,18 @@ impl<'p, 'v: 'p, 'tcx: 'v> FallibleExprFolder for SnapshotPatcher<'p, 'v, 'tcx>
                 match receiver.get_type() {
                     vir::Type::Int if field.name == "val_int" => Ok(*receiver),
                     vir::Type::Bool if field.name == "val_bool" => Ok(*receiver),
+                    vir::Type::Float(vir::Float::F32) if field.name == "val_float32" => {
+                        Ok(*receiver)
+                    }
+                    vir::Type::Float(vir::Float::F64) if field.name == "val_float64" => {
+                        Ok(*receiver)
+                    }
 
+                    // Dereferencing the result of a pure function that returns
+                    // a reference, e.g. to an element of a sequence.
                     vir::Type::Int if field.name == "val_ref" => Ok(*receiver),
                     vir::Type::Bool if field.name == "val_ref" => Ok(*receiver),
+                    vir::Type::Float(_) if field.name == "val_ref" => Ok(*receiver),
 
                     vir::Type::Snapshot(_) => match field.name.as_str() {
                         "val_ref" => Ok(*receiver),
@@ -12,8 +12,15 @@ use ::log::{debug, error, info};
 use once_cell::sync::Lazy;
 use prusti_common::{
     config,
-    report::log,
-    vir::{optimizations::optimize_program, program::Program},
+    report::{
+        log,
+        time_report::{self, Phase},
+    },
+    vir::{
+        optimizations::optimize_program,
+        program::{Program, VIPER_PRELUDE_NAME},
+        type_check::type_check_program,
+    },
     Stopwatch,
 };
 use prusti_interface::{
@@ -22,11 +29,13 @@ use prusti_interface::{
     specs::typed,
     PrustiError,
 };
-use prusti_rustc_interface::span::DUMMY_SP;
+use prusti_rustc_interface::{errors::MultiSpan, hir::def_id::DefId, span::DUMMY_SP};
 use prusti_server::{
     process_verification_request, spawn_server_thread, tokio::runtime::Builder, PrustiClient,
     VerificationRequest, ViperBackendConfig,
 };
+use serde_json::json;
+use std::time::Instant;
 use viper::{self, PersistentCache, Viper};
 use vir_crate::common::check_mode::CheckMode;
 
@@ -76,7 +85,33 @@ impl<'v, 'tcx> Verifier<'v, 'tcx> {
 
         let encoding_errors_count = self.encoder.count_encoding_errors();
 
-        let polymorphic_programs = self.encoder.get_viper_programs();
+        let mut polymorphic_programs = self.encoder.get_viper_programs();
+
+        if config::export_viper_prelude() {
+            match vir_crate::polymorphic::Program::prelude(
+                format!("{}_{}", self.env.name.local_crate_name(), VIPER_PRELUDE_NAME),
+                &polymorphic_programs,
+            ) {
+                Ok(prelude) => polymorphic_programs.push(prelude),
+                Err(error) => PrustiError::internal(
+                    format!("the Viper prelude could not be exported: {error}"),
+                    DUMMY_SP.into(),
+                )
+                .emit(&self.env.diagnostic),
+            }
+        }
+
+        if config::check_vir_types() {
+            for program in &polymorphic_programs {
+                for error in type_check_program(program) {
+                    PrustiError::internal(
+                        format!("the encoded program is ill-typed: {error}"),
+                        DUMMY_SP.into(),
+                    )
+                    .emit(&self.env.diagnostic);
+                }
+            }
+        }
 
         let mut programs: Vec<Program> = if config::simplify_encoding() {
             stopwatch.start_next("optimizing Viper program");
@@ -93,6 +128,22 @@ impl<'v, 'tcx> Verifier<'v, 'tcx> {
         };
         programs.extend(self.encoder.get_core_proof_programs());
 
+        if config::encode_only() {
+            stopwatch.finish();
+            for program in &programs {
+                log::report(
+                    "vir_program_encode_only",
+                    format!("{}.vir", program.get_name_with_check_mode()),
+                    program,
+                );
+            }
+            return if encoding_errors_count == 0 {
+                VerificationResult::Success
+            } else {
+                VerificationResult::Failure
+            };
+        }
+
         stopwatch.start_next("verifying Viper program");
         let verification_results = verify_programs(self.env, programs);
         stopwatch.finish();
@@ -142,6 +193,7 @@ impl<'v, 'tcx> Verifier<'v, 'tcx> {
 
         // Report verification errors
         let mut prusti_errors: Vec<_> = vec![];
+        let mut procedure_errors: Vec<_> = vec![];
         for (method, verification_error) in verification_errors.into_iter() {
             debug!("Verification error in {}: {:?}", method, verification_error);
             let mut prusti_error = error_manager.translate_verification_error(&verification_error);
@@ -187,6 +239,12 @@ impl<'v, 'tcx> Verifier<'v, 'tcx> {
                 }
             }
 
+            if config::results_json().is_some() {
+                procedure_errors.push((
+                    error_manager.get_def_id(&verification_error),
+                    prusti_error.clone(),
+                ));
+            }
             prusti_errors.push(prusti_error);
         }
         prusti_errors.sort();
@@ -196,7 +254,14 @@ impl<'v, 'tcx> Verifier<'v, 'tcx> {
             if prusti_error.is_disabled() {
                 prusti_error.cancel();
             } else {
+                let is_error = prusti_error.is_error();
                 prusti_error.emit(&self.env.diagnostic);
+                // Warnings derived from verification errors, such as vacuous
+                // preconditions, do not fail the verification. Internal errors
+                // reported as warnings still do.
+                if !is_error && !config::internal_errors_as_warnings() {
+                    continue;
+                }
             }
             result = VerificationResult::Failure;
         }
@@ -205,8 +270,105 @@ impl<'v, 'tcx> Verifier<'v, 'tcx> {
             result = VerificationResult::Failure;
         }
 
+        if config::require_all_specs_verified() {
+            let unverified = self
+                .encoder
+                .get_unverified_specified_procedures(&task.procedures);
+            if !unverified.is_empty() {
+                let names = unverified
+                    .iter()
+                    .map(|&proc_id| format!("`{}`", self.env.name.get_absolute_item_name(proc_id)))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let spans = unverified
+                    .iter()
+                    .map(|&proc_id| self.env.query.get_def_span(proc_id))
+                    .collect();
+                PrustiError::verification(
+                    format!(
+                        "the specifications of the following functions were not verified: {names}"
+                    ),
+                    MultiSpan::from_spans(spans),
+                )
+                .set_help("either verify these functions or mark them as `#[trusted]`")
+                .emit(&self.env.diagnostic);
+                result = VerificationResult::Failure;
+            }
+        }
+
+        if let Some(path) = config::results_json() {
+            self.report_results_json(&path, task, &procedure_errors);
+        }
+
         result
     }
+
+    /// Writes the result of the verification of each procedure of the task,
+    /// with the errors reported for it, as JSON to `path` (or to stdout if
+    /// `path` is `-`).
+    fn report_results_json(
+        &self,
+        path: &str,
+        task: &VerificationTask<'tcx>,
+        verification_errors: &[(Option<DefId>, PrustiError)],
+    ) {
+        let source_map = self.env.tcx().sess.source_map();
+        let error_to_json = |error: &PrustiError| {
+            let location = error
+                .span()
+                .primary_span()
+                .map(|span| source_map.lookup_char_pos(span.lo()));
+            json!({
+                "code": error.code(),
+                "message": error.message(),
+                "file": location
+                    .as_ref()
+                    .map(|loc| loc.file.name.prefer_local().to_string()),
+                "line": location.as_ref().map(|loc| loc.line),
+                "column": location.as_ref().map(|loc| loc.col_display + 1),
+            })
+        };
+        let procedures: Vec<_> = task
+            .procedures
+            .iter()
+            .map(|&proc_id| {
+                let errors: Vec<_> = self
+                    .encoder
+                    .get_procedure_encoding_errors(proc_id)
+                    .iter()
+                    .chain(
+                        verification_errors
+                            .iter()
+                            .filter(|(def_id, _)| *def_id == Some(proc_id))
+                            .map(|(_, error)| error),
+                    )
+                    .filter(|error| !error.is_disabled())
+                    .collect();
+                let status = if errors.iter().any(|error| error.is_error()) {
+                    "failed"
+                } else if self.encoder.is_procedure_verified(proc_id) {
+                    "verified"
+                } else {
+                    "skipped"
+                };
+                json!({
+                    "name": self.env.name.get_unique_item_name(proc_id),
+                    "status": status,
+                    "errors": errors.into_iter().map(&error_to_json).collect::<Vec<_>>(),
+                })
+            })
+            .collect();
+        let results = serde_json::to_string_pretty(&json!({ "procedures": procedures })).unwrap();
+        if path == "-" {
+            println!("{results}");
+        } else if let Err(error) = std::fs::write(path, results) {
+            PrustiError::internal(
+                format!("the verification results could not be written to {path}: {error}"),
+                DUMMY_SP.into(),
+            )
+            .emit(&self.env.diagnostic);
+        }
+    }
 }
 
 /// Verify a list of programs.
@@ -260,10 +422,12 @@ fn verify_programs(
             .expect("failed to construct Tokio runtime");
         verification_requests
             .map(|(program_name, request)| {
+                let start = Instant::now();
                 let remote_result = runtime.block_on(client.verify(request));
                 let result = remote_result.unwrap_or_else(|error| {
                     panic!("Verification request of program {program_name} failed: {error:?}")
                 });
+                time_report::record(&program_name, Phase::Verification, start.elapsed());
                 (program_name, result)
             })
             .collect()
@@ -277,7 +441,9 @@ fn verify_programs(
         let mut cache = PersistentCache::load_cache(config::cache_path());
         verification_requests
             .map(|(program_name, request)| {
-                let result = process_verification_request(&viper_thread, request, &mut cache);
+                let result = time_report::measure(&program_name, Phase::Verification, || {
+                    process_verification_request(&viper_thread, request, &mut cache)
+                });
                 (program_name, result)
             })
             .collect()
@@ -1,11 +1,13 @@
 use crate::verifier::verify;
-use prusti_common::config;
+use prusti_common::{config, report::time_report};
 use prusti_interface::{
     environment::{mir_storage, Environment},
-    specs::{self, cross_crate::CrossCrateSpecs, is_spec_fn},
+    specs::{self, cross_crate::CrossCrateSpecs, is_spec_fn, typed},
+    PrustiError,
 };
 use prusti_rustc_interface::{
     driver::Compilation,
+    errors::MultiSpan,
     hir::{def::DefKind, def_id::LocalDefId},
     interface::{interface::Compiler, Config, Queries},
     middle::ty::{
@@ -15,6 +17,7 @@ use prusti_rustc_interface::{
     },
     session::Session,
 };
+use std::time::Instant;
 
 #[derive(Default)]
 pub struct PrustiCompilerCalls;
@@ -125,21 +128,27 @@ impl prusti_rustc_interface::driver::Callbacks for PrustiCompilerCalls {
             spec_checker.check(&env);
             compiler.session().abort_if_errors();
 
+            let spec_collection_start = Instant::now();
             let hir = env.query.hir();
             let mut spec_collector = specs::SpecCollector::new(&mut env);
             spec_collector.collect_specs(hir);
 
             let mut def_spec = spec_collector.build_def_specs();
+            time_report::record_spec_collection(spec_collection_start.elapsed());
             // Do print_typeckd_specs prior to importing cross crate
             if config::print_typeckd_specs() {
                 for value in def_spec.all_values_debug(config::hide_uuids()) {
                     println!("{value}");
                 }
             }
+            if config::list_trusted() || config::max_trusted().is_some() {
+                report_trusted_items(&env, &def_spec);
+            }
             CrossCrateSpecs::import_export_cross_crate(&mut env, &mut def_spec);
             if !config::no_verify() {
                 verify(env, def_spec);
             }
+            time_report::print();
         });
 
         compiler.session().abort_if_errors();
@@ -150,3 +159,41 @@ impl prusti_rustc_interface::driver::Callbacks for PrustiCompilerCalls {
         }
     }
 }
+
+/// Lists the trusted items of the crate if `LIST_TRUSTED` is enabled and warns
+/// if their number exceeds `MAX_TRUSTED`.
+fn report_trusted_items(env: &Environment<'_>, def_spec: &typed::DefSpecificationMap) {
+    let mut trusted_items: Vec<_> = def_spec
+        .trusted_items()
+        .into_iter()
+        .map(|def_id| (env.name.get_unique_item_name(def_id), def_id))
+        .collect();
+    trusted_items.sort();
+    if config::list_trusted() {
+        println!("Trusted items: {}", trusted_items.len());
+        for (name, def_id) in &trusted_items {
+            if let Some(reason) = def_spec.get_trusted_reason(def_id) {
+                println!("trusted: {name} (reason: {reason})");
+            } else {
+                println!("trusted: {name}");
+            }
+        }
+    }
+    if let Some(max_trusted) = config::max_trusted() {
+        if trusted_items.len() > max_trusted {
+            let spans: Vec<_> = trusted_items[max_trusted..]
+                .iter()
+                .map(|(_, def_id)| env.query.get_def_span(*def_id))
+                .collect();
+            PrustiError::warning(
+                format!(
+                    "the crate contains {} trusted items, which exceeds the budget of {} set by `MAX_TRUSTED`",
+                    trusted_items.len(),
+                    max_trusted
+                ),
+                MultiSpan::from_spans(spans),
+            )
+            .emit(&env.diagnostic);
+        }
+    }
+}
@@ -17,7 +17,14 @@ pub fn verify(env: Environment<'_>, def_spec: typed::DefSpecificationMap) {
         debug!("Prepare verification task...");
         // TODO: can we replace `get_annotated_procedures` with information
         // that is already in `def_spec`?
-        let (annotated_procedures, types) = env.get_annotated_procedures_and_types();
+        let (mut annotated_procedures, types) = env.get_annotated_procedures_and_types();
+        if config::skip_main() {
+            annotated_procedures.retain(|&def_id| !env.query.is_main_fn(def_id));
+        }
+        if config::skip_tests() {
+            let test_fns = env.query.get_test_fns();
+            annotated_procedures.retain(|def_id| !test_fns.contains(def_id));
+        }
         let verification_task = VerificationTask {
             procedures: annotated_procedures,
             types,
@@ -0,0 +1,31 @@
+use bencher::Bencher;
+use vir::legacy::typaram::Substs;
+
+bencher::benchmark_main!(substs);
+bencher::benchmark_group!(
+    substs,
+    bench_apply_monomorphic,
+    bench_apply_generic,
+    bench_learn_repeated
+);
+
+const MONOMORPHIC: &str = "m_generics_basic_6$$Foo$opensqu$0$closesqu$$_beg_$u128$_end_";
+const GENERIC: &str = "m_generics_basic_6$$Foo$opensqu$0$closesqu$$_beg_$__TYPARAM__$C$__$_end_";
+
+fn bench_apply_monomorphic(bench: &mut Bencher) {
+    let substs = Substs::learn(MONOMORPHIC, MONOMORPHIC);
+    bench.iter(|| substs.apply(MONOMORPHIC));
+}
+
+fn bench_apply_generic(bench: &mut Bencher) {
+    let substs = Substs::learn(GENERIC, MONOMORPHIC);
+    bench.iter(|| substs.apply(GENERIC));
+}
+
+fn bench_learn_repeated(bench: &mut Bencher) {
+    bench.iter(|| {
+        for _ in 0..300 {
+            Substs::learn(GENERIC, MONOMORPHIC);
+        }
+    });
+}
@@ -245,6 +245,7 @@ pub enum BuiltinFunc {
     SingleSeq,
     LookupSeq,
     ConcatSeq,
+    TailSeq,
     SeqLen,
     NewInt,
     Index,
@@ -177,6 +177,7 @@ pub enum ContainerOpKind {
     SeqConstructor,
     SeqIndex,
     SeqConcat,
+    SeqDrop,
     SeqLen,
     MapEmpty,
     MapUpdate,
@@ -23,6 +23,67 @@ impl Domain {
     pub fn get_identifier(&self) -> String {
         self.name.clone()
     }
+
+    /// Combines two partial definitions of the same domain. The domains need
+    /// to have the same name and type variables. Functions and axioms are
+    /// deduplicated by name; functions with the same name need to have the same
+    /// signature.
+    pub fn merge(mut self, other: Domain) -> Result<Domain, DomainMergeError> {
+        if self.name != other.name {
+            return Err(DomainMergeError::NameMismatch(self.name, other.name));
+        }
+        if self.type_vars != other.type_vars {
+            return Err(DomainMergeError::TypeVarsMismatch(self.name));
+        }
+        for function in other.functions {
+            match self.functions.iter().find(|f| f.name == function.name) {
+                Some(existing) if existing != &function => {
+                    return Err(DomainMergeError::ConflictingFunction(
+                        self.name,
+                        function.name,
+                    ));
+                }
+                Some(_) => {}
+                None => self.functions.push(function),
+            }
+        }
+        for axiom in other.axioms {
+            if !self.axioms.iter().any(|a| a.name == axiom.name) {
+                self.axioms.push(axiom);
+            }
+        }
+        Ok(self)
+    }
+}
+
+/// The reason why two domains could not be merged.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DomainMergeError {
+    /// The domains have different names.
+    NameMismatch(String, String),
+    /// The domains have different type variables.
+    TypeVarsMismatch(String),
+    /// The domains declare a function with the same name but different
+    /// signatures.
+    ConflictingFunction(String, String),
+}
+
+impl fmt::Display for DomainMergeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DomainMergeError::NameMismatch(left, right) => {
+                write!(f, "cannot merge domain {} with domain {}", left, right)
+            }
+            DomainMergeError::TypeVarsMismatch(name) => {
+                write!(f, "the type variables of domain {} do not match", name)
+            }
+            DomainMergeError::ConflictingFunction(name, function) => write!(
+                f,
+                "function {} of domain {} is declared with different signatures",
+                function, name
+            ),
+        }
+    }
 }
 
 impl fmt::Display for Domain {
@@ -163,3 +224,71 @@ impl fmt::Display for DomainAxiom {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn domain(functions: Vec<DomainFunc>, axioms: Vec<DomainAxiom>) -> Domain {
+        Domain {
+            name: "D".to_string(),
+            functions,
+            axioms,
+            type_vars: vec![],
+        }
+    }
+
+    fn function(name: &str, return_type: Type) -> DomainFunc {
+        DomainFunc::new("D", name, vec![LocalVar::new("x", Type::Int)], return_type)
+    }
+
+    fn axiom(name: &str) -> DomainAxiom {
+        DomainAxiom {
+            comment: None,
+            name: name.to_string(),
+            expr: true.into(),
+            domain_name: "D".to_string(),
+        }
+    }
+
+    #[test]
+    fn merge_unions_functions_and_axioms() {
+        let left = domain(
+            vec![function("f", Type::Int), function("g", Type::Bool)],
+            vec![axiom("a1")],
+        );
+        let right = domain(
+            vec![function("g", Type::Bool), function("h", Type::Int)],
+            vec![axiom("a1"), axiom("a2")],
+        );
+        let merged = left.merge(right).unwrap();
+        let function_names: Vec<_> = merged.functions.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(function_names, vec!["f", "g", "h"]);
+        let axiom_names: Vec<_> = merged.axioms.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(axiom_names, vec!["a1", "a2"]);
+    }
+
+    #[test]
+    fn merge_rejects_conflicting_functions() {
+        let left = domain(vec![function("f", Type::Int)], vec![]);
+        let right = domain(vec![function("f", Type::Bool)], vec![]);
+        assert_eq!(
+            left.merge(right),
+            Err(DomainMergeError::ConflictingFunction(
+                "D".to_string(),
+                "f".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn merge_rejects_different_type_vars() {
+        let left = domain(vec![], vec![]);
+        let mut right = domain(vec![], vec![]);
+        right.type_vars.push(Type::type_var("T"));
+        assert_eq!(
+            left.merge(right),
+            Err(DomainMergeError::TypeVarsMismatch("D".to_string()))
+        );
+    }
+}
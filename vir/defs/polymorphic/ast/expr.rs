@@ -872,6 +872,85 @@ impl Expr {
         walker.non_pure
     }
 
+    /// Infers the trigger sets of a quantifier over `vars` whose body is this
+    /// expression. Candidate terms are the function applications that mention
+    /// some of the bound variables and contain only function applications,
+    /// field accesses, variables and constants; terms in nested quantifiers
+    /// are ignored. Every minimal candidate that mentions all the bound
+    /// variables becomes a trigger set on its own; if there is no such
+    /// candidate, all the candidates together form a single trigger set.
+    pub fn collect_triggers(&self, vars: &[LocalVar]) -> Vec<Trigger> {
+        fn is_trigger_term(expr: &Expr) -> bool {
+            match expr {
+                Expr::Local(..) | Expr::Const(..) => true,
+                Expr::Field(FieldExpr { base, .. }) => is_trigger_term(base),
+                Expr::FuncApp(FuncApp { arguments, .. })
+                | Expr::DomainFuncApp(DomainFuncApp { arguments, .. }) => {
+                    arguments.iter().all(is_trigger_term)
+                }
+                _ => false,
+            }
+        }
+        struct CandidateCollector<'a> {
+            vars: &'a [Expr],
+            candidates: Vec<Expr>,
+        }
+        impl<'a> CandidateCollector<'a> {
+            fn visit(&mut self, expr: Expr, arguments: &[Expr]) {
+                if is_trigger_term(&expr)
+                    && self.vars.iter().any(|var| expr.find(var))
+                    && !self.candidates.contains(&expr)
+                {
+                    self.candidates.push(expr);
+                }
+                for argument in arguments {
+                    self.walk(argument);
+                }
+            }
+        }
+        impl<'a> ExprWalker for CandidateCollector<'a> {
+            fn walk_func_app(&mut self, func_app: &FuncApp) {
+                self.visit(Expr::FuncApp(func_app.clone()), &func_app.arguments);
+            }
+            fn walk_domain_func_app(&mut self, domain_func_app: &DomainFuncApp) {
+                self.visit(
+                    Expr::DomainFuncApp(domain_func_app.clone()),
+                    &domain_func_app.arguments,
+                );
+            }
+            fn walk_forall(&mut self, _forall: &ForAll) {}
+            fn walk_exists(&mut self, _exists: &Exists) {}
+        }
+        let vars: Vec<Expr> = vars.iter().cloned().map(Expr::local).collect();
+        let mut collector = CandidateCollector {
+            vars: &vars,
+            candidates: vec![],
+        };
+        collector.walk(self);
+        let candidates = collector.candidates;
+        let covering: Vec<&Expr> = candidates
+            .iter()
+            .filter(|candidate| vars.iter().all(|var| candidate.find(var)))
+            .collect();
+        if covering.is_empty() {
+            if candidates.is_empty() {
+                vec![]
+            } else {
+                vec![Trigger::new(candidates)]
+            }
+        } else {
+            covering
+                .iter()
+                .filter(|candidate| {
+                    !covering
+                        .iter()
+                        .any(|other| other != *candidate && candidate.find(other))
+                })
+                .map(|candidate| Trigger::new(vec![(*candidate).clone()]))
+                .collect()
+        }
+    }
+
     /// Only defined for places
     pub fn get_base(&self) -> LocalVar {
         debug_assert!(self.is_place());
@@ -1224,6 +1303,17 @@ impl Expr {
     // TODO polymorphic: convert following 2 functions after type substitution is updated
     #[must_use]
     pub fn replace_place(self, target: &Expr, replacement: &Expr) -> Self {
+        self.substitute_place_with_expr_counted(target, replacement).0
+    }
+
+    /// Like `replace_place`, but also returns how many occurrences of `target`
+    /// were replaced. Occurrences in quantifier triggers are not counted.
+    #[must_use]
+    pub fn substitute_place_with_expr_counted(
+        self,
+        target: &Expr,
+        replacement: &Expr,
+    ) -> (Self, usize) {
         // TODO: disabled for snapshot patching
         /*
         debug_assert!(target.is_place());
@@ -1246,11 +1336,13 @@ impl Expr {
             target: &'a Expr,
             replacement: &'a Expr,
             subst: bool,
+            count: usize,
         }
         impl<'a> ExprFolder for PlaceReplacer<'a> {
             fn fold(&mut self, e: Expr) -> Expr {
                 if e.is_place() && &e == self.target {
                     self.subst = true;
+                    self.count += 1;
                     self.replacement.clone()
                 } else {
                     let default_expr = default_fold_expr(self, e);
@@ -1324,16 +1416,29 @@ impl Expr {
                 }
             }
         }
-        PlaceReplacer {
+        let mut replacer = PlaceReplacer {
             target,
             replacement,
             subst: false,
-        }
-        .fold(self)
+            count: 0,
+        };
+        let result = replacer.fold(self);
+        (result, replacer.count)
     }
 
     #[must_use]
     pub fn replace_multiple_places(self, replacements: &[(Expr, Expr)]) -> Self {
+        self.replace_multiple_places_counted(replacements).0
+    }
+
+    /// Like `replace_multiple_places`, but also returns for each replacement
+    /// how many occurrences of its source were replaced. Occurrences in
+    /// quantifier triggers are not counted.
+    #[must_use]
+    pub fn replace_multiple_places_counted(
+        self,
+        replacements: &[(Expr, Expr)],
+    ) -> (Self, Vec<usize>) {
         // TODO: disabled for snapshot patching
         /*
         for (src, dst) in replacements {
@@ -1355,14 +1460,16 @@ impl Expr {
 
         struct PlaceReplacer<'a> {
             replacements: &'a [(Expr, Expr)],
+            counts: Vec<usize>,
         }
         impl<'a> ExprFolder for PlaceReplacer<'a> {
             fn fold(&mut self, e: Expr) -> Expr {
                 // Check if this matches a substitution.
                 if e.is_place() {
-                    let substitution = self.replacements.iter().find(|(src, _)| src == &e);
-                    if let Some((_src, dst)) = substitution {
-                        return dst.clone();
+                    let substitution = self.replacements.iter().position(|(src, _)| src == &e);
+                    if let Some(index) = substitution {
+                        self.counts[index] += 1;
+                        return self.replacements[index].1.clone();
                     }
                 }
 
@@ -1451,7 +1558,12 @@ impl Expr {
                 })
             }
         }
-        PlaceReplacer { replacements }.fold(self)
+        let mut replacer = PlaceReplacer {
+            replacements,
+            counts: vec![0; replacements.len()],
+        };
+        let result = replacer.fold(self);
+        (result, replacer.counts)
     }
 
     /// Replaces expressions like `old[l5](old[l5](_9.val_ref).foo.bar)`
@@ -2725,3 +2837,61 @@ where
         rfold(self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn var(name: &str) -> Expr {
+        Expr::local(LocalVar::new(name, Type::Int))
+    }
+
+    #[test]
+    fn substitute_place_with_expr_counted_counts_occurrences() {
+        let expr = Expr::add(var("x"), Expr::mul(var("x"), var("y")));
+        let (result, count) = expr.substitute_place_with_expr_counted(&var("x"), &var("z"));
+        assert_eq!(count, 2);
+        assert_eq!(result, Expr::add(var("z"), Expr::mul(var("z"), var("y"))));
+    }
+
+    #[test]
+    fn substitute_place_with_expr_counted_reports_missing_target() {
+        let expr = Expr::add(var("y"), 1.into());
+        let (result, count) = expr
+            .clone()
+            .substitute_place_with_expr_counted(&var("x"), &var("z"));
+        assert_eq!(count, 0);
+        assert_eq!(result, expr);
+    }
+
+    #[test]
+    fn replace_multiple_places_counted_counts_each_replacement() {
+        let expr = Expr::and(
+            Expr::gt_cmp(var("x"), var("y")),
+            Expr::gt_cmp(var("x"), 0.into()),
+        );
+        let replacements = [
+            (var("x"), var("a")),
+            (var("y"), var("b")),
+            (var("w"), var("c")),
+        ];
+        let (result, counts) = expr.replace_multiple_places_counted(&replacements);
+        assert_eq!(counts, vec![2, 1, 0]);
+        assert_eq!(
+            result,
+            Expr::and(
+                Expr::gt_cmp(var("a"), var("b")),
+                Expr::gt_cmp(var("a"), 0.into()),
+            )
+        );
+    }
+
+    #[test]
+    fn replace_multiple_places_counted_replaces_simultaneously() {
+        let expr = Expr::sub(var("x"), var("y"));
+        let replacements = [(var("x"), var("y")), (var("y"), var("x"))];
+        let (result, counts) = expr.replace_multiple_places_counted(&replacements);
+        assert_eq!(counts, vec![1, 1]);
+        assert_eq!(result, Expr::sub(var("y"), var("x")));
+    }
+}
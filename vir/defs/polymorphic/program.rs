@@ -1,4 +1,7 @@
-use crate::polymorphic::{ast::*, cfg::CfgMethod};
+use crate::{
+    common::identifier::WithIdentifier,
+    polymorphic::{ast::*, cfg::CfgMethod},
+};
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct Program {
@@ -11,3 +14,67 @@ pub struct Program {
     pub functions: Vec<Function>,
     pub viper_predicates: Vec<Predicate>,
 }
+
+impl Program {
+    /// Builds a program without methods that contains the union of the
+    /// domains, backend types, fields, functions, and predicates of the given
+    /// programs. Definitions with the same identifier are included only once,
+    /// while partial definitions of the same domain are merged.
+    pub fn prelude<'a>(
+        name: String,
+        programs: impl IntoIterator<Item = &'a Program>,
+    ) -> Result<Program, DomainMergeError> {
+        let mut prelude = Program {
+            name,
+            domains: Vec::new(),
+            backend_types: Vec::new(),
+            fields: Vec::new(),
+            builtin_methods: Vec::new(),
+            methods: Vec::new(),
+            functions: Vec::new(),
+            viper_predicates: Vec::new(),
+        };
+        for program in programs {
+            for domain in &program.domains {
+                if let Some(index) = prelude.domains.iter().position(|d| d.name == domain.name) {
+                    let existing = prelude.domains.swap_remove(index);
+                    prelude.domains.push(existing.merge(domain.clone())?);
+                } else {
+                    prelude.domains.push(domain.clone());
+                }
+            }
+            for backend_type in &program.backend_types {
+                if !prelude.backend_types.iter().any(|t| t.name == backend_type.name) {
+                    prelude.backend_types.push(backend_type.clone());
+                }
+            }
+            for field in &program.fields {
+                if !prelude.fields.iter().any(|f| f.name == field.name) {
+                    prelude.fields.push(field.clone());
+                }
+            }
+            for function in &program.functions {
+                let identifier = function.get_identifier();
+                if !prelude
+                    .functions
+                    .iter()
+                    .any(|f| f.get_identifier() == identifier)
+                {
+                    prelude.functions.push(function.clone());
+                }
+            }
+            for predicate in &program.viper_predicates {
+                let identifier = predicate.get_identifier();
+                if !prelude
+                    .viper_predicates
+                    .iter()
+                    .any(|p| p.get_identifier() == identifier)
+                {
+                    prelude.viper_predicates.push(predicate.clone());
+                }
+            }
+        }
+        prelude.domains.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(prelude)
+    }
+}
@@ -184,11 +184,20 @@ impl From<polymorphic::DomainFunc> for legacy::DomainFunc {
 
 impl From<polymorphic::DomainAxiom> for legacy::DomainAxiom {
     fn from(domain_axiom: polymorphic::DomainAxiom) -> legacy::DomainAxiom {
+        // The triggers of the top-level quantifier are kept separately, such
+        // that they are printed and passed to the backend with the axiom.
+        let (expr, triggers) = match domain_axiom.expr.into() {
+            legacy::Expr::ForAll(vars, triggers, body, pos) => {
+                (legacy::Expr::ForAll(vars, vec![], body, pos), triggers)
+            }
+            expr => (expr, vec![]),
+        };
         legacy::DomainAxiom {
             comment: domain_axiom.comment,
             name: domain_axiom.name,
-            expr: domain_axiom.expr.into(),
+            expr,
             domain_name: domain_axiom.domain_name,
+            triggers,
         }
     }
 }
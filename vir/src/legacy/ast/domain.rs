@@ -75,7 +75,7 @@ impl fmt::Display for DomainFunc {
             if !first {
                 write!(f, ", ")?;
             }
-            write!(f, "{arg:?}")?;
+            write!(f, "{}: {}", arg.name, arg.typ)?;
             first = false
         }
         writeln!(f, "): {}", self.return_type)
@@ -95,18 +95,112 @@ pub struct DomainAxiom {
     pub name: String,
     pub expr: Expr,
     pub domain_name: String,
+    /// Triggers of the quantifier at the top of `expr`, in addition to the ones that the
+    /// quantifier already carries.
+    #[serde(default)]
+    pub triggers: Vec<Trigger>,
+}
+
+impl DomainAxiom {
+    /// The axiom's expression, with `triggers` attached to its top-level quantifier.
+    pub fn expr_with_triggers(&self) -> Expr {
+        match &self.expr {
+            Expr::ForAll(vars, triggers, body, pos) if !self.triggers.is_empty() => {
+                let mut triggers = triggers.clone();
+                triggers.extend(self.triggers.iter().cloned());
+                Expr::ForAll(vars.clone(), triggers, body.clone(), *pos)
+            }
+            expr => expr.clone(),
+        }
+    }
 }
 
 impl fmt::Display for DomainAxiom {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         if let Some(comment) = &self.comment {
-            writeln!(
-                f,
-                "/* {} */ axiom {} {{ {} }}",
-                comment, self.name, self.expr
-            )
-        } else {
-            writeln!(f, "axiom {} {{ {} }}", self.name, self.expr)
+            write!(f, "/* {comment} */ ")?;
         }
+        writeln!(f, "axiom {} {{ {} }}", self.name, self.expr_with_triggers())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_domain_func() {
+        let mut f = DomainFunc {
+            name: "f".to_string(),
+            formal_args: vec![LocalVar::new("x", Type::Int), LocalVar::new("b", Type::Bool)],
+            return_type: Type::Int,
+            unique: false,
+            domain_name: "D".to_string(),
+        };
+        assert_eq!(f.to_string(), "function f(x: Int, b: Bool): Int\n");
+        f.unique = true;
+        f.formal_args.clear();
+        assert_eq!(f.to_string(), "unique function f(): Int\n");
+    }
+
+    #[test]
+    fn test_axiom_with_triggers() {
+        let x = LocalVar::new("x", Type::Int);
+        let f = DomainFunc {
+            name: "f".to_string(),
+            formal_args: vec![x.clone()],
+            return_type: Type::Int,
+            unique: false,
+            domain_name: "D".to_string(),
+        };
+        let f_x = f.apply(vec![Expr::local(x.clone())]);
+        let body = Expr::ge_cmp(f_x.clone(), Expr::Const(Const::Int(0), Position::default()));
+        let mut axiom = DomainAxiom {
+            comment: None,
+            name: "f_positive".to_string(),
+            expr: Expr::ForAll(vec![x], vec![], box body, Position::default()),
+            domain_name: "D".to_string(),
+            triggers: vec![],
+        };
+        assert_eq!(
+            axiom.to_string(),
+            "axiom f_positive { (forall x: Int :: (f(x)) >= (0)) }\n"
+        );
+        axiom.triggers = vec![Trigger::new(vec![f_x])];
+        assert_eq!(
+            axiom.to_string(),
+            "axiom f_positive { (forall x: Int :: {f(x)} (f(x)) >= (0)) }\n"
+        );
+    }
+
+    #[test]
+    fn test_axiom_with_nested_triggers() {
+        let x = LocalVar::new("x", Type::Int);
+        let y = LocalVar::new("y", Type::Int);
+        let f = DomainFunc {
+            name: "f".to_string(),
+            formal_args: vec![x.clone(), y.clone()],
+            return_type: Type::Bool,
+            unique: false,
+            domain_name: "D".to_string(),
+        };
+        let f_x_y = f.apply(vec![Expr::local(x.clone()), Expr::local(y.clone())]);
+        let inner = Expr::ForAll(
+            vec![y],
+            vec![Trigger::new(vec![f_x_y.clone()])],
+            box f_x_y,
+            Position::default(),
+        );
+        let axiom = DomainAxiom {
+            comment: None,
+            name: "f_true".to_string(),
+            expr: Expr::ForAll(vec![x], vec![], box inner, Position::default()),
+            domain_name: "D".to_string(),
+            triggers: vec![],
+        };
+        assert_eq!(
+            axiom.to_string(),
+            "axiom f_true { (forall x: Int :: (forall y: Int :: {f(x, y)} f(x, y))) }\n"
+        );
     }
 }
@@ -224,30 +224,28 @@ impl fmt::Display for Expr {
             }
             Expr::ForAll(ref vars, ref triggers, ref body, ref _pos) => write!(
                 f,
-                "forall {} {} :: {}",
+                "(forall {} ::{} {})",
                 vars.iter()
                     .map(|x| format!("{x:?}"))
                     .collect::<Vec<String>>()
                     .join(", "),
                 triggers
                     .iter()
-                    .map(|x| x.to_string())
-                    .collect::<Vec<String>>()
-                    .join(", "),
+                    .map(|x| format!(" {x}"))
+                    .collect::<String>(),
                 body
             ),
             Expr::Exists(ref vars, ref triggers, ref body, ref _pos) => write!(
                 f,
-                "exists {} {} :: {}",
+                "(exists {} ::{} {})",
                 vars.iter()
                     .map(|x| format!("{x:?}"))
                     .collect::<Vec<String>>()
                     .join(", "),
                 triggers
                     .iter()
-                    .map(|x| x.to_string())
-                    .collect::<Vec<String>>()
-                    .join(", "),
+                    .map(|x| format!(" {x}"))
+                    .collect::<String>(),
                 body
             ),
             Expr::LetExpr(ref var, ref expr, ref body, ref _pos) => {
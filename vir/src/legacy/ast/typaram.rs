@@ -7,7 +7,72 @@
 use log::trace;
 use regex::Regex;
 use rustc_hash::FxHashMap;
+use std::cell::RefCell;
 
+lazy_static::lazy_static! {
+    static ref TYPARAM_RE: Regex = Regex::new("(__TYPARAM__\\$(.*?)\\$__)").unwrap();
+    static ref DISAMBIGUATOR_RE: Regex = Regex::new("\\[[0-9]+\\]").unwrap();
+    static ref ITEM_PREFIX_RE: Regex = Regex::new("(^|[<&*(\\[ $])m_").unwrap();
+    static ref REF_RE: Regex = Regex::new("(^|[<&*(\\[ ])(raw_)?ref\\$").unwrap();
+}
+
+thread_local! {
+    /// The results of `Substs::learn`, keyed by its arguments. The same pair of types is
+    /// typically learned many times while encoding generic code.
+    static LEARNED_SUBSTS: RefCell<FxHashMap<(String, String), Substs>> =
+        RefCell::new(FxHashMap::default());
+}
+
+/// The escape sequences introduced by `prusti_utils::utils::identifiers::encode_identifier`.
+/// `$$` has to be handled last, because it also occurs between consecutive escape sequences.
+const IDENTIFIER_ESCAPES: &[(&str, &str)] = &[
+    ("$sharp$", "#"),
+    ("$openang$", "<"),
+    ("$closeang$", ">"),
+    ("$openrou$", "("),
+    ("$closerou$", ")"),
+    ("$opensqu$", "["),
+    ("$closesqu$", "]"),
+    ("$opencur$", "{"),
+    ("$closecur$", "}"),
+    ("$comma$", ","),
+    ("$semic$", ";"),
+    ("$space$", " "),
+    ("$amp$", "&"),
+    ("$star$", "*"),
+    ("$$", "::"),
+];
+
+/// Converts a mangled type name such as
+/// `m_krate$$Number$opensqu$0$closesqu$$_beg_$__TYPARAM__$T$__$_sep_$i32$_end_` back to a
+/// readable form such as `krate::Number<T, i32>`, to be used in diagnostics and dumps.
+///
+/// The result is meant for humans only: the mangling is not injective, so the readable name
+/// cannot be mapped back to the original one.
+pub fn demangle(name: &str) -> String {
+    let mut result = TYPARAM_RE
+        .replace_all(name, |captures: &regex::Captures| {
+            let label = &captures[2];
+            label.strip_prefix('_').unwrap_or(label).to_string()
+        })
+        .into_owned();
+    for (from, to) in [("$_beg_$", "<"), ("$_sep_$", ", "), ("$_end_", ">")] {
+        result = result.replace(from, to);
+    }
+    for (from, to) in IDENTIFIER_ESCAPES {
+        result = result.replace(from, to);
+    }
+    let result = DISAMBIGUATOR_RE.replace_all(&result, "");
+    let result = ITEM_PREFIX_RE.replace_all(&result, "$1");
+    REF_RE
+        .replace_all(&result, |captures: &regex::Captures| {
+            let sigil = if captures.get(2).is_some() { "*" } else { "&" };
+            format!("{}{}", &captures[1], sigil)
+        })
+        .into_owned()
+}
+
+#[derive(Clone)]
 pub struct Substs {
     regex: Regex,
     repls: FxHashMap<String, String>,
@@ -31,10 +96,16 @@ impl Substs {
     /// This function will compute what is the type substitution needed to go from `from` to `to`.
     #[tracing::instrument(level = "trace")]
     pub fn learn(from: &str, to: &str) -> Self {
-        lazy_static::lazy_static! {
-            static ref TYPARAM_RE: Regex = Regex::new("(__TYPARAM__\\$(.*?)\\$__)").unwrap();
+        let key = (from.to_string(), to.to_string());
+        if let Some(substs) = LEARNED_SUBSTS.with(|cache| cache.borrow().get(&key).cloned()) {
+            return substs;
         }
+        let substs = Self::learn_uncached(from, to);
+        LEARNED_SUBSTS.with(|cache| cache.borrow_mut().insert(key, substs.clone()));
+        substs
+    }
 
+    fn learn_uncached(from: &str, to: &str) -> Self {
         // Start with an empty `repls_regex`
         let mut repls_regex_str = String::new();
         repls_regex_str.push('^');
@@ -90,6 +161,10 @@ impl Substs {
     }
 
     pub fn apply(&self, inner1: &str) -> String {
+        // Nothing to replace if no type parameters were learned (monomorphic code)
+        if self.repls.is_empty() {
+            return inner1.to_string();
+        }
         let mut newstr = String::new();
         let mut last = 0;
         for matsh in self.regex.find_iter(inner1) {
@@ -174,6 +249,70 @@ mod tests {
         test(outer1, outer2, inner1, inner2);
     }
 
+    #[test]
+    fn test_no_typarams() {
+        let outer = "m_generics_basic_1$$Number$opensqu$0$closesqu$$_beg_$i32$_end_";
+        let substs = Substs::learn(outer, outer);
+        assert!(substs.repls.is_empty());
+        let inner = "m_generics_basic_1$$BarBaz$opensqu$0$closesqu$$_beg_$__TYPARAM__$C$__$_end_";
+        assert_eq!(substs.apply(inner), inner);
+        assert_eq!(substs.apply(outer), outer);
+    }
+
+    #[test]
+    fn test_demangle_typarams() {
+        assert_eq!(
+            demangle("ref$m_generics_basic_3$$Number$opensqu$0$closesqu$$_beg_$__TYPARAM__$Y$__$_end_"),
+            "&generics_basic_3::Number<Y>"
+        );
+        assert_eq!(
+            demangle("m_generics_basic_7$$Number$opensqu$0$closesqu$$_beg_$__TYPARAM__$A$__$_sep_$__TYPARAM__$B$__$_end_"),
+            "generics_basic_7::Number<A, B>"
+        );
+        assert_eq!(demangle("__TYPARAM__$_T$__"), "T");
+    }
+
+    #[test]
+    fn test_demangle_concrete() {
+        assert_eq!(
+            demangle("m_generics_basic_6$$Foo$opensqu$0$closesqu$$_beg_$u128$_end_"),
+            "generics_basic_6::Foo<u128>"
+        );
+        assert_eq!(
+            demangle("m_generics_basic_4$$Number$opensqu$0$closesqu$$_beg_$__TYPARAM__$C$__$_sep_$i16$_end_"),
+            "generics_basic_4::Number<C, i16>"
+        );
+        assert_eq!(demangle("i32"), "i32");
+    }
+
+    #[test]
+    fn test_demangle_nested() {
+        let mangled = "ref$m_generics_basic_5$$Number$opensqu$0$closesqu$$_beg_$m_generics_basic_5$$Number$opensqu$0$closesqu$$_beg_$i8$_sep_$i32$_sep_$u8$_end_$_sep_$m_generics_basic_5$$Number$opensqu$0$closesqu$$_beg_$i16$_sep_$i32$_sep_$i64$_end_$_sep_$m_generics_basic_5$$Number$opensqu$0$closesqu$$_beg_$isize$_sep_$i32$_sep_$usize$_end_$_end_";
+        assert_eq!(
+            demangle(mangled),
+            "&generics_basic_5::Number<generics_basic_5::Number<i8, i32, u8>, \
+             generics_basic_5::Number<i16, i32, i64>, generics_basic_5::Number<isize, i32, usize>>"
+        );
+        let mangled = "m_generics_basic_5$$Number$opensqu$0$closesqu$$_beg_$__TYPARAM__$A$__$_sep_$m_generics_basic_5$$Number$opensqu$0$closesqu$$_beg_$__TYPARAM__$B$__$_sep_$i32$_sep_$__TYPARAM__$C$__$_end_$_sep_$__TYPARAM__$D$__$_end_";
+        assert_eq!(
+            demangle(mangled),
+            "generics_basic_5::Number<A, generics_basic_5::Number<B, i32, C>, D>"
+        );
+    }
+
+    #[test]
+    fn test_repeated_learn() {
+        let outer1 = "ref$m_generics_basic_4$$Number$opensqu$0$closesqu$$_beg_$__TYPARAM__$A$__$_sep_$__TYPARAM__$B$__$_end_";
+        let outer2 = "ref$m_generics_basic_4$$Number$opensqu$0$closesqu$$_beg_$__TYPARAM__$C$__$_sep_$i16$_end_";
+        let expected = Substs::learn_uncached(outer1, outer2);
+        for _ in 0..500 {
+            let substs = Substs::learn(outer1, outer2);
+            assert_eq!(substs.repls, expected.repls);
+        }
+        let key = (outer1.to_string(), outer2.to_string());
+        LEARNED_SUBSTS.with(|cache| assert!(cache.borrow().contains_key(&key)));
+    }
+
     #[test]
     pub fn test7() {
         let outer1 = "tuple2$__TYPARAM__$T$__$__TYPARAM__$T$__";
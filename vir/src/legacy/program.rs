@@ -1,4 +1,5 @@
 use crate::legacy::{ast::*, cfg::CfgMethod};
+use std::fmt;
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Hash, Eq, PartialEq)]
 pub struct Program {
@@ -67,3 +68,30 @@ impl Program {
         self.visit_statements_mut(|s| s.visit_positions_mut(&mut visitor));
     }
 }
+
+impl fmt::Display for Program {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for domain in &self.domains {
+            writeln!(f, "{domain}")?;
+        }
+        for backend_type in &self.backend_types {
+            writeln!(f, "backend type {}", backend_type.name)?;
+        }
+        for field in &self.fields {
+            writeln!(f, "field {field:?}")?;
+        }
+        for method in &self.builtin_methods {
+            writeln!(f, "{method}")?;
+        }
+        for function in &self.functions {
+            writeln!(f, "{function}")?;
+        }
+        for predicate in &self.viper_predicates {
+            writeln!(f, "{predicate}")?;
+        }
+        for method in &self.methods {
+            writeln!(f, "{method}")?;
+        }
+        Ok(())
+    }
+}
@@ -181,6 +181,7 @@ impl Typed for ContainerOp {
             ContainerOpKind::SeqEmpty
             | ContainerOpKind::SeqConstructor
             | ContainerOpKind::SeqConcat
+            | ContainerOpKind::SeqDrop
             | ContainerOpKind::MapEmpty
             | ContainerOpKind::MapUpdate
             | ContainerOpKind::SetEmpty